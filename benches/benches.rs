@@ -39,6 +39,12 @@ fn sampling(c: &mut Criterion) {
         .dynamic()
         .build()
         .unwrap();
+    let pooled_bspline = BSpline::builder()
+        .elements(dynamic_elements.clone())
+        .knots(KNOTS)
+        .pooled()
+        .build()
+        .unwrap();
     let const_bspline = BSpline::builder()
         .elements(ELEMENTS)
         .knots(KNOTS)
@@ -67,6 +73,14 @@ fn sampling(c: &mut Criterion) {
                 .collect()
         });
     });
+    c.bench_function("sampling_pooled_bspline", |b| {
+        b.iter::<Vec<f64>, _>(|| {
+            pooled_bspline
+                .by_ref()
+                .take(black_box(sample_size))
+                .collect()
+        });
+    });
     c.bench_function("sampling_const_bspline", |b| {
         b.iter::<Vec<f64>, _>(|| {
             const_bspline
@@ -91,6 +105,10 @@ fn sampling(c: &mut Criterion) {
                 .collect()
         });
     });
+    #[cfg(feature = "rayon")]
+    c.bench_function("par_sampling_const_bspline", |b| {
+        b.iter(|| const_bspline.par_sample(black_box(1_000_000)));
+    });
 }
 
 fn creation(c: &mut Criterion) {
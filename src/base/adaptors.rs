@@ -1,7 +1,19 @@
-use crate::{ConstDiscreteGenerator, Curve, DiscreteGenerator, Generator};
-use core::ops::{Add, Bound, Mul, RangeBounds};
+use crate::easing::Easing;
+use crate::{ConstDiscreteGenerator, ConstEquidistant, Curve, DiscreteGenerator, Generator};
+use crate::{Merge, SortedGenerator};
+use core::fmt;
+use core::fmt::Debug;
+use core::ops::{Add, Bound, Mul, RangeBounds, Sub};
 use num_traits::clamp;
 use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
 
 /// Wrapper for curves to clamp input to their domain.
 ///
@@ -43,16 +55,75 @@ where
     }
 }
 
+/// Curve adaptor which reshapes the input of a curve with an easing.
+///
+/// The input is clamped to the domain, normalized to [0.0,1.0], eased and mapped back onto the domain.
+/// This struct is created by the [`ease_input()`] method of curves. See its documentation for more.
+///
+/// [`ease_input()`]: crate::Curve::ease_input()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EasedInput<G, F> {
+    inner: G,
+    easing: F,
+}
+
+impl<G, F> EasedInput<G, F> {
+    /// Create a new adaptor which reshapes the input of the curve with the given easing.
+    pub fn new(gen: G, easing: F) -> Self {
+        EasedInput { inner: gen, easing }
+    }
+}
+
+impl<G, F, R> Generator<R> for EasedInput<G, F>
+where
+    G: Curve<R>,
+    F: Easing<R>,
+    R: Real,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.inner.domain();
+        let width = end - start;
+        if width.is_zero() {
+            return self.inner.gen(start);
+        }
+        // the domain may be reversed, such that start is bigger than end
+        let clamped = clamp(input, start.min(end), start.max(end));
+        let factor = (clamped - start) / width;
+        self.inner.gen(start + self.easing.ease(factor) * width)
+    }
+}
+
+impl<G, F, R> Curve<R> for EasedInput<G, F>
+where
+    G: Curve<R>,
+    F: Easing<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
 /// Acts like a slice of a curve.
 ///
 /// That is, a slice of a curve has the same domain as the curve itself but maps the domain onto the range given.
 ///
+/// The ends of the domain are mapped exactly onto the ends of the range, such that generating
+/// the start or end of the domain is the same as generating the start or end of the range with the curve itself.
+/// As such, slices can be stitched together without gaps.
+///
 /// This struct is created by the [`slice()`] method. Please look their for more information.
 ///
 /// [`slice()`]: crate::Curve::slice()
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub struct Slice<G, R>(TransformInput<G, R, R>);
+pub struct Slice<G, R> {
+    inner: G,
+    domain: [R; 2],
+    range: [R; 2],
+}
 
 impl<G, R> Slice<G, R>
 where
@@ -62,21 +133,25 @@ where
     /// Create a new slice of the given generator.
     ///
     /// It does not matter if the bounds itself are included or excluded as we assume a continuous curve.
+    /// Unbounded ends of the range are replaced by the ends of the domain.
     pub fn new<B>(gen: G, bound: B) -> Self
     where
         B: RangeBounds<R>,
     {
-        let [gen_start, gen_end] = gen.domain();
-        let bound_start = match bound.start_bound() {
+        let domain = gen.domain();
+        let start = match bound.start_bound() {
             Bound::Included(x) | Bound::Excluded(x) => *x,
-            Bound::Unbounded => gen_start,
+            Bound::Unbounded => domain[0],
         };
-        let bound_end = match bound.end_bound() {
+        let end = match bound.end_bound() {
             Bound::Included(x) | Bound::Excluded(x) => *x,
-            Bound::Unbounded => gen_end,
+            Bound::Unbounded => domain[1],
         };
-        let scale = (bound_end - bound_start) / (gen_end - gen_start);
-        Slice(TransformInput::new(gen, bound_start - gen_start, scale))
+        Slice {
+            inner: gen,
+            domain,
+            range: [start, end],
+        }
     }
 }
 
@@ -87,7 +162,11 @@ where
 {
     type Output = G::Output;
     fn gen(&self, input: R) -> Self::Output {
-        self.0.gen(input)
+        let [domain_start, domain_end] = self.domain;
+        let [start, end] = self.range;
+        // the factor is exactly zero and one at the ends of the domain, such that the ends are mapped exactly
+        let factor = (input - domain_start) / (domain_end - domain_start);
+        self.inner.gen(start * (R::one() - factor) + end * factor)
     }
 }
 
@@ -97,7 +176,7 @@ where
     R: Real,
 {
     fn domain(&self) -> [R; 2] {
-        self.0.inner.domain()
+        self.domain
     }
 }
 
@@ -133,6 +212,30 @@ where
     pub fn normalized_to_domain(generator: G, start: R, end: R) -> Self {
         Self::new(generator, -start, (end - start).recip())
     }
+
+    /// Transform an input such that the interval `from` is mapped affinely onto the interval `to`
+    /// before it is given to the wrapped generator.
+    ///
+    /// That is, the input `t` is transformed to `to[0] + (t - from[0]) * (to[1] - to[0]) / (from[1] - from[0])`.
+    /// If `to` is the domain of the wrapped curve, the domain of the transformed curve is `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::FuncEase, Curve, Generator, TransformInput};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// // a curve authored in seconds, used with frame indices at 60 frames per second
+    /// let curve = FuncEase::new(|x: f64| x * x);
+    /// let frames = TransformInput::domain_to_domain(curve, [0.0, 60.0], [0.0, 1.0]);
+    /// assert_f64_near!(frames.gen(30.0), 0.25);
+    /// let [start, end] = frames.domain();
+    /// assert_f64_near!(start, 0.0);
+    /// assert_f64_near!(end, 60.0);
+    /// ```
+    pub fn domain_to_domain(generator: G, from: [R; 2], to: [R; 2]) -> Self {
+        let multiplication = (to[1] - to[0]) / (from[1] - from[0]);
+        Self::new(generator, to[0] - from[0] * multiplication, multiplication)
+    }
 }
 
 impl<G, A, M, I> Generator<I> for TransformInput<G, A, M>
@@ -202,7 +305,39 @@ where
 ///
 /// That it, the struct holds two generators with output S and T and outputs (S,T).
 ///
-/// This `struct` is created by [`Generator::stack]. See its documentation for more.
+/// If both generators are curves, the stack is a curve as well. Its domain is the intersection
+/// of both domains, such that both curves are only evaluated within their own domain.
+/// If the domains are disjoint, the start of the returned domain is greater than its end.
+/// Similar, if both generators are discrete, the length of the stack is the smaller of both lengths.
+///
+/// This `struct` is created by [`Generator::stack()`]. See its documentation for more.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "linear", doc = "```rust")]
+#[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+/// # use enterpolation::{linear::{Linear, LinearError}, Curve, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// // author x(t) and y(t) separately
+/// let x = Linear::builder()
+///                 .elements([0.0,2.0,4.0])
+///                 .knots([0.0,1.0,2.0])
+///                 .build()?;
+/// let y = Linear::builder()
+///                 .elements([1.0,3.0])
+///                 .knots([0.5,3.0])
+///                 .build()?;
+/// let plot = x.stack(y);
+/// assert_eq!(plot.domain(), [0.5,2.0]);
+/// let (x, y) = plot.gen(1.5);
+/// assert_f64_near!(x, 3.0);
+/// assert_f64_near!(y, 1.8);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Stack<G, H>(G, H);
@@ -249,6 +384,7 @@ where
     H: Curve<R>,
     R: Real,
 {
+    /// Returns the intersection of the domains of both curves.
     fn domain(&self) -> [R; 2] {
         let first = self.0.domain();
         let second = self.1.domain();
@@ -256,6 +392,78 @@ where
     }
 }
 
+/// Adaptor which stacks several generators with the same output into one generator with arrays as output.
+///
+/// That is, the struct holds `N` generators with output T and outputs `[T;N]`.
+/// In contrast to nesting [`Stack`], the output does not consist of nested tuples.
+///
+/// This `struct` is created by [`utils::stack_array()`]. See its documentation for more.
+///
+/// [`utils::stack_array()`]: crate::utils::stack_array()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "G: serde::Serialize",
+        deserialize = "G: serde::Deserialize<'de>"
+    ))
+)]
+pub struct StackArray<G, const N: usize>(
+    #[cfg_attr(feature = "serde", serde(with = "crate::base::serde_array"))] [G; N],
+);
+
+impl<G, const N: usize> StackArray<G, N> {
+    /// Creates a generator which outputs the outputs of all given generators as an array.
+    pub fn new(generators: [G; N]) -> Self {
+        StackArray(generators)
+    }
+}
+
+impl<G, Input, const N: usize> Generator<Input> for StackArray<G, N>
+where
+    G: Generator<Input>,
+    Input: Copy,
+{
+    type Output = [G::Output; N];
+    fn gen(&self, input: Input) -> Self::Output {
+        core::array::from_fn(|i| self.0[i].gen(input))
+    }
+}
+
+impl<G, const N: usize> DiscreteGenerator for StackArray<G, N>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|gen| gen.len())
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+impl<G, const M: usize, const N: usize> ConstDiscreteGenerator<M> for StackArray<G, N> where
+    G: ConstDiscreteGenerator<M>
+{
+}
+
+impl<G, R, const N: usize> Curve<R> for StackArray<G, N>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0
+            .iter()
+            .fold([R::min_value(), R::max_value()], |acc, gen| {
+                let domain = gen.domain();
+                [acc[0].max(domain[0]), acc[1].min(domain[1])]
+            })
+    }
+}
+
 /// DiscreteGenerator Adaptor which repeats the underlying elements.
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -291,18 +499,175 @@ where
 
 impl<G> ConstDiscreteGenerator<{ usize::MAX }> for Repeat<G> where G: DiscreteGenerator {}
 
-/// Generator adaptor which repeats a fixed amount of first elements.
+/// DiscreteGenerator adaptor which repeats the underlying elements a fixed amount of times.
+///
+/// The length of the adaptor is the length of the underlying generator times the number of repetitions,
+/// saturating at `usize::MAX`.
+///
+/// This `struct` is created by [`DiscreteGenerator::repeat_n()`]. See its documentation for more.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RepeatN<G> {
+    inner: G,
+    n: usize,
+}
+
+impl<G> RepeatN<G> {
+    /// Repeat a given DiscreteGenerator `n` times.
+    pub fn new(gen: G, n: usize) -> Self {
+        RepeatN { inner: gen, n }
+    }
+}
+
+impl<G> Generator<usize> for RepeatN<G>
+where
+    G: DiscreteGenerator,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.inner
+            .gen(WrapMode::Repeat.index(input, self.inner.len()))
+    }
+}
+
+impl<G> DiscreteGenerator for RepeatN<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.inner.len().saturating_mul(self.n)
+    }
+}
+
+/// DiscreteGenerator adaptor which reflects the underlying elements at their boundaries.
+///
+/// That is, the elements are generated forth and back, without repeating the first and last element,
+/// for example `0,1,2,1,0,1,2,...`.
+/// Like [`Repeat`], this adaptor generates elements until `usize::MAX` is reached.
+///
+/// This `struct` is created by [`DiscreteGenerator::reflect()`]. See its documentation for more.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Reflect<G>(G);
+
+impl<G> Reflect<G> {
+    /// Reflect a given DiscreteGenerator pseudo-endlessly.
+    pub fn new(gen: G) -> Self {
+        Reflect(gen)
+    }
+}
+
+impl<G> Generator<usize> for Reflect<G>
+where
+    G: DiscreteGenerator,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0.gen(WrapMode::Mirror.index(input, self.0.len()))
+    }
+}
+
+impl<G> DiscreteGenerator for Reflect<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        usize::MAX
+    }
+}
+
+impl<G> ConstDiscreteGenerator<{ usize::MAX }> for Reflect<G> where G: DiscreteGenerator {}
+
+/// Policy of the [`Wrap`] adaptor on how to generate elements beyond the underlying ones.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WrapMode {
+    /// Repeat the last element.
+    Clamp,
+    /// Start again with the first element.
+    #[default]
+    Repeat,
+    /// Go back through the elements in reverse order, without repeating the last one.
+    Mirror,
+}
+
+impl WrapMode {
+    /// Maps any index to an index less than `len` according to the policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is zero.
+    fn index(self, input: usize, len: usize) -> usize {
+        match self {
+            WrapMode::Clamp => input.min(len - 1),
+            WrapMode::Repeat => input % len,
+            WrapMode::Mirror => {
+                if len == 1 {
+                    return 0;
+                }
+                let last = len - 1;
+                // if the period overflows, no input reaches its end
+                let index = match last.checked_mul(2) {
+                    Some(period) => input % period,
+                    None => input,
+                };
+                if index <= last {
+                    index
+                } else {
+                    last - (index - last)
+                }
+            }
+        }
+    }
+}
+
+/// Policy of an interpolation on how to handle inputs outside of its domain.
+///
+/// This policy is used by linear interpolations. Other interpolations, like bezier curves, are polynomials
+/// which are naturally defined outside of their domain and always extend their pieces.
+/// To hold their first and last element instead, use the [`clamp()`] adaptor.
+///
+/// [`clamp()`]: crate::Curve::clamp()
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Extrapolation {
+    /// Continue the first and last segment of the interpolation.
+    #[default]
+    Extend,
+    /// Hold the first and last element of the interpolation.
+    Clamp,
+    /// Treat such inputs as errors. Generating panics, fallible methods return `None`.
+    Error,
+}
+
+/// Generator adaptor which generates a fixed amount of elements after the underlying ones.
+///
+/// Which elements are generated is defined by the [`WrapMode`] used.
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Wrap<G> {
     inner: G,
     n: usize,
+    mode: WrapMode,
 }
 
 impl<G> Wrap<G> {
     /// Wrap the first `n` elements to the end.
     pub fn new(gen: G, n: usize) -> Self {
-        Wrap { inner: gen, n }
+        Wrap {
+            inner: gen,
+            n,
+            mode: WrapMode::Repeat,
+        }
+    }
+
+    /// Generate `n` additional elements at the end according to the given mode.
+    pub fn with_mode(gen: G, n: usize, mode: WrapMode) -> Self {
+        Wrap {
+            inner: gen,
+            n,
+            mode,
+        }
     }
 }
 
@@ -312,7 +677,7 @@ where
 {
     type Output = G::Output;
     fn gen(&self, input: usize) -> Self::Output {
-        self.inner.gen(input % self.inner.len())
+        self.inner.gen(self.mode.index(input, self.inner.len()))
     }
 }
 
@@ -325,35 +690,1363 @@ where
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::easing::Identity;
+/// Generator adaptor which calls a function with a reference of each generated element.
+///
+/// The elements itself are not changed. This is useful for debugging chains of adaptors.
+///
+/// This struct is created by the [`inspect()`] method of generators.
+///
+/// [`inspect()`]: crate::Generator::inspect()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Inspect<G, F> {
+    inner: G,
+    func: F,
+}
 
-    #[test]
-    fn input_transform() {
-        let identity = Identity {};
-        let transformed = TransformInput::new(identity, 0.0, 2.0);
-        assert_f64_near!(transformed.gen(1.0), 2.0);
-        let results = [0.0, 1.0, 2.0];
-        // try to extract
-        let extractor = transformed.extract([0.0, 0.5, 1.0]);
-        for (val, res) in extractor.zip(results.iter()) {
-            assert_f64_near!(val, res);
-        }
-        // try to take - should be the same as before as the domain should have changed accordingly
-        let transformed = TransformInput::new(identity, 0.0, 2.0);
-        for (val, res) in transformed
-            .take(results.len())
-            .zip(<Identity as Curve<f64>>::take(identity, results.len()))
-        {
-            assert_f64_near!(val, res);
+impl<G, F> Inspect<G, F> {
+    /// Create a new `Inspect` struct calling `func` on every generated element.
+    pub fn new(gen: G, func: F) -> Self {
+        Inspect { inner: gen, func }
+    }
+}
+
+impl<G, F, Input> Generator<Input> for Inspect<G, F>
+where
+    G: Generator<Input>,
+    F: Fn(&G::Output),
+{
+    type Output = G::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        let output = self.inner.gen(input);
+        (self.func)(&output);
+        output
+    }
+}
+
+impl<G, F> DiscreteGenerator for Inspect<G, F>
+where
+    G: DiscreteGenerator,
+    F: Fn(&G::Output),
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<G, F, const N: usize> ConstDiscreteGenerator<N> for Inspect<G, F>
+where
+    G: ConstDiscreteGenerator<N>,
+    F: Fn(&G::Output),
+{
+}
+
+impl<G, F, R> Curve<R> for Inspect<G, F>
+where
+    G: Curve<R>,
+    F: Fn(&G::Output),
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Generator adaptor which transforms each generated element with a function.
+///
+/// This struct is created by the [`map()`] method of generators. See its documentation for more.
+///
+/// [`map()`]: crate::Generator::map()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Map<G, F> {
+    inner: G,
+    func: F,
+}
+
+impl<G, F> Map<G, F> {
+    /// Create a new `Map` struct transforming every generated element with `func`.
+    pub fn new(gen: G, func: F) -> Self {
+        Map { inner: gen, func }
+    }
+}
+
+impl<G, F, Input, O> Generator<Input> for Map<G, F>
+where
+    G: Generator<Input>,
+    F: Fn(G::Output) -> O,
+{
+    type Output = O;
+    fn gen(&self, input: Input) -> Self::Output {
+        (self.func)(self.inner.gen(input))
+    }
+}
+
+impl<G, F, O> DiscreteGenerator for Map<G, F>
+where
+    G: DiscreteGenerator,
+    F: Fn(G::Output) -> O,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<G, F, O, const N: usize> ConstDiscreteGenerator<N> for Map<G, F>
+where
+    G: ConstDiscreteGenerator<N>,
+    F: Fn(G::Output) -> O,
+{
+}
+
+impl<G, F, O, R> Curve<R> for Map<G, F>
+where
+    G: Curve<R>,
+    F: Fn(G::Output) -> O,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Generator adaptor which combines the outputs of two generators with a function.
+///
+/// Like [`Stack`], both generators are given the same input. If both are curves, the domain is the
+/// intersection of both domains. If both are discrete, the length is the smaller of both lengths.
+///
+/// This struct is created by the [`zip_with()`] method of generators. See its documentation for more.
+///
+/// [`zip_with()`]: crate::Generator::zip_with()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ZipWith<G, H, F> {
+    first: G,
+    second: H,
+    func: F,
+}
+
+impl<G, H, F> ZipWith<G, H, F> {
+    /// Create a new `ZipWith` struct combining the outputs of both generators with `func`.
+    pub fn new(first: G, second: H, func: F) -> Self {
+        ZipWith {
+            first,
+            second,
+            func,
         }
     }
+}
 
-    #[test]
-    fn slice() {
-        let identity = Identity {};
+impl<G, H, F, Input, O> Generator<Input> for ZipWith<G, H, F>
+where
+    G: Generator<Input>,
+    H: Generator<Input>,
+    F: Fn(G::Output, H::Output) -> O,
+    Input: Copy,
+{
+    type Output = O;
+    fn gen(&self, input: Input) -> Self::Output {
+        (self.func)(self.first.gen(input), self.second.gen(input))
+    }
+}
+
+impl<G, H, F, O> DiscreteGenerator for ZipWith<G, H, F>
+where
+    G: DiscreteGenerator,
+    H: DiscreteGenerator,
+    F: Fn(G::Output, H::Output) -> O,
+{
+    fn len(&self) -> usize {
+        self.first.len().min(self.second.len())
+    }
+}
+
+impl<G, H, F, O, const N: usize> ConstDiscreteGenerator<N> for ZipWith<G, H, F>
+where
+    G: ConstDiscreteGenerator<N>,
+    H: ConstDiscreteGenerator<N>,
+    F: Fn(G::Output, H::Output) -> O,
+{
+}
+
+impl<G, H, F, O, R> Curve<R> for ZipWith<G, H, F>
+where
+    G: Curve<R>,
+    H: Curve<R>,
+    F: Fn(G::Output, H::Output) -> O,
+    R: Real,
+{
+    /// Returns the intersection of the domains of both curves.
+    fn domain(&self) -> [R; 2] {
+        let first = self.first.domain();
+        let second = self.second.domain();
+        [first[0].max(second[0]), first[1].min(second[1])]
+    }
+}
+
+/// Curve which approximates the derivative of another curve by finite differences.
+///
+/// This struct is created by the [`finite_difference()`] method of curves. See its documentation for more.
+///
+/// [`finite_difference()`]: crate::Curve::finite_difference()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FiniteDifference<C, R> {
+    inner: C,
+    step: R,
+}
+
+impl<C, R> FiniteDifference<C, R>
+where
+    R: Real,
+{
+    /// Create a new `FiniteDifference` struct using the given step size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not bigger than zero.
+    pub fn new(curve: C, step: R) -> Self {
+        assert!(step > R::zero(), "the step size has to be bigger than zero");
+        FiniteDifference { inner: curve, step }
+    }
+}
+
+impl<C, R> Generator<R> for FiniteDifference<C, R>
+where
+    C: Curve<R>,
+    C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    R: Real,
+{
+    type Output = C::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.inner.domain();
+        // the domain may be reversed
+        let (min, max) = (start.min(end), start.max(end));
+        // near the borders of the domain, the difference is one-sided
+        let lower = (input - self.step).max(input.min(min));
+        let upper = (input + self.step).min(input.max(max));
+        let difference = self.inner.gen(upper) - self.inner.gen(lower);
+        if upper <= lower {
+            // the domain has no width, such that the difference is zero
+            return difference;
+        }
+        difference * (upper - lower).recip()
+    }
+}
+
+impl<C, R> Curve<R> for FiniteDifference<C, R>
+where
+    C: Curve<R>,
+    C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Curve which approximates another curve by linear interpolation of equidistant samples.
+///
+/// The samples are taken once at creation, such that generating values is fast,
+/// independent of how expensive the original curve was. The domain stays the same.
+///
+/// This struct is created by the [`cached()`] method of curves. See its documentation for more.
+///
+/// [`cached()`]: crate::Curve::cached()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, R: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, R: serde::Deserialize<'de>"
+    ))
+)]
+pub struct CachedCurve<T, R, const N: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::base::serde_array"))]
+    samples: [T; N],
+    domain: [R; 2],
+}
+
+impl<T, R, const N: usize> CachedCurve<T, R, N>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a cache of the given curve with `N` equidistant samples over its domain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than 2 or if `N - 1` can not be converted to `R`.
+    pub fn new<C>(curve: &C) -> Self
+    where
+        C: Curve<R, Output = T>,
+    {
+        assert!(N > 1, "a cached curve needs at least two samples");
+        let domain = curve.domain();
+        let [start, end] = domain;
+        let step = (end - start) / R::from_usize(N - 1).unwrap();
+        let samples = core::array::from_fn(|i| {
+            if i == N - 1 {
+                curve.gen(end)
+            } else {
+                curve.gen(start + step * R::from_usize(i).unwrap())
+            }
+        });
+        CachedCurve { samples, domain }
+    }
+
+    /// Returns the samples this curve interpolates.
+    pub fn samples(&self) -> &[T; N] {
+        &self.samples
+    }
+}
+
+impl<T, R, const N: usize> Generator<R> for CachedCurve<T, R, N>
+where
+    T: Merge<R> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    type Output = T;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.domain;
+        let width = end - start;
+        if width.is_zero() {
+            return self.samples[0];
+        }
+        let normalized = (input - start) / width;
+        let (min_index, max_index, factor) =
+            ConstEquidistant::<R, N>::new().upper_border(normalized);
+        self.samples[min_index].merge(self.samples[max_index], factor)
+    }
+}
+
+impl<T, R, const N: usize> Curve<R> for CachedCurve<T, R, N>
+where
+    T: Merge<R> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        self.domain
+    }
+}
+
+/// Curve which lays several curves back-to-back.
+///
+/// The domain of each curve is moved such that it starts where the domain of the previous curve ends.
+/// The domain of the first curve is kept, such that contiguous curves keep their domains.
+/// At the seam of two curves, the latter curve is used.
+/// Inputs outside of the domain are passed to the first or last curve respectively.
+///
+/// This struct is created by [`utils::concat()`]. See its documentation for more.
+///
+/// [`utils::concat()`]: crate::utils::concat()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: serde::Serialize, R: serde::Serialize",
+        deserialize = "C: serde::Deserialize<'de>, R: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Concat<C, R, const N: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::base::serde_array"))]
+    curves: [C; N],
+    #[cfg_attr(feature = "serde", serde(with = "crate::base::serde_array"))]
+    ends: [R; N],
+    start: R,
+}
+
+impl<C, R, const N: usize> Concat<C, R, N>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    /// Create a curve of the given curves laid back-to-back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no curve is given.
+    pub fn new(curves: [C; N]) -> Self {
+        assert!(N > 0, "at least one curve has to be concatenated");
+        let start = curves[0].domain()[0];
+        let mut end = start;
+        let ends = core::array::from_fn(|i| {
+            let [curve_start, curve_end] = curves[i].domain();
+            end = end + (curve_end - curve_start);
+            end
+        });
+        Concat {
+            curves,
+            ends,
+            start,
+        }
+    }
+}
+
+impl<C, R, const N: usize> Generator<R> for Concat<C, R, N>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    type Output = C::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let index = self.ends.partition_point(|end| *end <= input).min(N - 1);
+        let offset = if index == 0 {
+            self.start
+        } else {
+            self.ends[index - 1]
+        };
+        let curve = &self.curves[index];
+        curve.gen(curve.domain()[0] + (input - offset))
+    }
+}
+
+impl<C, R, const N: usize> Curve<R> for Concat<C, R, N>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.start, self.ends[N - 1]]
+    }
+}
+
+/// Generator adaptor which snaps the generated values to evenly spaced levels.
+///
+/// This struct is created by the [`quantize_output()`] method of curves. See its documentation for more.
+///
+/// [`quantize_output()`]: crate::Curve::quantize_output()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct QuantizeOutput<G, T> {
+    inner: G,
+    levels: usize,
+    min: T,
+    max: T,
+}
+
+impl<G, T> QuantizeOutput<G, T>
+where
+    T: PartialOrd,
+{
+    /// Create a new adaptor which snaps the output to `levels` evenly spaced values in `[min, max]`.
+    ///
+    /// If `max` is smaller than `min`, both bounds are swapped.
+    /// If `levels` is less than 2 or `min` equals `max`, `min` is always generated.
+    pub fn new(gen: G, levels: usize, min: T, max: T) -> Self {
+        let (min, max) = if max < min { (max, min) } else { (min, max) };
+        QuantizeOutput {
+            inner: gen,
+            levels,
+            min,
+            max,
+        }
+    }
+}
+
+impl<G, T, Input> Generator<Input> for QuantizeOutput<G, T>
+where
+    G: Generator<Input, Output = T>,
+    T: Real + FromPrimitive,
+{
+    type Output = T;
+    fn gen(&self, input: Input) -> Self::Output {
+        let range = self.max - self.min;
+        if self.levels < 2 || range.is_zero() {
+            return self.min;
+        }
+        let steps = T::from_usize(self.levels - 1).unwrap();
+        // in contrast to `clamp`, this does not panic for NaN bounds
+        let value = self.inner.gen(input).max(self.min).min(self.max);
+        let level = ((value - self.min) / range * steps).round();
+        self.min + range * level / steps
+    }
+}
+
+impl<G, T, R> Curve<R> for QuantizeOutput<G, T>
+where
+    G: Curve<R, Output = T>,
+    T: Real + FromPrimitive,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Generator adaptor which adds a constant offset to the generated values.
+///
+/// This struct is created by the [`offset()`] method of curves. See its documentation for more.
+///
+/// [`offset()`]: crate::Curve::offset()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Offset<G, T> {
+    inner: G,
+    delta: T,
+}
+
+impl<G, T> Offset<G, T> {
+    /// Create a new adaptor which adds `delta` to every generated value.
+    pub fn new(gen: G, delta: T) -> Self {
+        Offset { inner: gen, delta }
+    }
+}
+
+impl<G, T, Input> Generator<Input> for Offset<G, T>
+where
+    G: Generator<Input, Output = T>,
+    T: Add<Output = T> + Copy,
+{
+    type Output = T;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.inner.gen(input) + self.delta
+    }
+}
+
+impl<G, T, R> Curve<R> for Offset<G, T>
+where
+    G: Curve<R, Output = T>,
+    T: Add<Output = T> + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Generator adaptor which multiplies the generated values with a constant factor.
+///
+/// This struct is created by the [`scale()`] method of curves. See its documentation for more.
+///
+/// [`scale()`]: crate::Curve::scale()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Scale<G, R> {
+    inner: G,
+    factor: R,
+}
+
+impl<G, R> Scale<G, R> {
+    /// Create a new adaptor which multiplies every generated value with `factor`.
+    pub fn new(gen: G, factor: R) -> Self {
+        Scale { inner: gen, factor }
+    }
+
+    /// Add a constant offset to the scaled values.
+    ///
+    /// This composes both adaptors into a single [`Affine`] adaptor.
+    pub fn offset<T>(self, delta: T) -> Affine<G, R, T> {
+        Affine::new(self.inner, self.factor, delta)
+    }
+}
+
+impl<G, R, Input> Generator<Input> for Scale<G, R>
+where
+    G: Generator<Input>,
+    G::Output: Mul<R, Output = G::Output>,
+    R: Copy,
+{
+    type Output = G::Output;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.inner.gen(input) * self.factor
+    }
+}
+
+impl<G, R> Curve<R> for Scale<G, R>
+where
+    G: Curve<R>,
+    G::Output: Mul<R, Output = G::Output>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Generator adaptor which first scales and then offsets the generated values.
+///
+/// This struct is created by calling [`offset()`] on a [`Scale`] adaptor.
+///
+/// [`offset()`]: Scale::offset()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Affine<G, R, T> {
+    inner: G,
+    factor: R,
+    delta: T,
+}
+
+impl<G, R, T> Affine<G, R, T> {
+    /// Create a new adaptor which multiplies every generated value with `factor` and adds `delta`.
+    pub fn new(gen: G, factor: R, delta: T) -> Self {
+        Affine {
+            inner: gen,
+            factor,
+            delta,
+        }
+    }
+}
+
+impl<G, R, T, Input> Generator<Input> for Affine<G, R, T>
+where
+    G: Generator<Input, Output = T>,
+    T: Mul<R, Output = T> + Add<Output = T> + Copy,
+    R: Copy,
+{
+    type Output = T;
+    fn gen(&self, input: Input) -> Self::Output {
+        self.inner.gen(input) * self.factor + self.delta
+    }
+}
+
+impl<G, R, T> Curve<R> for Affine<G, R, T>
+where
+    G: Curve<R, Output = T>,
+    T: Mul<R, Output = T> + Add<Output = T> + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Curve adaptor which reflects inputs outside of the domain back into it.
+///
+/// The input is mirrored at the boundaries of the domain, such that the curve is traversed back
+/// and forth repeatedly. This struct is created by the [`reflect_extrapolate()`] method of curves.
+///
+/// [`reflect_extrapolate()`]: crate::Curve::reflect_extrapolate()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ReflectDomain<G>(G);
+
+impl<G> ReflectDomain<G> {
+    /// Create a new `ReflectDomain` struct.
+    pub fn new(gen: G) -> Self {
+        ReflectDomain(gen)
+    }
+}
+
+impl<G, R> Generator<R> for ReflectDomain<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.0.domain();
+        let length = end - start;
+        if length <= R::zero() {
+            return self.0.gen(start);
+        }
+        let period = length + length;
+        let cycles = (input - start) / period;
+        // true modulo, such that also negative inputs are reflected correctly
+        let offset = (cycles - cycles.floor()) * period;
+        if offset > length {
+            self.0.gen(start + period - offset)
+        } else {
+            self.0.gen(start + offset)
+        }
+    }
+}
+
+impl<G, R> Curve<R> for ReflectDomain<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// Curve adaptor which repeats the curve periodically outside of its domain.
+///
+/// The input is reduced modulo the length of the domain, such that the curve tiles the real line.
+/// In contrast to [`Wrap`], which appends elements to discrete generators, this adaptor works
+/// on the input of curves. This struct is created by the [`wrap_domain()`] method of curves.
+///
+/// [`wrap_domain()`]: crate::Curve::wrap_domain()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct WrapDomain<G>(G);
+
+impl<G> WrapDomain<G> {
+    /// Create a new `WrapDomain` struct.
+    pub fn new(gen: G) -> Self {
+        WrapDomain(gen)
+    }
+}
+
+impl<G, R> Generator<R> for WrapDomain<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.0.domain();
+        let length = end - start;
+        if length <= R::zero() {
+            return self.0.gen(start);
+        }
+        let cycles = (input - start) / length;
+        // true modulo, such that also negative inputs are wrapped correctly
+        let offset = (cycles - cycles.floor()) * length;
+        self.0.gen(start + offset)
+    }
+}
+
+impl<G, R> Curve<R> for WrapDomain<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// Curve adaptor which traverses the curve backwards.
+///
+/// The input `t` is mapped to `start + end - t`, where `[start, end]` is the domain of the curve.
+/// This struct is created by the [`reverse()`] method of curves. See its documentation for more.
+///
+/// [`reverse()`]: crate::Curve::reverse()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Reversed<G>(G);
+
+impl<G> Reversed<G> {
+    /// Create a new `Reversed` struct.
+    pub fn new(gen: G) -> Self {
+        Reversed(gen)
+    }
+}
+
+impl<G, R> Generator<R> for Reversed<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let [start, end] = self.0.domain();
+        self.0.gen(start + end - input)
+    }
+}
+
+impl<G, R> Curve<R> for Reversed<G>
+where
+    G: Curve<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.0.domain()
+    }
+}
+
+/// DiscreteGenerator adaptor which generates the elements in reverse order.
+///
+/// This struct is created by the [`reversed()`] method of discrete generators. See its documentation for more.
+///
+/// [`reversed()`]: crate::DiscreteGenerator::reversed()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ReversedElements<G>(G);
+
+impl<G> ReversedElements<G> {
+    /// Create a new `ReversedElements` struct.
+    pub fn new(gen: G) -> Self {
+        ReversedElements(gen)
+    }
+}
+
+impl<G> Generator<usize> for ReversedElements<G>
+where
+    G: DiscreteGenerator,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0.gen(self.0.len() - 1 - input)
+    }
+}
+
+impl<G> DiscreteGenerator for ReversedElements<G>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<G, const N: usize> ConstDiscreteGenerator<N> for ReversedElements<G> where
+    G: ConstDiscreteGenerator<N>
+{
+}
+
+/// Wrapper for collections to generate their elements by cloning them.
+///
+/// Collections like `Vec<T>` or `[T; N]` are only generators if their elements are `Copy`.
+/// Wrapping them allows elements which are only `Clone`, like elements owning heap memory.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{Cloned, DiscreteGenerator, Generator};
+/// let elements = Cloned::new([[1.0, 2.0], [3.0, 4.0]].map(|x| x.to_vec()));
+/// assert_eq!(elements.len(), 2);
+/// assert_eq!(elements.gen(1), [3.0, 4.0].to_vec());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Cloned<C>(C);
+
+impl<C> Cloned<C> {
+    /// Create a new `Cloned` struct.
+    pub fn new(collection: C) -> Self {
+        Cloned(collection)
+    }
+
+    /// Return the wrapped collection.
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Generator<usize> for Cloned<Vec<T>> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0[input].clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> DiscreteGenerator for Cloned<Vec<T>> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Generator<usize> for Cloned<VecDeque<T>> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0[input].clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> DiscreteGenerator for Cloned<VecDeque<T>> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Generator<usize> for Cloned<Box<[T]>> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0[input].clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> DiscreteGenerator for Cloned<Box<[T]>> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: Clone> Generator<usize> for Cloned<&[T]> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0[input].clone()
+    }
+}
+
+impl<T: Clone> DiscreteGenerator for Cloned<&[T]> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T: Clone, const N: usize> Generator<usize> for Cloned<[T; N]> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.0[input].clone()
+    }
+}
+
+impl<T: Clone, const N: usize> DiscreteGenerator for Cloned<[T; N]> {
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Clone, const N: usize> ConstDiscreteGenerator<N> for Cloned<[T; N]> {}
+
+/// DiscreteGenerator adaptor which generates overlapping windows of consecutive elements.
+///
+/// The element at index `i` is the array of the elements of the underlying generator
+/// at the indices `i` up to `i+W-1`.
+///
+/// This struct is created by the [`array_windows()`] method of discrete generators. See its documentation for more.
+///
+/// [`array_windows()`]: crate::DiscreteGenerator::array_windows()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Windows<G, const W: usize>(G);
+
+impl<G, const W: usize> Windows<G, W> {
+    /// Create a new `Windows` struct.
+    ///
+    /// `W` has to be at least one, otherwise compilation fails.
+    pub fn new(gen: G) -> Self {
+        const { assert!(W > 0, "windows have to contain at least one element") };
+        Windows(gen)
+    }
+}
+
+impl<G, const W: usize> Generator<usize> for Windows<G, W>
+where
+    G: DiscreteGenerator,
+{
+    type Output = [G::Output; W];
+    fn gen(&self, input: usize) -> Self::Output {
+        core::array::from_fn(|i| self.0.gen(input + i))
+    }
+}
+
+impl<G, const W: usize> DiscreteGenerator for Windows<G, W>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        (self.0.len() + 1).saturating_sub(W)
+    }
+}
+
+/// DiscreteGenerator adaptor which groups consecutive elements into non-overlapping arrays.
+///
+/// The element at index `i` is the array of the elements of the underlying generator
+/// at the indices `i*C` up to `i*C+C-1`. Trailing elements which do not fill a whole chunk are ignored.
+///
+/// This struct is created by the [`array_chunks()`] method of discrete generators. See its documentation for more.
+///
+/// [`array_chunks()`]: crate::DiscreteGenerator::array_chunks()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Chunks<G, const C: usize>(G);
+
+impl<G, const C: usize> Chunks<G, C> {
+    /// Create a new `Chunks` struct.
+    ///
+    /// `C` has to be at least one, otherwise compilation fails.
+    pub fn new(gen: G) -> Self {
+        const { assert!(C > 0, "chunks have to contain at least one element") };
+        Chunks(gen)
+    }
+}
+
+impl<G, const C: usize> Generator<usize> for Chunks<G, C>
+where
+    G: DiscreteGenerator,
+{
+    type Output = [G::Output; C];
+    fn gen(&self, input: usize) -> Self::Output {
+        core::array::from_fn(|i| self.0.gen(input * C + i))
+    }
+}
+
+impl<G, const C: usize> DiscreteGenerator for Chunks<G, C>
+where
+    G: DiscreteGenerator,
+{
+    fn len(&self) -> usize {
+        self.0.len() / C
+    }
+}
+
+/// Curve adaptor which holds the output constant within given ranges of the input.
+///
+/// For each dead zone `[a,b]`, all inputs inside of it generate the value of the curve at `a`.
+/// Outside of the dead zones, the curve is left unchanged.
+/// This struct is created by the [`dead_zone()`] method of curves. See its documentation for more.
+///
+/// [`dead_zone()`]: crate::Curve::dead_zone()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DeadZone<G, Z> {
+    inner: G,
+    zones: Z,
+}
+
+impl<G, Z, R> DeadZone<G, Z>
+where
+    G: Curve<R>,
+    Z: DiscreteGenerator<Output = [R; 2]>,
+    R: Real,
+{
+    /// Create a new adaptor with the given dead zones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDeadZone`] if a dead zone is not within the domain of the curve,
+    /// its start is bigger than its end or if it overlaps with the dead zone before.
+    /// As such, the dead zones have to be given in increasing order.
+    pub fn new(gen: G, zones: Z) -> Result<Self, InvalidDeadZone> {
+        let [start, end] = gen.domain();
+        let mut last = start;
+        for (index, [zone_start, zone_end]) in zones.iter().enumerate() {
+            if zone_start < last || zone_end < zone_start || zone_end > end {
+                return Err(InvalidDeadZone::new(index));
+            }
+            last = zone_end;
+        }
+        Ok(DeadZone { inner: gen, zones })
+    }
+}
+
+impl<G, Z> DeadZone<G, Z> {
+    /// Create a new adaptor with the given dead zones.
+    ///
+    /// # Panics
+    ///
+    /// The dead zones should be within the domain of the curve, non-overlapping and in increasing order.
+    /// Otherwise the adaptor may generate unexpected values.
+    pub fn new_unchecked(gen: G, zones: Z) -> Self {
+        DeadZone { inner: gen, zones }
+    }
+}
+
+impl<G, Z, R> Generator<R> for DeadZone<G, Z>
+where
+    G: Generator<R>,
+    Z: DiscreteGenerator<Output = [R; 2]>,
+    R: Real,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        // search for the number of dead zones starting at or before the input
+        let mut pointer = 0;
+        let mut dist = self.zones.len();
+        while dist > 0 {
+            let step = dist / 2;
+            let sample = pointer + step;
+            if input >= self.zones.gen(sample)[0] {
+                pointer = sample + 1;
+                dist -= step + 1;
+            } else {
+                dist = step;
+            }
+        }
+        if pointer > 0 {
+            let [zone_start, zone_end] = self.zones.gen(pointer - 1);
+            if input <= zone_end {
+                return self.inner.gen(zone_start);
+            }
+        }
+        self.inner.gen(input)
+    }
+}
+
+impl<G, Z, R> Curve<R> for DeadZone<G, Z>
+where
+    G: Curve<R>,
+    Z: DiscreteGenerator<Output = [R; 2]>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+/// Curve adaptor which reparameterizes a curve by its arc length.
+///
+/// The input of this curve is the length along the original curve, such that equidistant inputs
+/// result in equidistant points along the curve. The domain is `[0.0, total_length]`.
+/// This struct is created by the [`arc_length()`] method of curves. See its documentation for more.
+///
+/// [`arc_length()`]: crate::Curve::arc_length()
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcLength<G, R> {
+    inner: G,
+    /// The cumulative lengths at equidistant inputs of the inner curve.
+    lengths: Vec<R>,
+}
+
+#[cfg(feature = "std")]
+impl<G, R> ArcLength<G, R>
+where
+    G: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    /// Create a new adaptor by sampling the given curve `samples` times.
+    ///
+    /// The lengths between successive samples are measured with the given distance function,
+    /// for example the absolute difference for scalars or the euclidean distance for vectors.
+    /// More samples result in a more accurate approximation of the arc length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is less than 2 or if `samples - 1` can not be converted to `R`.
+    pub fn new<F>(curve: G, samples: usize, distance: F) -> Self
+    where
+        F: Fn(G::Output, G::Output) -> R,
+        G::Output: Copy,
+    {
+        assert!(samples > 1, "the arc length needs at least two samples");
+        let mut lengths = Vec::with_capacity(samples);
+        let mut length = R::zero();
+        let mut values = (&curve).take(samples);
+        // unwrap is fine as we take at least two samples
+        let mut last = values.next().unwrap();
+        lengths.push(length);
+        for value in values {
+            length = length + distance(last, value);
+            lengths.push(length);
+            last = value;
+        }
+        ArcLength {
+            inner: curve,
+            lengths,
+        }
+    }
+
+    /// Returns the approximated length of the whole curve.
+    pub fn total_length(&self) -> R {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Returns a curve with domain `[0.0,1.0]`, where the input is the fraction of the total length.
+    pub fn normalized(self) -> TransformInput<Self, R, R> {
+        let total = self.total_length();
+        TransformInput::new(self, R::zero(), total)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G, R> Generator<R> for ArcLength<G, R>
+where
+    G: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    type Output = G::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        let last = self.lengths.len() - 1;
+        // index of the first sample whose length is bigger than the input
+        let max_index = self
+            .lengths
+            .partition_point(|length| *length <= input)
+            .clamp(1, last);
+        let min_index = max_index - 1;
+        let span = self.lengths[max_index] - self.lengths[min_index];
+        let factor = if span > R::zero() {
+            (input - self.lengths[min_index]) / span
+        } else {
+            R::zero()
+        };
+        // refine linearly between the inputs of the samples
+        let [start, end] = self.inner.domain();
+        let step = (end - start) / R::from_usize(last).unwrap();
+        let position = R::from_usize(min_index).unwrap() + factor;
+        self.inner.gen(start + step * position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G, R> Curve<R> for ArcLength<G, R>
+where
+    G: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), self.total_length()]
+    }
+}
+
+/// DiscreteGenerator adaptor which remembers every generated element.
+///
+/// Each element is generated at most once by the inner generator, afterwards a copy of the stored element
+/// is returned. This is useful if generating elements is expensive and they are needed multiple times,
+/// for example as elements of a bspline. The cache is thread-safe.
+/// This struct is created by the [`cache()`] method of discrete generators. See its documentation for more.
+///
+/// [`cache()`]: crate::DiscreteGenerator::cache()
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache<G, T> {
+    inner: G,
+    cache: Vec<OnceLock<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<G> Cache<G, G::Output>
+where
+    G: DiscreteGenerator,
+{
+    /// Create a new, empty cache for the given generator.
+    ///
+    /// Memory for all elements of the generator is reserved immediately.
+    pub fn new(gen: G) -> Self {
+        let cache = (0..gen.len()).map(|_| OnceLock::new()).collect();
+        Cache { inner: gen, cache }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G> Generator<usize> for Cache<G, G::Output>
+where
+    G: DiscreteGenerator,
+    G::Output: Clone,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        self.cache[input]
+            .get_or_init(|| self.inner.gen(input))
+            .clone()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G> DiscreteGenerator for Cache<G, G::Output>
+where
+    G: DiscreteGenerator,
+    G::Output: Clone,
+{
+    fn len(&self) -> usize {
+        self.cache.len()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G, const N: usize> ConstDiscreteGenerator<N> for Cache<G, G::Output>
+where
+    G: ConstDiscreteGenerator<N>,
+    G::Output: Clone,
+{
+}
+
+/// Error returned if a dead zone is invalid.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InvalidDeadZone {
+    index: usize,
+}
+
+impl InvalidDeadZone {
+    /// Create a new error with the index of the invalid dead zone.
+    pub fn new(index: usize) -> Self {
+        InvalidDeadZone { index }
+    }
+}
+
+impl fmt::Display for InvalidDeadZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Dead zone {} is invalid. Dead zones have to be inside the domain, non-overlapping and in increasing order.",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidDeadZone {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::{FuncEase, Identity};
+    use crate::Equidistant;
+
+    #[test]
+    fn finite_difference() {
+        let square = FuncEase::new(|x: f64| x * x);
+        let derivative = square.finite_difference(1e-5);
+        assert_eq!(derivative.domain(), [0.0, 1.0]);
+        for (input, result) in [(0.5, 1.0), (0.25, 0.5)] {
+            assert_f64_near!(derivative.gen(input), result, 1 << 24);
+        }
+        // one-sided differences at the borders have an error of about the step size
+        assert!((derivative.gen(0.0) - 0.0).abs() < 2e-5);
+        assert!((derivative.gen(1.0) - 2.0).abs() < 2e-5);
+        // reversed domain
+        let falling = CachedCurve {
+            samples: [0.0, 2.0],
+            domain: [1.0, 0.0],
+        };
+        let derivative = falling.finite_difference(1e-5);
+        for input in [0.0, 0.5, 1.0] {
+            assert!((derivative.gen(input) + 2.0).abs() < 1e-9);
+        }
+        // domain without width
+        let point = CachedCurve {
+            samples: [3.0, 4.0],
+            domain: [1.0, 1.0],
+        };
+        assert_f64_near!(point.finite_difference(1e-5).gen(1.0), 0.0);
+    }
+
+    #[test]
+    fn zip_with() {
+        let zipped = [1.0, 2.0, 3.0].zip_with([4.0, 5.0], |a, b| a * b);
+        assert_eq!(zipped.len(), 2);
+        assert_eq!([zipped.gen(0), zipped.gen(1)], [4.0, 10.0]);
+        let first = TransformInput::new(Identity {}, 0.0, 2.0);
+        let second = TransformInput::new(Identity {}, -0.25, 1.0);
+        let sum = ZipWith::new(first, second, |a: f64, b: f64| a + b);
+        assert_eq!(sum.domain(), [0.25, 0.5]);
+        assert_f64_near!(sum.gen(0.5), 1.25);
+    }
+
+    #[test]
+    fn input_transform() {
+        let identity = Identity {};
+        let transformed = TransformInput::new(identity, 0.0, 2.0);
+        assert_f64_near!(transformed.gen(1.0), 2.0);
+        let results = [0.0, 1.0, 2.0];
+        // try to extract
+        let extractor = transformed.extract([0.0, 0.5, 1.0]);
+        for (val, res) in extractor.zip(results.iter()) {
+            assert_f64_near!(val, res);
+        }
+        // try to take - should be the same as before as the domain should have changed accordingly
+        let transformed = TransformInput::new(identity, 0.0, 2.0);
+        for (val, res) in transformed
+            .take(results.len())
+            .zip(<Identity as Curve<f64>>::take(identity, results.len()))
+        {
+            assert_f64_near!(val, res);
+        }
+    }
+
+    #[test]
+    fn domain_to_domain() {
+        let curve = TransformInput::normalized_to_domain(Identity {}, 0.0, 4.0);
+        let transformed = TransformInput::domain_to_domain(curve, [-1.0, 1.0], [0.0, 4.0]);
+        assert_f64_near!(transformed.gen(-1.0), 0.0);
+        assert_f64_near!(transformed.gen(0.0), 0.5);
+        assert_f64_near!(transformed.gen(0.5), 0.75);
+        let [start, end] = transformed.domain();
+        assert_f64_near!(start, -1.0);
+        assert_f64_near!(end, 1.0);
+        // mapping onto a reversed interval
+        let reversed = TransformInput::domain_to_domain(Identity {}, [0.0, 2.0], [1.0, 0.0]);
+        assert_f64_near!(reversed.gen(0.5), 0.75);
+    }
+
+    #[test]
+    fn slice() {
+        let identity = Identity {};
         let slice = Slice::new(identity, 10.0..100.0);
         let results = [10.0, 100.0];
         assert_f64_near!(slice.gen(0.0), 10.0);
@@ -362,4 +2055,426 @@ mod test {
             assert_f64_near!(val, res);
         }
     }
+
+    #[test]
+    fn slice_endpoints() {
+        let curve = TransformInput::new(FuncEase::new(|x: f64| x.sin()), -1.0, 0.5);
+        assert_eq!(curve.domain(), [2.0, 4.0]);
+        let ranges = [(0.1, 0.7), (0.7, 1.3), (1.3, 0.3)];
+        for (start, end) in ranges {
+            let slice = curve.slice(start..end);
+            assert_eq!(slice.domain(), [2.0, 4.0]);
+            assert_eq!(slice.gen(2.0), curve.gen(start));
+            assert_eq!(slice.gen(4.0), curve.gen(end));
+            assert_eq!(slice.take(7).next_back(), Some(curve.gen(end)));
+            assert_f64_near!(slice.gen(3.0), curve.gen((start + end) / 2.0));
+        }
+        // unbounded ends are replaced by the ends of the domain
+        let slice = curve.slice(3.0..);
+        assert_eq!(slice.gen(2.0), curve.gen(3.0));
+        assert_eq!(slice.gen(4.0), curve.gen(4.0));
+        let slice = curve.slice(..=3.0);
+        assert_eq!(slice.gen(2.0), curve.gen(2.0));
+        assert_eq!(slice.gen(4.0), curve.gen(3.0));
+        let slice = curve.slice(..);
+        assert_eq!(slice.gen(2.5), curve.gen(2.5));
+    }
+
+    #[test]
+    fn repeat_n_and_reflect() {
+        let elements = [0, 1, 2];
+        let repeated = elements.repeat_n(3);
+        assert_eq!(repeated.len(), 9);
+        for index in 0..9 {
+            assert_eq!(repeated.gen(index), index % 3);
+        }
+        assert_eq!(elements.repeat_n(0).len(), 0);
+        let huge = elements.repeat_n(usize::MAX);
+        assert_eq!(huge.len(), usize::MAX);
+        assert_eq!(huge.gen(usize::MAX - 1), 2);
+        let reflected = elements.reflect();
+        let results = [0, 1, 2, 1, 0, 1, 2, 1, 0];
+        for (index, result) in results.iter().enumerate() {
+            assert_eq!(reflected.gen(index), result);
+        }
+        assert_eq!([7].reflect().gen(5), 7);
+        assert_eq!(reflected.gen(usize::MAX), 1);
+        // the period of huge generators overflows
+        let wide = Equidistant::<f64>::new(usize::MAX, 0.0, 1.0).reflect();
+        assert_f64_near!(wide.gen(usize::MAX - 1), 1.0);
+    }
+
+    #[test]
+    fn wrap_modes() {
+        let elements = [0, 1, 2, 3];
+        let clamp = Wrap::with_mode(elements, 3, WrapMode::Clamp);
+        assert_eq!(clamp.len(), 7);
+        assert_eq!(clamp.gen(3), 3);
+        assert_eq!(clamp.gen(4), 3);
+        assert_eq!(clamp.gen(6), 3);
+        let repeat = Wrap::with_mode(elements, 5, WrapMode::Repeat);
+        assert_eq!(repeat.len(), 9);
+        assert_eq!(repeat.gen(3), 3);
+        assert_eq!(repeat.gen(4), 0);
+        assert_eq!(repeat.gen(7), 3);
+        assert_eq!(repeat.gen(8), 0);
+        let mirror = Wrap::with_mode(elements, 6, WrapMode::Mirror);
+        assert_eq!(mirror.len(), 10);
+        assert_eq!(mirror.gen(3), 3);
+        assert_eq!(mirror.gen(4), 2);
+        assert_eq!(mirror.gen(6), 0);
+        assert_eq!(mirror.gen(7), 1);
+        assert_eq!(mirror.gen(9), 3);
+        let single = Wrap::with_mode([5], 2, WrapMode::Mirror);
+        assert_eq!(single.gen(2), 5);
+    }
+
+    #[test]
+    fn inspect() {
+        use core::cell::Cell;
+        let counter = Cell::new(0);
+        let inspected = Inspect::new([1.0, 2.0, 3.0], |val: &f64| {
+            counter.set(counter.get() + 1);
+            assert!(*val > 0.0);
+        });
+        assert_eq!(inspected.len(), 3);
+        assert_f64_near!(inspected.gen(1), 2.0);
+        assert_f64_near!(inspected.gen(2), 3.0);
+        assert_eq!(counter.get(), 2);
+        let curve = Inspect::new(Identity {}, |_: &f64| counter.set(counter.get() + 1));
+        assert_eq!(curve.domain(), [0.0, 1.0]);
+        assert_f64_near!(curve.gen(0.25), 0.25);
+        assert_eq!(counter.get(), 3);
+    }
+
+    #[test]
+    fn cached() {
+        let curve = TransformInput::new(Identity {}, 1.0, 2.0);
+        let cached = CachedCurve::<_, _, 5>::new(&curve);
+        assert_eq!(cached.domain(), [-0.5, 0.0]);
+        for input in [-0.5, -0.3, -0.125, 0.0, 1.0] {
+            assert_f64_near!(cached.gen(input), curve.gen(input));
+        }
+        let square = FuncEase::new(|x: f64| x * x);
+        let cached = CachedCurve::<_, _, 3>::new(&square);
+        assert_eq!(cached.samples(), &[0.0, 0.25, 1.0]);
+        assert_f64_near!(cached.gen(0.25), 0.125);
+        assert_f64_near!(cached.gen(0.75), 0.625);
+        // a domain without width generates the first sample
+        let point = CachedCurve {
+            samples: [3.0, 4.0],
+            domain: [1.0, 1.0],
+        };
+        assert_f64_near!(point.gen(1.0), 3.0);
+        assert_f64_near!(point.gen(2.0), 3.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn cached_serde() {
+        let cached = CachedCurve::<_, _, 3>::new(&FuncEase::new(|x: f64| x * x));
+        let json = serde_json::to_string(&cached).unwrap();
+        let restored: CachedCurve<f64, f64, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cached);
+    }
+
+    #[test]
+    fn quantize_output() {
+        let quantized = QuantizeOutput::new(Identity {}, 3, 0.0, 1.0);
+        let expected = [0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0];
+        for (value, result) in quantized.take(expected.len()).zip(expected) {
+            assert_f64_near!(value, result);
+        }
+        assert_f64_near!(quantized.gen(-2.0), 0.0);
+        assert_f64_near!(quantized.gen(2.0), 1.0);
+        let single = QuantizeOutput::new(Identity {}, 1, 0.25, 1.0);
+        assert_f64_near!(single.gen(0.8), 0.25);
+        let flat = QuantizeOutput::new(Identity {}, 3, 0.5, 0.5);
+        assert_f64_near!(flat.gen(0.8), 0.5);
+        assert_f64_near!(flat.gen(-1.0), 0.5);
+        // reversed bounds are swapped
+        let reversed = QuantizeOutput::new(Identity {}, 3, 1.0, 0.0);
+        assert_f64_near!(reversed.gen(0.3), 0.5);
+        assert_f64_near!(reversed.gen(2.0), 1.0);
+    }
+
+    #[test]
+    fn wrap_domain() {
+        let wrapped = WrapDomain::new(TransformInput::new(Identity {}, -1.0, 0.5));
+        assert_eq!(wrapped.domain(), [2.0, 4.0]);
+        let expected = [
+            (2.0, 0.0),
+            (3.0, 0.5),
+            (4.0, 0.0),
+            (4.5, 0.25),
+            (7.0, 0.5),
+            (1.5, 0.75),
+            (0.0, 0.0),
+            (-13.0, 0.5),
+            (-14.5, 0.75),
+        ];
+        for (input, result) in expected {
+            assert_f64_near!(wrapped.gen(input), result);
+        }
+    }
+
+    #[test]
+    fn reflect_domain() {
+        let reflected = ReflectDomain::new(TransformInput::new(Identity {}, -1.0, 0.5));
+        assert_eq!(reflected.domain(), [2.0, 4.0]);
+        let expected = [
+            (2.0, 0.0),
+            (3.0, 0.5),
+            (4.0, 1.0),
+            (4.5, 0.75),
+            (6.0, 0.0),
+            (7.0, 0.5),
+            (1.5, 0.25),
+            (0.0, 1.0),
+            (-13.0, 0.5),
+            (-14.5, 0.25),
+        ];
+        for (input, result) in expected {
+            assert_f64_near!(reflected.gen(input), result);
+        }
+    }
+
+    #[test]
+    fn dead_zone() {
+        let curve = TransformInput::new(Identity {}, 0.0, 0.1);
+        let dead = DeadZone::new(curve, [[2.0, 4.0], [4.0, 5.0], [8.0, 10.0]]).unwrap();
+        let inputs = [0.0, 1.0, 2.0, 3.0, 4.0, 4.5, 5.0, 6.0, 9.0, 10.0, 11.0];
+        let results = [0.0, 0.1, 0.2, 0.2, 0.4, 0.4, 0.4, 0.6, 0.8, 0.8, 1.1];
+        for (value, result) in dead.sample(inputs).zip(results) {
+            assert_f64_near!(value, result);
+        }
+        assert_eq!(
+            DeadZone::new(curve, [[2.0, 4.0], [3.0, 5.0]]),
+            Err(InvalidDeadZone::new(1))
+        );
+        assert_eq!(
+            DeadZone::new(curve, [[-1.0, 4.0]]),
+            Err(InvalidDeadZone::new(0))
+        );
+        assert_eq!(
+            DeadZone::new(curve, [[4.0, 2.0]]),
+            Err(InvalidDeadZone::new(0))
+        );
+        assert_eq!(
+            DeadZone::new(curve, [[8.0, 11.0]]),
+            Err(InvalidDeadZone::new(0))
+        );
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn arc_length() {
+        // the speed of this curve increases linearly
+        let curve = FuncEase::new(|x: f64| x * x);
+        let normalized = curve.arc_length(1001, |a: f64, b: f64| (b - a).abs());
+        assert_f64_near!(normalized.total_length(), 1.0);
+        assert_eq!(normalized.domain(), [0.0, 1.0]);
+        for i in 0..=10 {
+            let input = i as f64 / 10.0;
+            assert!((normalized.gen(input) - input).abs() < 1e-6);
+        }
+        let long = Stack::new(
+            FuncEase::new(|x: f64| 3.0 * x),
+            FuncEase::new(|x: f64| 4.0 * x),
+        );
+        let long = ArcLength::new(long, 11, |a: (f64, f64), b: (f64, f64)| {
+            (b.0 - a.0).hypot(b.1 - a.1)
+        });
+        assert_f64_near!(long.total_length(), 5.0);
+        let half = long.normalized().gen(0.5);
+        assert_f64_near!(half.0, 1.5);
+        assert_f64_near!(half.1, 2.0);
+    }
+
+    #[test]
+    fn reversed() {
+        let curve = FuncEase::new(|x: f64| x * x);
+        let reversed = curve.reverse();
+        assert_eq!(reversed.domain(), [0.0, 1.0]);
+        assert_f64_near!(reversed.gen(0.0), 1.0);
+        assert_f64_near!(reversed.gen(0.25), 0.5625);
+        assert_f64_near!(reversed.gen(1.0), 0.0);
+        assert_f64_near!(curve.reverse().clamp().gen(2.0), 0.0);
+        let twice = curve.reverse().reverse();
+        for input in [0.0, 0.3, 0.5, 0.9, 1.0] {
+            assert_f64_near!(twice.gen(input), curve.gen(input));
+        }
+        let elements = [1, 2, 3, 4].reversed();
+        assert_eq!(elements.len(), 4);
+        assert_eq!(elements.to_array(), [4, 3, 2, 1]);
+        assert_eq!(elements.reversed().to_array(), [1, 2, 3, 4]);
+    }
+
+    #[cfg(feature = "linear")]
+    #[test]
+    fn stack_curve() {
+        use crate::linear::Linear;
+        let first = Linear::builder()
+            .elements([0.0, 1.0])
+            .knots([0.2, 0.8])
+            .build()
+            .unwrap();
+        let second = FuncEase::new(|x: f64| 2.0 * x);
+        let stack = first.stack(second);
+        assert_eq!(stack.domain(), [0.2, 0.8]);
+        assert_eq!(second.stack(first).domain(), [0.2, 0.8]);
+        let (x, y) = stack.gen(0.5);
+        assert_f64_near!(x, 0.5);
+        assert_f64_near!(y, 1.0);
+        // disjoint domains result in an empty domain
+        let disjoint = Linear::builder()
+            .elements([0.0, 1.0])
+            .knots([1.0, 2.0])
+            .build()
+            .unwrap()
+            .stack(first);
+        let [start, end] = disjoint.domain();
+        assert!(start > end);
+    }
+
+    #[test]
+    fn stack_array() {
+        let points = StackArray::new([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+        assert_eq!(points.len(), 3);
+        assert_eq!(points.gen(2), [2.0, 5.0, 8.0]);
+        assert_eq!(
+            points.to_array(),
+            [[0.0, 3.0, 6.0], [1.0, 4.0, 7.0], [2.0, 5.0, 8.0]]
+        );
+        let curves = StackArray::new([
+            TransformInput::normalized_to_domain(Identity {}, 0.0, 2.0),
+            TransformInput::normalized_to_domain(Identity {}, 0.0, 4.0),
+        ]);
+        assert_eq!(curves.domain(), [0.0, 2.0]);
+        assert_eq!(curves.gen(1.0), [0.5, 0.25]);
+    }
+
+    #[test]
+    fn array_windows() {
+        let windows = [1, 2, 3, 4].array_windows::<3>();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(windows.gen(0), [1, 2, 3]);
+        assert_eq!(windows.gen(1), [2, 3, 4]);
+        assert_eq!([1, 2, 3, 4].array_windows::<4>().len(), 1);
+        assert_eq!([1, 2, 3].array_windows::<4>().len(), 0);
+        assert_eq!([1, 2, 3].array_windows::<1>().gen(2), [3]);
+    }
+
+    #[test]
+    fn array_chunks() {
+        let chunks = [1, 2, 3, 4, 5, 6, 7].array_chunks::<2>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.gen(0), [1, 2]);
+        assert_eq!(chunks.gen(2), [5, 6]);
+        assert_eq!([1, 2, 3].array_chunks::<4>().len(), 0);
+        assert_eq!([1, 2, 3].array_chunks::<1>().gen(2), [3]);
+    }
+
+    #[test]
+    fn concat() {
+        let curve = Concat::new([
+            TransformInput::normalized_to_domain(Identity {}, 0.0, 2.0),
+            TransformInput::normalized_to_domain(Identity {}, 5.0, 6.0),
+        ]);
+        assert_eq!(curve.domain(), [0.0, 3.0]);
+        let inputs = [-1.0, 0.0, 1.0, 2.0, 2.5, 3.0, 4.0];
+        let results = [-0.5, 0.0, 0.5, 0.0, 0.5, 1.0, 2.0];
+        for (value, result) in curve.sample(inputs).zip(results) {
+            assert_f64_near!(value, result);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn stack_array_serde() {
+        let stacked = StackArray::new([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let json = serde_json::to_string(&stacked).unwrap();
+        let restored: StackArray<[f64; 2], 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, stacked);
+        assert_eq!(restored.gen(1), [2.0, 4.0, 6.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn concat_serde() {
+        let curve: Concat<Identity, f64, 2> = Concat::new([Identity::new(), Identity::new()]);
+        let json = serde_json::to_string(&curve).unwrap();
+        let restored: Concat<Identity, f64, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, curve);
+        let invalid = json.replace("[{},{}]", "[{}]");
+        assert_ne!(invalid, json);
+        assert!(serde_json::from_str::<Concat<Identity, f64, 2>>(&invalid).is_err());
+    }
+
+    #[test]
+    fn map() {
+        let curve = FuncEase::new(|x: f64| x * x).map(|x| [x, x * 2.0]);
+        assert_eq!(curve.domain(), [0.0, 1.0]);
+        assert_eq!(curve.gen(0.5), [0.25, 0.5]);
+        // arrays have an inherent `map` method, such that the trait method has to be called explicitly
+        let elements = Generator::map([1, 2, 3], |x| x * 10);
+        assert_eq!(elements.gen(2), 30);
+        assert_eq!(elements.to_array(), [10, 20, 30]);
+        let elements = Map::new([1, 2, 3], |x: i32| x > 1);
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.to_array(), [false, true, true]);
+    }
+
+    #[test]
+    fn ease_input() {
+        let curve = FuncEase::new(|x: f64| x).slice(1.0..=3.0);
+        let eased = curve.ease_input(|x: f64| x * x);
+        assert_eq!(eased.domain(), [0.0, 1.0]);
+        assert_f64_near!(eased.gen(0.5), 1.5);
+        // inputs are clamped to the domain
+        assert_f64_near!(eased.gen(-1.0), 1.0);
+        assert_f64_near!(eased.gen(2.0), 3.0);
+        let curve =
+            TransformInput::domain_to_domain(FuncEase::new(|x: f64| x), [2.0, 4.0], [0.0, 1.0]);
+        let eased = curve.ease_input(|x: f64| x * x);
+        assert_eq!(eased.domain(), [2.0, 4.0]);
+        assert_f64_near!(eased.gen(3.0), 0.25);
+        // reversed domains are eased from their start to their end
+        let curve = TransformInput::new(Identity {}, 0.0, -1.0);
+        let eased = curve.ease_input(|x: f64| x * x);
+        assert_eq!(eased.domain(), [0.0, -1.0]);
+        assert_f64_near!(eased.gen(-0.5), 0.25);
+        assert_f64_near!(eased.gen(1.0), 0.0);
+        assert_f64_near!(eased.gen(-2.0), 1.0);
+    }
+
+    #[test]
+    fn offset_and_scale() {
+        let curve = FuncEase::new(|x: f64| x * x);
+        let offset = curve.offset(1.0);
+        assert_eq!(offset.domain(), [0.0, 1.0]);
+        assert_f64_near!(offset.gen(0.5), 1.25);
+        let scale = curve.scale(4.0);
+        assert_eq!(scale.domain(), [0.0, 1.0]);
+        assert_f64_near!(scale.gen(0.5), 1.0);
+        let affine: Affine<_, _, _> = curve.scale(4.0).offset(1.0);
+        assert_eq!(affine.domain(), [0.0, 1.0]);
+        assert_f64_near!(affine.gen(0.5), 2.0);
+        // the order of adaptors matters
+        assert_f64_near!(curve.offset(1.0).scale(4.0).gen(0.5), 5.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn cache() {
+        use core::cell::Cell;
+        let calls = Cell::new(0);
+        let elements = [1.0, 2.0, 3.0].inspect(|_| calls.set(calls.get() + 1));
+        let cached = elements.cache();
+        assert_eq!(cached.len(), 3);
+        assert_f64_near!(cached.gen(1), 2.0);
+        assert_f64_near!(cached.gen(1), 2.0);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(cached.to_array(), [1.0, 2.0, 3.0]);
+        assert_eq!(calls.get(), 3);
+    }
 }
@@ -1,11 +1,27 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
 
+use core::fmt::Debug;
 use core::iter::FusedIterator;
-use core::ops::RangeBounds;
+use core::ops::{Add, Bound, Mul, RangeBounds, Sub};
 
 use super::Equidistant;
-use super::{Clamp, Composite, Repeat, Slice, Stack};
+#[cfg(feature = "std")]
+use super::{ArcLength, Cache};
+use super::{
+    CachedCurve, Chunks, Clamp, Composite, DeadZone, EasedInput, FiniteDifference, Inspect,
+    InvalidDeadZone, Map, Offset, QuantizeOutput, Reflect, ReflectDomain, Repeat, RepeatN,
+    Reversed, ReversedElements, Scale, Slice, Stack, Windows, Wrap, WrapDomain, WrapMode, ZipWith,
+};
+use crate::easing::Easing;
+#[cfg(feature = "linear")]
+use crate::linear::{Linear, LinearError, TooFewElements};
+#[cfg(feature = "linear")]
+use crate::Identity;
+#[cfg(any(feature = "linear", feature = "alloc"))]
+use topology_traits::Merge;
 
 /// Trait which symbolises the generation or copying of an element.
 ///
@@ -17,6 +33,23 @@ pub trait Generator<Input> {
     type Output;
     /// Method to generate the element at the given input
     fn gen(&self, input: Input) -> Self::Output;
+    /// Generate the element at the given input.
+    ///
+    /// This is an alias of [`gen()`], as `gen` is a reserved keyword since the 2024 edition of Rust.
+    /// Implementors should only implement [`gen()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{easing::Identity, Generator};
+    /// let identity = Identity::new();
+    /// assert_eq!(identity.evaluate(0.5), identity.gen(0.5));
+    /// ```
+    ///
+    /// [`gen()`]: Self::gen()
+    fn evaluate(&self, input: Input) -> Self::Output {
+        self.gen(input)
+    }
     /// Helper function if one wants to extract values from the interpolation.
     ///
     /// It takes an iterator of items which are inputed into the [`gen()`] method
@@ -59,6 +92,7 @@ pub trait Generator<Input> {
     /// Stack two generators together
     ///
     /// That is for two generators with output `T` and `R` the created generators output will be `(T,R)`.
+    /// Stacking two curves results in a curve, whose domain is the intersection of both domains.
     ///
     /// # Examples
     ///
@@ -126,6 +160,110 @@ pub trait Generator<Input> {
     {
         Composite::new(self, gen)
     }
+    /// Creates a generator which calls a function with a reference of each generated element.
+    ///
+    /// The generated elements are not changed. This is useful for debugging long chains of adaptors,
+    /// similar to the `inspect` method of iterators.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .inspect(|value| println!("generated {}", value));
+    /// assert_f64_near!(linear.gen(0.5), 1.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn inspect<F>(self, func: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Output),
+    {
+        Inspect::new(self, func)
+    }
+    /// Creates a generator which transforms each generated element with the given function.
+    ///
+    /// This is the counterpart of [`composite()`], which transforms the input instead,
+    /// and works similar to the `map` method of iterators.
+    /// The domain or length of the generator stays the same.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .map(|x| x * 2.0);
+    /// assert_f64_near!(linear.gen(0.5), 3.0);
+    /// assert_eq!(linear.domain(), [0.0,1.0]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`composite()`]: Self::composite()
+    fn map<F, O>(self, func: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: Fn(Self::Output) -> O,
+    {
+        Map::new(self, func)
+    }
+    /// Creates a generator which combines the outputs of both generators with the given function.
+    ///
+    /// This works like [`stack()`] followed by [`map()`], without the intermediate tuple.
+    /// Combining two curves results in a curve, whose domain is the intersection of both domains.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let amplitude = Linear::builder()
+    ///                 .elements([1.0,3.0])
+    ///                 .knots([0.0,2.0])
+    ///                 .build()?;
+    /// let envelope = Linear::builder()
+    ///                 .elements([0.0,1.0,0.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let modulated = amplitude.zip_with(envelope, |a, e| a * e);
+    /// assert_f64_near!(modulated.gen(1.0), 2.0);
+    /// assert_f64_near!(modulated.gen(1.5), 1.25);
+    /// assert_eq!(modulated.domain(), [0.0,2.0]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`stack()`]: Self::stack()
+    /// [`map()`]: Self::map()
+    fn zip_with<G, F, O>(self, gen: G, func: F) -> ZipWith<Self, G, F>
+    where
+        Self: Sized,
+        G: Generator<Input>,
+        F: Fn(Self::Output, G::Output) -> O,
+    {
+        ZipWith::new(self, gen, func)
+    }
     /// Get a reference of the generator.
     ///
     /// This is useful if one wants to add an adaptor without consuming the original.
@@ -194,6 +332,10 @@ where
     fn domain(&self) -> [R; 2];
     /// Takes equidistant samples of the curve.
     ///
+    /// The samples are taken from the start of the domain to its end. If the start of the domain is
+    /// greater than its end, the inputs of the samples are decreasing.
+    /// The returned iterator is double-ended, such that its samples can also be taken in reverse order.
+    ///
     /// # Examples
     ///
     #[cfg_attr(feature = "linear", doc = "```rust")]
@@ -226,9 +368,146 @@ where
         let [start, end] = self.domain();
         Take(self.extract(Stepper::new(samples, start, end)))
     }
+    /// Takes equidistant samples of the curve in parallel.
+    ///
+    /// The samples are the same as the ones of [`take()`], but they are generated on multiple threads
+    /// and collected into a vector. As such, the curve has to be [`Sync`] and its output has to be [`Send`].
+    /// For small numbers of samples, the overhead of distributing the work may outweigh its benefits,
+    /// so [`take()`] should stay the default.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "rayon"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "rayon")), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let samples = linear.par_sample(11);
+    /// let results = [0.0,1.0,2.0,3.0,4.0,5.0,4.6,4.2,3.8,3.4,3.0];
+    /// for (value,result) in samples.into_iter().zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples - 1` can not be converted to the type `R`.
+    ///
+    /// [`take()`]: Curve::take()
+    #[cfg(feature = "rayon")]
+    fn par_sample(&self, samples: usize) -> Vec<Self::Output>
+    where
+        Self: Sync,
+        Self::Output: Send,
+        R: FromPrimitive + Sync,
+    {
+        use rayon::prelude::*;
+        let [start, end] = self.domain();
+        let inputs = Equidistant::new(samples, start, end);
+        (0..samples)
+            .into_par_iter()
+            .map(|index| self.gen(inputs.gen(index)))
+            .collect()
+    }
+    /// Collect equidistant samples of the curve together with their inputs.
+    ///
+    /// This works like [`take()`], however the samples are collected into a vector of `(input, output)` pairs.
+    /// This is useful for plotting or exporting the curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let samples = linear.collect_curve(5);
+    /// let results = [(0.0,0.0),(0.5,2.5),(1.0,5.0),(1.5,4.0),(2.0,3.0)];
+    /// for ((input,value),(x,y)) in samples.into_iter().zip(results){
+    ///     assert_f64_near!(input, x);
+    ///     assert_f64_near!(value, y);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples - 1` can not be converted to the type `R`.
+    ///
+    /// [`take()`]: Curve::take()
+    #[cfg(feature = "alloc")]
+    fn collect_curve(&self, samples: usize) -> Vec<(R, Self::Output)>
+    where
+        R: FromPrimitive,
+    {
+        let [start, end] = self.domain();
+        let inputs = Equidistant::new(samples, start, end);
+        let mut vec = Vec::with_capacity(samples);
+        vec.extend(inputs.into_iter().map(|input| (input, self.gen(input))));
+        vec
+    }
+    /// Takes equidistant samples of the curve together with their input and tangent.
+    ///
+    /// The iterator yields `(input, value, tangent)` for each sample, which is useful for example to draw
+    /// oriented markers along a path. The tangent is approximated by central differences,
+    /// which become one-sided at the borders of the domain, such that the curve is never sampled outside of it.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let expected = [(0.0,0.0,5.0),(0.5,2.5,5.0),(1.5,4.0,-2.0),(2.0,3.0,-2.0)];
+    /// let samples: Vec<_> = linear.take_with_tangent(5).collect();
+    /// // at the input 1.0 the tangent is not defined, such we skip it
+    /// for ((input, value, tangent), result) in samples[..2].iter().chain(&samples[3..]).zip(expected) {
+    ///     assert_f64_near!(*input, result.0);
+    ///     assert_f64_near!(*value, result.1);
+    ///     assert!((tangent - result.2).abs() < 1e-6);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+    fn take_with_tangent(&self, samples: usize) -> TakeWithTangent<&Self, R>
+    where
+        Self: Sized,
+        Self::Output: Sub<Output = Self::Output> + Mul<R, Output = Self::Output>,
+        R: FromPrimitive,
+    {
+        TakeWithTangent::new(self, samples)
+    }
     /// Take a slice of a curve.
     ///
     /// A slice of a curve maps its domain onto the given range.
+    /// The ends of the domain are mapped exactly onto the ends of the range,
+    /// while unbounded ends of the range are replaced by the ends of the domain.
     ///
     /// # Examples
     ///
@@ -258,6 +537,43 @@ where
     {
         Slice::new(self, bounds)
     }
+    /// Reshape the input of the curve with an easing.
+    ///
+    /// The input is clamped to the domain and normalized to [0.0,1.0], such that the easing
+    /// only changes the speed with which the curve is traversed, but not its domain.
+    /// In contrast to [`composite()`], no curve has to be constructed for the easing.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, easing::smoothstep, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let curve = Bezier::builder()
+    ///                 .elements([0.0,10.0])
+    ///                 .domain(0.0,2.0)
+    ///                 .constant::<2>()
+    ///                 .build()?;
+    /// let eased = curve.ease_input(smoothstep);
+    /// assert_eq!(eased.domain(), [0.0,2.0]);
+    /// assert_f64_near!(eased.gen(0.5), 1.5625);
+    /// assert_f64_near!(eased.gen(1.0), 5.0);
+    /// assert_f64_near!(eased.gen(3.0), 10.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`composite()`]: crate::Generator::composite()
+    fn ease_input<F>(self, easing: F) -> EasedInput<Self, F>
+    where
+        Self: Sized,
+        F: Easing<R>,
+    {
+        EasedInput::new(self, easing)
+    }
     /// Clamp the input of a curve to its domain.
     ///
     /// # Examples
@@ -267,36 +583,864 @@ where
     /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
     /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
     /// #
-    /// # fn main() -> Result<(), LinearError> {
-    /// let linear = Linear::builder()
-    ///                 .elements([0.0,3.0])
-    ///                 .knots([0.0,1.0])
-    ///                 .build()?
-    ///                 .clamp();
-    /// let expected = [[-1.0,0.0],[0.0,0.0],[0.5,1.5],[1.0,3.0],[2.0,3.0]];
-    /// for [input,result] in expected {
-    ///     assert_f64_near!(linear.gen(input), result);
-    /// }
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .clamp();
+    /// let expected = [[-1.0,0.0],[0.0,0.0],[0.5,1.5],[1.0,3.0],[2.0,3.0]];
+    /// for [input,result] in expected {
+    ///     assert_f64_near!(linear.gen(input), result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn clamp(self) -> Clamp<Self>
+    where
+        Self: Sized,
+    {
+        Clamp::new(self)
+    }
+    /// Reflect inputs outside of the domain back into the domain.
+    ///
+    /// Instead of extrapolating, the curve is traversed back and forth when leaving its domain,
+    /// as if the domain was mirrored at its boundaries repeatedly.
+    /// In contrast to [`clamp()`], the curve does not freeze at the boundaries.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .reflect_extrapolate();
+    /// let expected = [[-0.5,1.5],[0.5,1.5],[1.25,2.25],[2.0,0.0],[3.5,1.5]];
+    /// for [input,result] in expected {
+    ///     assert_f64_near!(linear.gen(input), result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`clamp()`]: Self::clamp()
+    fn reflect_extrapolate(self) -> ReflectDomain<Self>
+    where
+        Self: Sized,
+    {
+        ReflectDomain::new(self)
+    }
+    /// Repeat the curve periodically outside of its domain.
+    ///
+    /// The input is reduced modulo the length of the domain, such that the curve starts over
+    /// whenever it leaves its domain. This is useful for looping animations.
+    /// Negative inputs are wrapped as well. The domain itself stays the same.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .wrap_domain();
+    /// let expected = [[-0.25,2.25],[0.5,1.5],[1.3,0.9],[2.0,0.0],[3.5,1.5]];
+    /// for [input,result] in expected {
+    ///     assert_f64_near!(linear.gen(input), result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn wrap_domain(self) -> WrapDomain<Self>
+    where
+        Self: Sized,
+    {
+        WrapDomain::new(self)
+    }
+    /// Traverse the curve backwards.
+    ///
+    /// The input `t` is mapped to `start + end - t`, where `[start, end]` is the domain of the curve.
+    /// The domain itself stays the same.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0,4.0])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .build()?
+    ///                 .reverse();
+    /// let expected = [[0.0,4.0],[1.0,3.5],[2.0,3.0],[3.0,0.0]];
+    /// for [input,result] in expected {
+    ///     assert_f64_near!(linear.gen(input), result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn reverse(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed::new(self)
+    }
+    /// Snap the output of the curve to `levels` evenly spaced values between `min` and `max`.
+    ///
+    /// Each generated value is clamped to `[min, max]` and mapped to the nearest level.
+    /// This creates flat plateaus in the output, for example for posterized gradients.
+    /// In contrast to quantizing the input, the curve is still evaluated at the input given.
+    ///
+    /// If `levels` is less than 2 or `min` equals `max`, `min` is always returned.
+    /// If `max` is smaller than `min`, both bounds are swapped.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,10.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .quantize_output(3, 0.0, 10.0);
+    /// let expected = [0.0,0.0,5.0,5.0,5.0,10.0];
+    /// for (value, result) in linear.sample([0.0,0.2,0.3,0.5,0.7,0.8]).zip(expected) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn quantize_output(
+        self,
+        levels: usize,
+        min: Self::Output,
+        max: Self::Output,
+    ) -> QuantizeOutput<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Real + FromPrimitive,
+    {
+        QuantizeOutput::new(self, levels, min, max)
+    }
+    /// Add a constant offset to the output of the curve.
+    ///
+    /// The domain of the curve is unchanged.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,10.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?
+    ///                 .offset(5.0);
+    /// assert_eq!(linear.domain(), [0.0,1.0]);
+    /// assert_f64_near!(linear.gen(0.0), 5.0);
+    /// assert_f64_near!(linear.gen(0.5), 10.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn offset(self, delta: Self::Output) -> Offset<Self, Self::Output>
+    where
+        Self: Sized,
+        Self::Output: Add<Output = Self::Output> + Copy,
+    {
+        Offset::new(self, delta)
+    }
+    /// Multiply the output of the curve with a constant factor.
+    ///
+    /// The domain of the curve is unchanged.
+    /// Calling [`offset()`] on the returned adaptor composes both into a single [`Affine`] adaptor.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let gradient = Linear::builder()
+    ///                 .elements([0.1,0.3])
+    ///                 .knots([0.0,1.0])
+    ///                 .build()?;
+    /// let brighter = gradient.scale(2.0).offset(0.1);
+    /// assert_f64_near!(brighter.gen(0.0), 0.3);
+    /// assert_f64_near!(brighter.gen(1.0), 0.7);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`offset()`]: crate::Scale::offset()
+    /// [`Affine`]: crate::Affine
+    fn scale(self, factor: R) -> Scale<Self, R>
+    where
+        Self: Sized,
+        Self::Output: Mul<R, Output = Self::Output>,
+    {
+        Scale::new(self, factor)
+    }
+    /// Hold the output of the curve constant within the given dead zones.
+    ///
+    /// For each dead zone `[a,b]`, all inputs inside of it generate the value of the curve at `a`.
+    /// Outside of the dead zones, the curve is left unchanged.
+    /// This is useful for example to remap the input of joysticks.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+    /// # use enterpolation::{linear::Linear, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let linear = Linear::builder()
+    ///                 .elements([-1.0,1.0])
+    ///                 .knots([-1.0,1.0])
+    ///                 .build()?
+    ///                 .dead_zone([[-0.1,0.1]])?;
+    /// let expected = [-0.5,-0.1,-0.1,-0.1,0.5];
+    /// for (value, result) in linear.sample([-0.5,-0.1,0.0,0.1,0.5]).zip(expected) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDeadZone`] if a dead zone is not within the domain of the curve,
+    /// its start is bigger than its end or if it overlaps with the dead zone before.
+    /// As such, the dead zones have to be given in increasing order.
+    ///
+    /// [`InvalidDeadZone`]: crate::InvalidDeadZone
+    fn dead_zone<Z>(self, zones: Z) -> Result<DeadZone<Self, Z>, InvalidDeadZone>
+    where
+        Self: Sized,
+        Z: DiscreteGenerator<Output = [R; 2]>,
+    {
+        DeadZone::new(self, zones)
+    }
+    /// Cache the curve by taking `N` equidistant samples of it.
+    ///
+    /// The returned curve has the same domain and generates values by linear interpolation
+    /// of the samples. This accelerates repeated evaluations of expensive curves at the cost of accuracy.
+    ///
+    /// For a curve with a continuous second deriative bounded by `M`, the error within the domain
+    /// is at most `M * h * h / 8`, where `h = (end - start) / (N - 1)` is the distance between samples.
+    /// Such doubling `N` roughly quarters the error.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([0.0,4.0,0.0])
+    ///                 .normalized::<f64>()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let cached = bezier.cached::<101>();
+    /// assert_eq!(cached.domain(), bezier.domain());
+    /// assert_f64_near!(cached.gen(0.5), bezier.gen(0.5));
+    /// // the second deriative is -16, such the error is at most 0.0002
+    /// assert!((cached.gen(0.333) - bezier.gen(0.333)).abs() <= 0.0002);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than 2 or if `N - 1` can not be converted to `R`.
+    fn cached<const N: usize>(&self) -> CachedCurve<Self::Output, R, N>
+    where
+        Self: Sized,
+        R: FromPrimitive + Debug,
+    {
+        CachedCurve::new(self)
+    }
+    /// Approximate the derivative of the curve by finite differences with the given step size.
+    ///
+    /// The returned curve generates the central difference `(gen(t+step) - gen(t-step)) / (2*step)`.
+    /// Near the borders of the domain, the difference is taken one-sided, such that the curve
+    /// is never evaluated outside of its domain for inputs inside of it.
+    /// This works for all curves, while some interpolations also provide an exact derivative.
+    ///
+    /// The choice of the step size is a tradeoff. The error of the central difference shrinks
+    /// quadratically with the step size, the error of one-sided differences only linearly.
+    /// However, smaller step sizes amplify the round-off error of the generated values by about
+    /// `epsilon / step`. For curves of moderate scale, a step size of about the cube root of `epsilon`,
+    /// that is `1e-5` for `f64`, balances both. Where the curve is not differentiable, as at the knots
+    /// of a linear interpolation, the result is a mean of both sides.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator, Curve};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([0.0,4.0,0.0])
+    ///                 .normalized::<f64>()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// // the exact derivative is 8 - 16t
+    /// let velocity = bezier.finite_difference(1e-5);
+    /// assert!((velocity.gen(0.25) - 4.0).abs() < 1e-6);
+    /// // one-sided at the border of the domain
+    /// assert!((velocity.gen(0.0) - 8.0).abs() < 1e-3);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not bigger than zero.
+    fn finite_difference(self, step: R) -> FiniteDifference<Self, R>
+    where
+        Self: Sized,
+        Self::Output: Sub<Output = Self::Output> + Mul<R, Output = Self::Output>,
+    {
+        FiniteDifference::new(self, step)
+    }
+    /// Reparameterize the curve by its arc length, such that the curve is traversed with constant speed.
+    ///
+    /// The curve is sampled equidistantly `samples` times within its domain to build a table of
+    /// cumulative lengths, which are measured with the given distance function.
+    /// The returned curve takes the length along the curve as input and has the domain `[0.0, total_length]`.
+    /// Use [`normalized()`] to get a curve with domain `[0.0,1.0]` instead.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,5.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?
+    ///                 .arc_length(101, |a: f64, b: f64| (b - a).abs());
+    /// assert_f64_near!(linear.total_length(), 5.0);
+    /// assert_f64_near!(linear.gen(3.0), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is less than 2 or if `samples - 1` can not be converted to `R`.
+    ///
+    /// [`normalized()`]: ArcLength::normalized()
+    #[cfg(feature = "std")]
+    fn arc_length<F>(self, samples: usize, distance: F) -> ArcLength<Self, R>
+    where
+        Self: Sized,
+        Self::Output: Copy,
+        R: FromPrimitive,
+        F: Fn(Self::Output, Self::Output) -> R,
+    {
+        ArcLength::new(self, samples, distance)
+    }
+    /// Approximate the total variation of the curve, that is the sum of the magnitude of all changes.
+    ///
+    /// The curve is sampled equidistantly `samples` times within its domain and the magnitudes
+    /// of the differences of successive samples are summed up. The magnitude is given by the closure,
+    /// for example the absolute value for scalars or the euclidean norm for vectors.
+    /// For vectors, the total variation is an approximation of the length of the curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// assert_f64_near!(linear.total_variation(11, |diff: f64| diff.abs()), 7.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is 0 or if `samples - 1` can not be converted to the type `R`.
+    fn total_variation<F>(&self, samples: usize, magnitude: F) -> R
+    where
+        Self: Sized,
+        Self::Output: Sub<Output = Self::Output> + Copy,
+        R: FromPrimitive,
+        F: Fn(Self::Output) -> R,
+    {
+        let mut values = self.take(samples);
+        let mut last = match values.next() {
+            Some(value) => value,
+            None => return R::zero(),
+        };
+        values.fold(R::zero(), |sum, value| {
+            let diff = value - last;
+            last = value;
+            sum + magnitude(diff)
+        })
+    }
+    /// Approximate the definite integral of the curve from `start` to `end`.
+    ///
+    /// The integral is approximated by adaptive Simpson quadrature. Intervals are halved as long as
+    /// the magnitude of the estimated error is bigger than their share of `tolerance`.
+    /// The magnitude is given by the closure, for example the absolute value for scalars or
+    /// the euclidean norm for vectors. After 20 halvings, an interval is accepted regardless of its error.
+    /// If `start` is bigger than `end`, the integral is negative.
+    ///
+    /// This approximation is available for all curves. Linear interpolations without easing and bezier curves
+    /// additionally have an inherent `integral()` method, which calculates the integral exactly and should be preferred.
+    /// The methods are named differently on purpose, such that the exact method does not shadow this one
+    /// and it is always clear from the name whether a result is exact or approximated.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// // a speed profile which accelerates quadratically
+    /// let speed = Linear::builder()
+    ///                 .elements([0.0,4.0])
+    ///                 .knots([0.0,2.0])
+    ///                 .easing(|x: f64| x * x)
+    ///                 .build()?;
+    /// let distance = speed.integrate(0.0, 2.0, 1e-9, |x: f64| x.abs());
+    /// assert_f64_near!(distance, 8.0 / 3.0, 8);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn integrate<F>(&self, start: R, end: R, tolerance: R, magnitude: F) -> Self::Output
+    where
+        Self: Sized,
+        Self::Output: Add<Output = Self::Output>
+            + Sub<Output = Self::Output>
+            + Mul<R, Output = Self::Output>
+            + Copy,
+        R: FromPrimitive,
+        F: Fn(Self::Output) -> R,
+    {
+        let first = self.gen(start);
+        let last = self.gen(end);
+        let middle = self.gen((start + end) / R::from_usize(2).unwrap());
+        let whole = simpson(first, middle, last, end - start);
+        adaptive_simpson(
+            self,
+            [start, end],
+            [first, middle, last],
+            whole,
+            tolerance,
+            &magnitude,
+            20,
+        )
+    }
+    /// Approximate the inputs at which the curvature of the curve changes its sign.
+    ///
+    /// The curve is sampled equidistantly `samples` times within its domain. At each sample
+    /// the sign of the curvature is approximated with finite differences of its neighbours.
+    /// The given closure receives the first and second difference and has to return a value with
+    /// the same sign as the curvature. For curves with scalar output this is just the second difference,
+    /// for curves in the plane the cross product of both differences.
+    ///
+    /// Each bracket with a sign change is then bisected `bisections` times and its center is returned.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "bezier", feature = "alloc"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "bezier", feature = "alloc")), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Curve};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([0.0,1.0,-1.0,0.0])
+    ///                 .normalized::<f64>()
+    ///                 .constant::<4>()
+    ///                 .build()?;
+    /// let inflections = bezier.inflection_points(10, 30, |_, second| second);
+    /// assert_eq!(inflections.len(), 1);
+    /// assert!((inflections[0] - 0.5).abs() < 1e-6);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples - 1` can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn inflection_points<F>(&self, samples: usize, bisections: usize, signed_curvature: F) -> Vec<R>
+    where
+        Self::Output: Sub<Output = Self::Output> + Copy,
+        R: FromPrimitive,
+        F: Fn(Self::Output, Self::Output) -> R,
+    {
+        let mut inflections = Vec::new();
+        if samples < 3 {
+            return inflections;
+        }
+        let [start, end] = self.domain();
+        let step = (end - start) / R::from_usize(samples - 1).unwrap();
+        let curvature = |input: R| {
+            let before = self.gen(input - step);
+            let current = self.gen(input);
+            let after = self.gen(input + step);
+            signed_curvature(after - before, (after - current) - (current - before))
+        };
+        let mut lower = start + step;
+        let mut lower_curvature = curvature(lower);
+        for i in 2..samples - 1 {
+            let upper = start + step * R::from_usize(i).unwrap();
+            let upper_curvature = curvature(upper);
+            if lower_curvature.is_zero() {
+                inflections.push(lower);
+            } else if lower_curvature.signum() != upper_curvature.signum()
+                && !upper_curvature.is_zero()
+            {
+                let mut left = lower;
+                let mut left_curvature = lower_curvature;
+                let mut right = upper;
+                for _ in 0..bisections {
+                    let middle = (left + right) / R::from_usize(2).unwrap();
+                    let middle_curvature = curvature(middle);
+                    if middle_curvature.is_zero() {
+                        left = middle;
+                        right = middle;
+                        break;
+                    }
+                    if middle_curvature.signum() == left_curvature.signum() {
+                        left = middle;
+                        left_curvature = middle_curvature;
+                    } else {
+                        right = middle;
+                    }
+                }
+                inflections.push((left + right) / R::from_usize(2).unwrap());
+            }
+            lower = upper;
+            lower_curvature = upper_curvature;
+        }
+        if lower_curvature.is_zero() {
+            inflections.push(lower);
+        }
+        inflections
+    }
+    /// Write equidistant samples of the curve directly into the given writer.
+    ///
+    /// For each of the `samples` inputs, the closure is called with the writer, the input and
+    /// the value of the curve at that input. As such the closure decides the layout of the output.
+    /// No samples are collected in memory, which allows to stream huge numbers of samples into a file.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+    /// # use enterpolation::{linear::Linear, Curve};
+    /// # use std::io::Write;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let mut output = Vec::new();
+    /// linear.write_samples(5, &mut output, |writer, input, value| {
+    ///     writeln!(writer, "{}, {}", input, value)
+    /// })?;
+    /// assert_eq!(String::from_utf8(output)?, "0, 0\n0.5, 2.5\n1, 5\n1.5, 4\n2, 3\n");
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the closure, after which no further samples are written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+    #[cfg(feature = "std")]
+    fn write_samples<W, F>(
+        &self,
+        samples: usize,
+        writer: &mut W,
+        mut format: F,
+    ) -> std::io::Result<()>
+    where
+        W: std::io::Write,
+        R: FromPrimitive,
+        F: FnMut(&mut W, R, Self::Output) -> std::io::Result<()>,
+    {
+        let [start, end] = self.domain();
+        for input in Stepper::new(samples, start, end) {
+            format(writer, input, self.gen(input))?;
+        }
+        Ok(())
+    }
+    /// Fill the given slice with equidistant samples of the curve.
+    ///
+    /// As many samples as the slice is long are taken over the whole domain.
+    /// No memory is allocated, such that a buffer can be reused, for example in real-time loops.
+    /// If the slice has only one element, the curve is sampled at the start of its domain.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let mut buffer = [0.0; 5];
+    /// linear.sample_into(&mut buffer);
+    /// let results = [0.0,2.5,5.0,4.0,3.0];
+    /// for (value,result) in buffer.into_iter().zip(results) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice minus one can not be converted to the type `R`.
+    fn sample_into(&self, out: &mut [Self::Output])
+    where
+        R: FromPrimitive,
+    {
+        self.sample_range_into(.., out)
+    }
+    /// Fill the given slice with equidistant samples of the curve within the given range.
+    ///
+    /// This works like [`sample_into()`], however the samples are taken over the given range instead of the domain.
+    /// Unbounded ends of the range are replaced by the ends of the domain.
+    /// It does not matter if the bounds itself are included or excluded as we assume a continuous curve.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::{Linear, LinearError}, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .build()?;
+    /// let mut buffer = [0.0; 3];
+    /// linear.sample_range_into(1.0.., &mut buffer);
+    /// let results = [5.0,4.0,3.0];
+    /// for (value,result) in buffer.into_iter().zip(results) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice minus one can not be converted to the type `R`.
+    ///
+    /// [`sample_into()`]: Self::sample_into()
+    fn sample_range_into<B>(&self, range: B, out: &mut [Self::Output])
+    where
+        B: RangeBounds<R>,
+        R: FromPrimitive,
+    {
+        let [domain_start, domain_end] = self.domain();
+        let start = match range.start_bound() {
+            Bound::Included(x) | Bound::Excluded(x) => *x,
+            Bound::Unbounded => domain_start,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(x) | Bound::Excluded(x) => *x,
+            Bound::Unbounded => domain_end,
+        };
+        match out.len() {
+            0 => {}
+            1 => out[0] = self.gen(start),
+            len => {
+                for (slot, input) in out.iter_mut().zip(Stepper::new(len, start, end)) {
+                    *slot = self.gen(input);
+                }
+            }
+        }
+    }
+}
+
+//Make references of curves also curves
+impl<C: Curve<R> + ?Sized, R> Curve<R> for &C
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        (**self).domain()
+    }
+}
+
+/// Curves whose point closest to a target can be searched.
+///
+/// This trait is implemented for all curves, as the search only uses the values of the curve.
+pub trait Projectable<R>: Curve<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Search the input whose value is closest to the given target.
+    ///
+    /// Returns the found input together with the distance of its value to the target.
+    /// The distance between two values is given by the closure,
+    /// for example the absolute value of their difference or the euclidean distance.
+    ///
+    /// The curve is sampled equidistantly `samples` times within its domain. The input is then refined
+    /// with `iterations` steps of a golden-section search between the neighbours of the closest sample.
+    /// Each step shrinks the searched interval by a factor of about 0.618.
+    /// If the curve passes the target closely several times, enough samples are necessary
+    /// to find the closest one. Otherwise, another local minimum may be returned.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "bezier", doc = "```rust")]
+    #[cfg_attr(not(feature = "bezier"), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Point, Projectable};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([Point::new([0.0,0.0]), Point::new([1.0,2.0]), Point::new([2.0,0.0])])
+    ///                 .normalized::<f64>()
+    ///                 .constant()
+    ///                 .build()?;
+    /// let euclidean = |a: &Point<f64,2>, b: &Point<f64,2>| (a[0]-b[0]).hypot(a[1]-b[1]);
+    /// let (input, distance) = bezier.closest_parameter(&Point::new([1.0,2.0]), euclidean, 10, 50);
+    /// assert!((input - 0.5).abs() < 1e-6);
+    /// assert_f64_near!(distance, 1.0);
     /// #
     /// #     Ok(())
     /// # }
     /// ```
-    fn clamp(self) -> Clamp<Self>
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples - 1` can not be converted to `R`.
+    /// Less than 2 samples are treated as 2 samples.
+    fn closest_parameter<D>(
+        &self,
+        target: &Self::Output,
+        distance: D,
+        samples: usize,
+        iterations: usize,
+    ) -> (R, R)
     where
-        Self: Sized,
+        D: Fn(&Self::Output, &Self::Output) -> R,
     {
-        Clamp::new(self)
+        let [start, end] = self.domain();
+        let inputs = Equidistant::new(samples.max(2), start, end);
+        let dist = |input: R| distance(&self.gen(input), target);
+        let mut best = (start, dist(start));
+        let mut best_index = 0;
+        for index in 1..inputs.len() {
+            let input = inputs.gen(index);
+            let candidate = dist(input);
+            if candidate < best.1 {
+                best = (input, candidate);
+                best_index = index;
+            }
+        }
+        // golden-section search between the neighbours of the closest sample
+        let ratio = (R::from_usize(5).unwrap().sqrt() - R::one()) / R::from_usize(2).unwrap();
+        let mut low = inputs.gen(best_index.saturating_sub(1));
+        let mut high = inputs.gen((best_index + 1).min(inputs.len() - 1));
+        let mut first = high - (high - low) * ratio;
+        let mut second = low + (high - low) * ratio;
+        let mut first_dist = dist(first);
+        let mut second_dist = dist(second);
+        for _ in 0..iterations {
+            if first_dist < second_dist {
+                high = second;
+                second = first;
+                second_dist = first_dist;
+                first = high - (high - low) * ratio;
+                first_dist = dist(first);
+            } else {
+                low = first;
+                first = second;
+                first_dist = second_dist;
+                second = low + (high - low) * ratio;
+                second_dist = dist(second);
+            }
+        }
+        for candidate in [(first, first_dist), (second, second_dist)] {
+            if candidate.1 < best.1 {
+                best = candidate;
+            }
+        }
+        best
     }
 }
 
-//Make references of curves also curves
-impl<C: Curve<R> + ?Sized, R> Curve<R> for &C
+impl<C, R> Projectable<R> for C
 where
-    R: Real,
+    C: Curve<R> + ?Sized,
+    R: Real + FromPrimitive,
 {
-    fn domain(&self) -> [R; 2] {
-        (**self).domain()
-    }
 }
 
 /// Specialized [`Generator`] with input of type `usize`.
@@ -326,6 +1470,27 @@ pub trait DiscreteGenerator: Generator<usize> {
         }
         Some(self.gen(self.len() - 1))
     }
+    /// Returns the element at the given index, or `None` if the index is out of bounds.
+    ///
+    /// In contrast to [`gen()`], this method never relies on the caller to guarantee a valid index,
+    /// which makes it suitable for indices derived from user input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::DiscreteGenerator;
+    /// let elements = [1.0,2.0,3.0];
+    /// assert_eq!(elements.get_checked(2), Some(3.0));
+    /// assert_eq!(elements.get_checked(3), None);
+    /// ```
+    ///
+    /// [`gen()`]: Generator::gen()
+    fn get_checked(&self, index: usize) -> Option<Self::Output> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.gen(index))
+    }
     /// Returns `true` if the generator does not generate any elements.
     fn is_empty(&self) -> bool {
         self.len() == 0
@@ -348,6 +1513,293 @@ pub trait DiscreteGenerator: Generator<usize> {
     {
         Repeat::new(self)
     }
+    /// Transform generator to one which repeats its elements `n` times.
+    ///
+    /// The length of the new generator saturates at `usize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let repeated = [0.0,1.0,2.0].repeat_n(2);
+    /// assert_eq!(repeated.len(), 6);
+    /// assert_eq!(repeated.gen(4), 1.0);
+    /// ```
+    fn repeat_n(self, n: usize) -> RepeatN<Self>
+    where
+        Self: Sized,
+    {
+        RepeatN::new(self, n)
+    }
+    /// Transform generator to one which reflects its elements at its boundaries.
+    ///
+    /// The elements are generated forth and back, without repeating the first and last element.
+    /// This is useful to extend elements seamlessly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let reflected = [0.0,1.0,2.0].reflect();
+    /// let results = [0.0,1.0,2.0,1.0,0.0,1.0];
+    /// for (index, result) in results.iter().enumerate() {
+    ///     assert_eq!(reflected.gen(index), result);
+    /// }
+    /// ```
+    fn reflect(self) -> Reflect<Self>
+    where
+        Self: Sized,
+    {
+        Reflect::new(self)
+    }
+    /// Transform generator to one which generates its elements in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let reversed = [1, 2, 3].reversed();
+    /// assert_eq!(reversed.len(), 3);
+    /// assert_eq!(reversed.gen(0), 3);
+    /// assert_eq!(reversed.gen(2), 1);
+    /// ```
+    fn reversed(self) -> ReversedElements<Self>
+    where
+        Self: Sized,
+    {
+        ReversedElements::new(self)
+    }
+    /// Transform generator to one which generates arrays of `W` consecutive elements.
+    ///
+    /// This is the analog of [`slice::windows()`] for discrete generators: the element at index `i`
+    /// consists of the elements at the indices `i` up to `i+W-1`.
+    /// If the generator has less than `W` elements, the resulting generator has no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let windows = [1, 2, 3, 4].array_windows::<2>();
+    /// assert_eq!(windows.len(), 3);
+    /// assert_eq!(windows.gen(0), [1, 2]);
+    /// assert_eq!(windows.gen(2), [3, 4]);
+    /// ```
+    ///
+    /// [`slice::windows()`]: slice::windows()
+    fn array_windows<const W: usize>(self) -> Windows<Self, W>
+    where
+        Self: Sized,
+    {
+        Windows::new(self)
+    }
+    /// Transform generator to one which groups its elements into arrays of `C` consecutive elements.
+    ///
+    /// In contrast to [`array_windows()`], the chunks do not overlap: the element at index `i` consists of
+    /// the elements at the indices `i*C` up to `i*C+C-1`. If the number of elements is not a multiple of `C`,
+    /// the remaining elements at the end are truncated.
+    ///
+    /// This is useful to interpolate between points whose coordinates are stored interleaved,
+    /// like a flat vertex buffer. The buffer is not copied, each chunk is read when it is generated.
+    /// As arrays do not implement [`Merge`], the chunks have to be mapped to [`Point`]s before
+    /// they can be used as elements of an interpolation.
+    /// The number of chunks is only known at runtime, such that `Chunks` is never a [`ConstDiscreteGenerator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let points = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0].array_chunks::<2>();
+    /// assert_eq!(points.len(), 3);
+    /// assert_eq!(points.gen(0), [0.0, 1.0]);
+    /// assert_eq!(points.gen(2), [4.0, 5.0]);
+    /// ```
+    ///
+    #[cfg_attr(all(feature = "bezier", feature = "alloc"), doc = "```rust")]
+    #[cfg_attr(not(all(feature = "bezier", feature = "alloc")), doc = "```ignore")]
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, DiscreteGenerator, Generator, Point};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// // control polygon of 2D points stored interleaved
+    /// let buffer = [0.0, 0.0, 1.0, 2.0, 2.0, 0.0];
+    /// let bezier = Bezier::builder()
+    ///                 .elements(buffer.array_chunks::<2>().map(Point::new))
+    ///                 .normalized::<f64>()
+    ///                 .dynamic()
+    ///                 .build()?;
+    /// assert_eq!(bezier.gen(0.5).into_array(), [1.0, 1.0]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Merge`]: crate::Merge
+    /// [`Point`]: crate::Point
+    /// [`array_windows()`]: DiscreteGenerator::array_windows()
+    fn array_chunks<const C: usize>(self) -> Chunks<Self, C>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self)
+    }
+    /// Transform generator to one which remembers every generated element.
+    ///
+    /// Each element is generated at most once by this generator, which is useful if generating elements
+    /// is expensive. Memory for all elements is reserved immediately,
+    /// such this should not be used for generators with a huge number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator};
+    /// let expensive = [1, 2, 3].inspect(|element| println!("generated {}", element));
+    /// let cached = expensive.cache();
+    /// assert_eq!(cached.gen(1), 2);
+    /// // the element is not generated again
+    /// assert_eq!(cached.gen(1), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    fn cache(self) -> Cache<Self, Self::Output>
+    where
+        Self: Sized,
+    {
+        Cache::new(self)
+    }
+    /// Transform generator to one which generates `n` additional elements at the end.
+    ///
+    /// Which elements are generated is defined by the given [`WrapMode`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Generator, WrapMode};
+    /// let mirrored = [1, 2, 3].wrap_with(2, WrapMode::Mirror);
+    /// assert_eq!(mirrored.len(), 5);
+    /// assert_eq!(mirrored.gen(3), 2);
+    /// assert_eq!(mirrored.gen(4), 1);
+    /// ```
+    fn wrap_with(self, n: usize, mode: WrapMode) -> Wrap<Self>
+    where
+        Self: Sized,
+    {
+        Wrap::with_mode(self, n, mode)
+    }
+    /// Collect all elements of the generator into a vector.
+    ///
+    /// The vector is preallocated with the length of the generator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{DiscreteGenerator, Equidistant};
+    /// let knots = Equidistant::<f64>::new(5, 0.0, 1.0);
+    /// assert_eq!(knots.to_vec(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_vec(&self) -> Vec<Self::Output> {
+        let mut vec = Vec::with_capacity(self.len());
+        vec.extend(self.iter());
+        vec
+    }
+    /// Fill the gaps of a generator of optional elements by linear interpolation.
+    ///
+    /// Each `None` is replaced by the linear interpolation of the nearest present elements before and after it,
+    /// weighted by the distance of their indices. Gaps at the start and end are filled with the nearest
+    /// present element. Returns `None` if no element is present at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::DiscreteGenerator;
+    /// let filled = [None, Some(1.0), None, None, Some(4.0), None].fill_gaps::<f64, _>();
+    /// assert_eq!(filled, Some(vec![1.0, 1.0, 2.0, 3.0, 4.0, 4.0]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the distance between two present elements can not be converted to the type `R`.
+    #[cfg(feature = "alloc")]
+    fn fill_gaps<R, T>(self) -> Option<Vec<T>>
+    where
+        Self: Sized + DiscreteGenerator<Output = Option<T>>,
+        T: Merge<R> + Copy,
+        R: Real + FromPrimitive,
+    {
+        let len = self.len();
+        let mut filled = Vec::with_capacity(len);
+        // index and value of the last present element
+        let mut last: Option<(usize, T)> = None;
+        for (index, element) in self.into_iter().enumerate() {
+            let value = match element {
+                Some(value) => value,
+                None => continue,
+            };
+            match last {
+                Some((last_index, last_value)) => {
+                    let dist = R::from_usize(index - last_index).unwrap();
+                    for gap in 1..index - last_index {
+                        let factor = R::from_usize(gap).unwrap() / dist;
+                        filled.push(last_value.merge(value, factor));
+                    }
+                }
+                None => filled.extend(core::iter::repeat_n(value, index)),
+            }
+            filled.push(value);
+            last = Some((index, value));
+        }
+        let (_, last_value) = last?;
+        filled.resize(len, last_value);
+        Some(filled)
+    }
+    /// Transform generator to a curve by linearly interpolating its elements.
+    ///
+    /// The elements are placed at equidistant knots from `start` to `end`.
+    /// This is a shortcut for creating a [`Linear`] interpolation with equidistant knots
+    /// without using its builder.
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "linear", doc = "```rust")]
+    #[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+    /// # use enterpolation::{linear::LinearError, DiscreteGenerator, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let curve = [0.0,5.0,3.0].as_curve(0.0,10.0)?;
+    /// assert_f64_near!(curve.gen(2.5), 2.5);
+    /// assert_f64_near!(curve.gen(7.5), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if the generator has less than two elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len - 1` can not be converted to the type `R`.
+    ///
+    /// [`Linear`]: crate::linear::Linear
+    /// [`TooFewElements`]: crate::linear::LinearError
+    #[cfg(feature = "linear")]
+    #[allow(clippy::wrong_self_convention)]
+    fn as_curve<R>(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<Linear<Equidistant<R>, Self, Identity>, LinearError>
+    where
+        Self: Sized,
+        Self::Output: Merge<R>,
+        R: Real + FromPrimitive,
+    {
+        if self.len() < 2 {
+            return Err(TooFewElements::new(self.len()).into());
+        }
+        let knots = Equidistant::new(self.len(), start, end);
+        Ok(Linear::new_unchecked(self, knots, Identity::new()))
+    }
 }
 
 // Make references of DiscreteGenerator also DiscreteGenerator
@@ -444,9 +1896,8 @@ where
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.front < self.back {
-            let res = self.gen.gen(self.back);
             self.back -= 1;
-            return Some(res);
+            return Some(self.gen.gen(self.back));
         }
         None
     }
@@ -575,6 +2026,101 @@ where
     }
 }
 
+/// Iterator which yields equidistant samples of a curve together with their input and tangent.
+///
+/// This struct is created by the [`take_with_tangent()`] method on [`Curve`]. See its documentation for more.
+///
+/// [`take_with_tangent()`]: crate::Curve::take_with_tangent()
+/// [`Curve`]: crate::Curve
+#[derive(Debug, Clone, PartialEq)] // Iterators shouldn't be Copy -- see #27186
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TakeWithTangent<C, R: Real = f64> {
+    curve: C,
+    stepper: Stepper<R>,
+    step: R,
+}
+
+impl<C, R> TakeWithTangent<C, R>
+where
+    C: Curve<R>,
+    R: Real + FromPrimitive,
+{
+    /// Create an iterator which takes `samples` samples of the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if given size of samples is 0 or if `samples - 1` can not be converted to the type `R`.
+    pub fn new(curve: C, samples: usize) -> Self {
+        let [start, end] = curve.domain();
+        // the cube root of epsilon is the optimal step size for central differences
+        let step = (end - start).abs() * R::epsilon().cbrt();
+        TakeWithTangent {
+            stepper: Stepper::new(samples, start, end),
+            curve,
+            step,
+        }
+    }
+
+    fn sample(&self, input: R) -> (R, C::Output, C::Output)
+    where
+        C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    {
+        let [start, end] = self.curve.domain();
+        // at the borders of the domain, the difference is one-sided
+        let lower = (input - self.step).max(start.min(end));
+        let upper = (input + self.step).min(start.max(end));
+        let difference = self.curve.gen(upper) - self.curve.gen(lower);
+        if upper <= lower {
+            // the domain has no width, such that the difference is zero
+            return (input, self.curve.gen(input), difference);
+        }
+        (
+            input,
+            self.curve.gen(input),
+            difference * (upper - lower).recip(),
+        )
+    }
+}
+
+impl<C, R> Iterator for TakeWithTangent<C, R>
+where
+    C: Curve<R>,
+    C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    R: Real + FromPrimitive,
+{
+    type Item = (R, C::Output, C::Output);
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.stepper.next()?;
+        Some(self.sample(input))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stepper.size_hint()
+    }
+    fn count(self) -> usize {
+        self.stepper.count()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let input = self.stepper.nth(n)?;
+        Some(self.sample(input))
+    }
+}
+
+impl<C, R> FusedIterator for TakeWithTangent<C, R>
+where
+    C: Curve<R>,
+    C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    R: Real + FromPrimitive,
+{
+}
+
+impl<C, R> ExactSizeIterator for TakeWithTangent<C, R>
+where
+    C: Curve<R>,
+    C::Output: Sub<Output = C::Output> + Mul<R, Output = C::Output>,
+    R: Real + FromPrimitive,
+{
+}
+
 /// Stepper is an iterator which increments its number.
 ///
 /// Stepper can be seen as a [`Range`] with variable step size.
@@ -601,6 +2147,17 @@ where
     /// Creates a new Stepper stepping from `start` to `end`
     /// Also the given steps are not allowed to be less than 1
     ///
+    /// If `start` is greater than `end`, the stepper steps in negative direction,
+    /// that is, its values are decreasing from `start` to `end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::Stepper;
+    /// let stepper = Stepper::new(5, 1.0, 0.0);
+    /// assert_eq!(stepper.collect::<Vec<f64>>(), vec![1.0, 0.75, 0.5, 0.25, 0.0]);
+    /// ```
+    ///
     /// #Panics
     ///
     /// Panics if the given steps are 0 and if `steps -1` can not be transformed into R.
@@ -644,6 +2201,63 @@ where
     }
 }
 
+/// Simpson's rule for the given values at the start, middle and end of an interval of the given width.
+fn simpson<R, T>(first: T, middle: T, last: T, width: R) -> T
+where
+    T: Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    (first + middle * R::from_usize(4).unwrap() + last) * (width / R::from_usize(6).unwrap())
+}
+
+/// Recursive step of the adaptive Simpson quadrature used by [`Curve::integrate()`].
+fn adaptive_simpson<C, R, F>(
+    curve: &C,
+    [start, end]: [R; 2],
+    [first, middle, last]: [C::Output; 3],
+    whole: C::Output,
+    tolerance: R,
+    magnitude: &F,
+    depth: usize,
+) -> C::Output
+where
+    C: Curve<R>,
+    C::Output:
+        Add<Output = C::Output> + Sub<Output = C::Output> + Mul<R, Output = C::Output> + Copy,
+    R: Real + FromPrimitive,
+    F: Fn(C::Output) -> R,
+{
+    let two = R::from_usize(2).unwrap();
+    let center = (start + end) / two;
+    let left_middle = curve.gen((start + center) / two);
+    let right_middle = curve.gen((center + end) / two);
+    let left = simpson(first, left_middle, middle, center - start);
+    let right = simpson(middle, right_middle, last, end - center);
+    let error = left + right - whole;
+    let fifteen = R::from_usize(15).unwrap();
+    if depth == 0 || magnitude(error) <= tolerance * fifteen {
+        // Richardson extrapolation of both estimations
+        return left + right + error * fifteen.recip();
+    }
+    adaptive_simpson(
+        curve,
+        [start, center],
+        [first, left_middle, middle],
+        left,
+        tolerance / two,
+        magnitude,
+        depth - 1,
+    ) + adaptive_simpson(
+        curve,
+        [center, end],
+        [middle, right_middle, last],
+        right,
+        tolerance / two,
+        magnitude,
+        depth - 1,
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -664,4 +2278,178 @@ mod test {
             assert_f64_near!(val, res[i]);
         }
     }
+
+    #[test]
+    fn reversed_stepper() {
+        use crate::{Identity, TransformInput};
+        let mut stepper = Stepper::new(5, 5.0, 3.0);
+        assert_eq!(stepper.len(), 5);
+        let res = [5.0, 4.5, 4.0, 3.5, 3.0];
+        assert_f64_near!(stepper.next_back().unwrap(), res[4]);
+        for (val, expected) in stepper.by_ref().zip(&res[..4]) {
+            assert_f64_near!(val, *expected);
+        }
+        assert_eq!(stepper.next(), None);
+        let mut single = Stepper::new(1, 5.0, 3.0);
+        assert_eq!(single.next(), Some(5.0));
+        assert_eq!(single.next(), None);
+        // curves with a reversed domain are sampled in negative direction
+        let curve = TransformInput::new(Identity {}, 0.0, -1.0);
+        assert_eq!(curve.domain(), [0.0, -1.0]);
+        let samples = curve.take(5);
+        assert_eq!(samples.len(), 5);
+        for (value, result) in samples.rev().zip([1.0, 0.75, 0.5, 0.25, 0.0]) {
+            assert_f64_near!(value, result);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn inflection_points() {
+        use crate::easing::{smoothstep, FuncEase};
+        let curve = FuncEase::new(smoothstep::<f64>);
+        let inflections = curve.inflection_points(10, 40, |_, second| second);
+        assert_eq!(inflections.len(), 1);
+        assert!((inflections[0] - 0.5).abs() < 1e-6);
+        let convex = FuncEase::new(|x: f64| x * x);
+        assert!(convex
+            .inflection_points(10, 40, |_, second| second)
+            .is_empty());
+        assert!(curve
+            .inflection_points(2, 40, |_, second| second)
+            .is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fill_gaps() {
+        use alloc::vec;
+        assert_eq!([None::<f64>, None].fill_gaps::<f64, _>(), None);
+        assert_eq!(
+            [Some(1.0), Some(2.0)].fill_gaps::<f64, _>(),
+            Some(vec![1.0, 2.0])
+        );
+        assert_eq!(
+            [None, None, Some(3.0)].fill_gaps::<f64, _>(),
+            Some(vec![3.0, 3.0, 3.0])
+        );
+        let filled = [Some(0.0), None, None, None, Some(1.0)]
+            .fill_gaps::<f64, _>()
+            .unwrap();
+        for (value, result) in filled.iter().zip([0.0, 0.25, 0.5, 0.75, 1.0]) {
+            assert_f64_near!(value, result);
+        }
+    }
+
+    #[test]
+    fn sample_into() {
+        use crate::easing::FuncEase;
+        let curve = FuncEase::new(|x: f64| x * 2.0);
+        let mut buffer = [f64::NAN; 3];
+        curve.sample_into(&mut buffer);
+        assert_eq!(buffer, [0.0, 1.0, 2.0]);
+        let mut single = [f64::NAN; 1];
+        curve.sample_into(&mut single);
+        assert_eq!(single, [0.0]);
+        curve.sample_range_into(0.5..1.0, &mut buffer);
+        assert_eq!(buffer, [1.0, 1.5, 2.0]);
+        curve.sample_range_into(..0.5, &mut buffer);
+        assert_eq!(buffer, [0.0, 0.5, 1.0]);
+        curve.sample_into(&mut []);
+    }
+
+    #[test]
+    fn closest_parameter() {
+        use crate::easing::FuncEase;
+        // parabola whose minimum is at 0.3
+        let curve = FuncEase::new(|x: f64| (x - 0.3) * (x - 0.3));
+        let distance = |a: &f64, b: &f64| (a - b).abs();
+        let (input, dist) = curve.closest_parameter(&0.0, distance, 4, 60);
+        assert!((input - 0.3).abs() < 1e-6);
+        assert!(dist < 1e-12);
+        // the target is closest to an end of the domain
+        let (input, dist) = curve.closest_parameter(&1.0, distance, 5, 60);
+        assert_f64_near!(input, 1.0);
+        assert_f64_near!(dist, 0.51);
+        // no refinement returns the closest sample
+        let (input, _) = curve.closest_parameter(&0.0, distance, 11, 0);
+        assert_f64_near!(input, 0.3);
+    }
+
+    #[test]
+    fn take_with_tangent() {
+        use crate::easing::FuncEase;
+        // the curve must never be sampled outside of its domain
+        let curve = FuncEase::new(|x: f64| {
+            assert!((0.0..=1.0).contains(&x), "sampled outside of the domain");
+            x * x
+        });
+        let expected = [(0.0, 0.0, 0.0), (0.5, 0.25, 1.0), (1.0, 1.0, 2.0)];
+        for ((input, value, tangent), result) in curve.take_with_tangent(3).zip(expected) {
+            assert_f64_near!(input, result.0);
+            assert_f64_near!(value, result.1);
+            assert!((tangent - result.2).abs() < 1e-4);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_vec_and_collect_curve() {
+        use crate::easing::FuncEase;
+        use alloc::vec;
+        let array = [1.0, 2.0, 3.0];
+        assert_eq!(DiscreteGenerator::to_vec(&array), vec![1.0, 2.0, 3.0]);
+        assert!(Equidistant::<f64>::new(0, 0.0, 1.0).to_vec().is_empty());
+        let curve = FuncEase::new(|x: f64| x * x);
+        let samples = curve.collect_curve(3);
+        assert_eq!(samples, vec![(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)]);
+        assert!(curve.collect_curve(0).is_empty());
+    }
+
+    #[cfg(all(feature = "std", feature = "linear"))]
+    #[test]
+    fn vec_deque_and_boxed_slice() {
+        use crate::linear::Linear;
+        use std::collections::VecDeque;
+        let mut window: VecDeque<f64> = [0.0, 1.0, 2.0].iter().collect();
+        window.pop_front();
+        window.push_back(5.0);
+        assert_eq!(window.to_vec(), vec![1.0, 2.0, 5.0]);
+        let linear = Linear::builder()
+            .elements(&window)
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.75), 3.5);
+        let boxed: Box<[f64]> = vec![1.0, 2.0].into_boxed_slice();
+        assert_eq!(boxed.len(), 2);
+        assert_eq!(boxed.gen(1), 2.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn slice_methods_not_shadowed() {
+        // the methods of discrete generators must not hide the ones of slices
+        let boxed: Box<[f64]> = vec![1.0, 2.0, 3.0].into_boxed_slice();
+        assert_eq!(boxed.windows(2).count(), 2);
+        assert_eq!(boxed.array_windows::<2>().gen(1), [2.0, 3.0]);
+        let vec = Vec::from([1.0, 2.0, 3.0]);
+        assert_eq!(vec.windows(2).next(), Some(&[1.0, 2.0][..]));
+        assert_eq!(vec.chunks(2).last(), Some(&[3.0][..]));
+        let arr = [1.0, 2.0, 3.0];
+        assert_eq!(arr.chunks(2).count(), 2);
+        assert_eq!(arr.array_chunks::<2>().len(), 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_sample() {
+        use crate::easing::FuncEase;
+        let curve = FuncEase::new(|x: f64| x * x);
+        let serial: Vec<f64> = curve.take(1001).collect();
+        assert_eq!(curve.par_sample(1001), serial);
+        assert_eq!(curve.par_sample(1), vec![0.0]);
+        assert!(curve.par_sample(0).is_empty());
+    }
 }
@@ -6,6 +6,8 @@ use num_traits::identities::Zero;
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[cfg(feature = "std")]
 use std::error::Error;
 
@@ -13,6 +15,7 @@ use std::error::Error;
 use core::fmt::Debug;
 
 use super::{DiscreteGenerator, Generator};
+use crate::Scalar;
 
 // REMARK: It may be valuable to create traits SortedNonEmpty and SortedNonSingular
 // REMARK: These would be Sorted + NonEmpty and Sorted + MinSize<2>.
@@ -107,6 +110,38 @@ pub trait SortedGenerator: DiscreteGenerator {
     {
         self.strict_upper_bound_clamped(element, 0, self.len())
     }
+    /// Returns the smallest index for which the corresponding element is bigger or equal to the input.
+    /// Such the element before this index is the biggest element which is strictly smaller than the input.
+    /// If all elements are smaller, this function will return self.len().
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Sorted};
+    /// let arr = Sorted::new_unchecked([0.0,0.1,0.2,0.7,0.7,0.7,0.8,1.0]);
+    /// assert_eq!(arr.strict_lower_bound(-1.0),0);
+    /// assert_eq!(arr.strict_lower_bound(0.15),2);
+    /// assert_eq!(arr.strict_lower_bound(0.7),3);
+    /// assert_eq!(arr.strict_lower_bound(20.0),8);
+    /// ```
+    fn strict_lower_bound(&self, element: Self::Output) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        let mut pointer = 0;
+        let mut dist = self.len();
+        while dist > 0 {
+            let step = dist / 2;
+            let sample = pointer + step;
+            if element > self.gen(sample) {
+                pointer = sample + 1;
+                dist -= step + 1;
+            } else {
+                dist = step;
+            }
+        }
+        pointer
+    }
 
     /// Find the values inside the collection for which the given element is inbetween
     /// and a linear factor at how close it is to which value.
@@ -202,6 +237,76 @@ pub trait SortedGenerator: DiscreteGenerator {
         )
     }
 
+    /// Find the values inside the collection for which the given element is inbetween
+    /// and a linear factor at how close it is to which value.
+    ///
+    /// In contrast to [`upper_border()`], the returned indices are chosen with [`strict_lower_bound()`].
+    /// Such if the element is equal to some elements of the collection, the first of them is used
+    /// as `second` instead of the last of them as `first`. For an element inside the collection,
+    /// the indices returned correspond to values such that `first < value <= second` is true.
+    ///
+    /// The same remarks as for [`upper_border()`] apply.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is has less than *two* elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Sorted, Generator};
+    /// # use enterpolation::utils;
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let arr = Sorted::new_unchecked([0.0,0.1,0.2,0.7,0.7,0.7,0.8,1.0]);
+    /// assert_eq!(arr.lower_border(0.7), (2, 3, 1.0));
+    /// assert_eq!(arr.upper_border(0.7), (5, 6, 0.0));
+    /// let values = vec![-1.0,0.0,0.15,0.7,1.0,20.0];
+    /// for value in values {
+    ///     let (min_index, max_index, factor) = arr.lower_border(value);
+    ///     let min = arr.gen(min_index);
+    ///     let max = arr.gen(max_index);
+    ///     assert_f64_near!(utils::lerp(min,max,factor),value);
+    /// }
+    /// ```
+    ///
+    /// [`upper_border()`]: SortedGenerator::upper_border()
+    /// [`strict_lower_bound()`]: SortedGenerator::strict_lower_bound()
+    fn lower_border(&self, element: Self::Output) -> (usize, usize, Self::Output)
+    where
+        Self::Output: PartialOrd
+            + Sub<Output = Self::Output>
+            + Div<Output = Self::Output>
+            + Zero
+            + Copy
+            + Debug,
+    {
+        let max_index = self.strict_lower_bound(element);
+        // test if we have to clamp max_index -> if so, factor has to be calculated with a check for NaN.
+        if self.len() == max_index {
+            let max_index = self.len() - 1;
+            let min_index = max_index - 1;
+            return (
+                min_index,
+                max_index,
+                self.linear_factor(min_index, max_index, element),
+            );
+        }
+        if max_index == 0 {
+            let max_index = 1;
+            let min_index = 0;
+            return (
+                min_index,
+                max_index,
+                self.linear_factor(min_index, max_index, element),
+            );
+        }
+        (
+            max_index - 1,
+            max_index,
+            self.linear_factor_unchecked(max_index - 1, max_index, element),
+        )
+    }
+
     /// Calculate the factor of `element` inbetween `min` and `max`.
     ///
     /// That is, the factor would be needed to generate `element` from a linear interpolation of
@@ -250,7 +355,19 @@ pub trait SortedGenerator: DiscreteGenerator {
 }
 
 /// Struct to represent a sorted collection/generator.
-#[derive(Debug, Copy, Clone, PartialEq)]
+///
+/// Sorted collections may contain equal adjacent elements. Used as knots, not all interpolations
+/// tolerate such duplicates:
+/// - linear interpolations and hermite curves jump at a duplicated knot inside of the domain,
+///   taking the element of the later knot at the knot itself,
+/// - bsplines explicitly allow knots to repeat up to their degree,
+/// - catmull-rom splines, monotone cubic and natural cubic splines divide by the distance of
+///   adjacent knots and generate NaN or infinite values around duplicates.
+///
+/// Use [`new_dedup()`] to collapse duplicated knots before creating such an interpolation.
+///
+/// [`new_dedup()`]: Sorted::new_dedup()
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Sorted<C>(C);
 
@@ -278,6 +395,61 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<R> Sorted<Vec<R>>
+where
+    R: PartialOrd,
+{
+    /// Create sorted knots and their elements from pairs, collapsing runs of equal knots.
+    ///
+    /// Elements of equal knots are merged with the given function, which is called with the accumulated
+    /// element of the run and the next element. This allows to keep the first or last element
+    /// or to calculate the sum of them.
+    /// Such a table with repeated knots results in an interpolation without duplicated knots.
+    /// If all knots are equal, only a single knot remains.
+    ///
+    /// # Errors
+    ///
+    /// [`NotSorted`] if the knots are decreasing at some point or if a knot is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{Sorted, DiscreteGenerator};
+    /// let table = [(0.0, 1.0), (1.0, 2.0), (1.0, 4.0), (2.0, 3.0)];
+    /// let (knots, elements) = Sorted::new_dedup(table, |a, b| (a + b) / 2.0).unwrap();
+    /// assert_eq!(knots.to_vec(), vec![0.0, 1.0, 2.0]);
+    /// assert_eq!(elements, vec![1.0, 3.0, 3.0]);
+    /// ```
+    pub fn new_dedup<I, T, F>(points: I, mut merge: F) -> Result<(Self, Vec<T>), NotSorted>
+    where
+        I: IntoIterator<Item = (R, T)>,
+        F: FnMut(T, T) -> T,
+    {
+        let mut knots: Vec<R> = Vec::new();
+        let mut elements = Vec::new();
+        for (index, (knot, element)) in points.into_iter().enumerate() {
+            let ordering = match knots.last() {
+                // the first knot only has to be comparable
+                None => knot.partial_cmp(&knot).map(|_| Ordering::Less),
+                Some(last) => last.partial_cmp(&knot),
+            };
+            match ordering {
+                Some(Ordering::Less) => {
+                    knots.push(knot);
+                    elements.push(element);
+                }
+                Some(Ordering::Equal) => {
+                    let run = elements.pop().expect("every knot has an element");
+                    elements.push(merge(run, element));
+                }
+                _ => return Err(NotSorted::new(index)),
+            }
+        }
+        Ok((Sorted(knots), elements))
+    }
+}
+
 impl<C> Sorted<C> {
     /// Creates a sorted collection without checking if it is sorted.
     ///
@@ -347,6 +519,43 @@ impl fmt::Display for NotSorted {
 #[cfg(feature = "std")]
 impl Error for NotSorted {}
 
+/// Error returned if equidistant knots could not be created.
+///
+/// Either less than two knots were requested or the distance between the knots is not finite.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InvalidEquidistant {
+    len: usize,
+}
+
+impl InvalidEquidistant {
+    /// Create a new error for equidistant knots with the given length.
+    pub fn new(len: usize) -> Self {
+        InvalidEquidistant { len }
+    }
+}
+
+impl fmt::Display for InvalidEquidistant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.len < 2 {
+            write!(
+                f,
+                "Equidistant knots need at least 2 knots, however only {} were requested.",
+                self.len
+            )
+        } else {
+            write!(
+                f,
+                "The distance between the {} equidistant knots is not finite.",
+                self.len
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidEquidistant {}
+
 /// Struct used as a generator for equidistant elements.
 /// Acts like an array of knots.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -370,34 +579,98 @@ pub struct Equidistant<R = f64> {
 
 impl<R> Equidistant<R>
 where
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     /// Create a generator for equidistant real numbers with `len-1` steps from 0.0 to 1.0.
     ///
+    /// If the given length is 1, the only element is 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{Equidistant, Generator, SortedGenerator};
+    /// let single = Equidistant::<f64>::normalized(1);
+    /// assert_eq!(single.gen(0), 0.0);
+    /// assert_eq!(single.strict_upper_bound(0.5), 1);
+    /// ```
+    ///
     /// #Panics
     ///
-    /// Panics if the given length is 0 or `length -  1` can not be transformed into R.
+    /// Panics if `length -  1` can not be transformed into R.
     pub fn normalized(len: usize) -> Self {
         Equidistant {
             len,
-            step: R::from_usize(len - 1).unwrap().recip(),
+            step: Self::step_size(len, R::one()),
             offset: R::zero(),
         }
     }
 
     /// Create a generator for equidistant real numbers with `len-1` steps from `start` to `end`.
     ///
+    /// If the given length is 1, the only element is `start`.
+    ///
+    /// This constructor does not validate its input. Generating elements of knots created from
+    /// non-finite values yields non-finite values. Use [`try_new()`] to validate the input.
+    ///
     /// #Panics
     ///
-    /// Panics if the given length is 0 or `length -  1` can not be transformed into R.
+    /// Panics if `length -  1` can not be transformed into R.
+    ///
+    /// [`try_new()`]: Equidistant::try_new()
     pub fn new(len: usize, start: R, end: R) -> Self {
         Equidistant {
             len,
-            step: (end - start) / R::from_usize(len - 1).unwrap(),
+            step: Self::step_size(len, end - start),
             offset: start,
         }
     }
 
+    /// Create a generator for equidistant real numbers with `len-1` steps from `start` to `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if less than two elements are requested
+    /// or if the distance between the elements is not finite, for example if `start` or `end` is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{Equidistant, Generator};
+    /// let knots = Equidistant::try_new(3, 1.0, 2.0).unwrap();
+    /// assert_eq!(knots.gen(1), 1.5);
+    /// assert!(Equidistant::try_new(1, 1.0, 2.0).is_err());
+    /// assert!(Equidistant::try_new(3, 1.0, f64::INFINITY).is_err());
+    /// ```
+    pub fn try_new(len: usize, start: R, end: R) -> Result<Self, InvalidEquidistant> {
+        if len < 2 {
+            return Err(InvalidEquidistant::new(len));
+        }
+        let equidistant = Self::new(len, start, end);
+        if !equidistant.step.is_finite() {
+            return Err(InvalidEquidistant::new(len));
+        }
+        Ok(equidistant)
+    }
+
+    /// Create a generator for equidistant real numbers with `len-1` steps from 0.0 to 1.0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if less than two elements are requested.
+    pub fn try_normalized(len: usize) -> Result<Self, InvalidEquidistant> {
+        Self::try_new(len, R::zero(), R::one())
+    }
+
+    /// Returns the distance between elements such that `len` elements span the given width.
+    ///
+    /// For less than two elements, the distance is zero instead of infinite.
+    fn step_size(len: usize, width: R) -> R {
+        if len <= 1 {
+            return R::zero();
+        }
+        width / R::from_index(len - 1).unwrap()
+    }
+
     /// Create a generator for equidistant real number with `len-1` steps from `start` to `end`.
     pub fn step(len: usize, start: R, step: R) -> Self {
         Equidistant {
@@ -410,17 +683,17 @@ where
 
 impl<R> Generator<usize> for Equidistant<R>
 where
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     type Output = R;
     fn gen(&self, input: usize) -> R {
-        self.step * R::from_usize(input).unwrap() + self.offset
+        self.step * R::from_index(input).unwrap() + self.offset
     }
 }
 
 impl<R> DiscreteGenerator for Equidistant<R>
 where
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     fn len(&self) -> usize {
         self.len
@@ -429,7 +702,7 @@ where
 
 impl<R> SortedGenerator for Equidistant<R>
 where
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     /// Returns the smallest index for which the corresponding element is bigger then the input.
     /// If all elements are bigger, this function will return self.len().
@@ -451,11 +724,46 @@ where
         if element < self.offset {
             return 0;
         }
+        // all elements are equal to the offset
+        if self.len <= 1 || self.step == R::zero() {
+            return self.len();
+        }
         let scaled = (element - self.offset) / self.step;
         // now unrwapping is fine as we are above zero.
-        let min_index = scaled.floor().to_usize().unwrap();
+        let min_index = scaled.floor().to_index().unwrap();
         self.len().min(min_index + 1)
     }
+    /// Returns the smallest index for which the corresponding element is bigger or equal to the input.
+    /// Such the element before this index is the biggest element which is strictly smaller than the input.
+    /// If all elements are smaller, this function will return self.len().
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Equidistant};
+    /// let equi = Equidistant::normalized(11);
+    /// assert_eq!(equi.strict_lower_bound(-1.0),0);
+    /// assert_eq!(equi.strict_lower_bound(0.0),0);
+    /// assert_eq!(equi.strict_lower_bound(0.15),2);
+    /// assert_eq!(equi.strict_lower_bound(20.0),11);
+    /// ```
+    fn strict_lower_bound(&self, element: Self::Output) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        // extrapolation to the left
+        if element <= self.offset {
+            return 0;
+        }
+        // all elements are equal to the offset
+        if self.len <= 1 || self.step == R::zero() {
+            return self.len();
+        }
+        let scaled = (element - self.offset) / self.step;
+        // now unrwapping is fine as we are above zero.
+        let max_index = scaled.ceil().to_index().unwrap();
+        self.len().min(max_index)
+    }
     /// Returns the smallest index between `min` and `max`
     /// for which the corresponding element is bigger then the input.
     /// If all elements are bigger, this function will return the given maximum.
@@ -481,9 +789,13 @@ where
         if element < self.gen(min) {
             return min;
         }
+        // all elements are equal to the offset
+        if self.len <= 1 || self.step == R::zero() {
+            return max;
+        }
         let scaled = (element - self.offset) / self.step;
         // now unrwapping is fine as we are above zero.
-        let min_index = scaled.floor().to_usize().unwrap();
+        let min_index = scaled.floor().to_index().unwrap();
         max.min(min_index + 1)
     }
     /// Find the values inside the collection for which the given element is inbetween
@@ -536,19 +848,60 @@ where
             return (0, 1, scaled);
         }
         // now unrwapping is fine as we are above zero.
-        let min_index = scaled.floor().to_usize().unwrap();
-        let max_index = scaled.ceil().to_usize().unwrap();
+        let min_index = scaled.floor().to_index().unwrap();
+        let max_index = scaled.ceil().to_index().unwrap();
         //extrapolation to the right
         if max_index >= self.len {
             return (
                 self.len - 2,
                 self.len - 1,
-                scaled - R::from_usize(self.len - 2).unwrap(),
+                scaled - R::from_index(self.len - 2).unwrap(),
             );
         }
         let factor = scaled.fract();
         (min_index, max_index, factor)
     }
+    /// Find the values inside the collection for which the given element is inbetween
+    /// and a linear factor at how close it is to which value.
+    ///
+    /// In contrast to [`upper_border()`], an element equal to a knot is associated with
+    /// the segment ending at that knot.
+    ///
+    /// # Panics
+    ///
+    /// May Panic if `self` is has less than *two* elements.
+    /// Also panics if length-1 as usize can not be converted to `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, Equidistant};
+    /// let equdist = Equidistant::normalized(5);
+    /// assert_eq!(equdist.lower_border(0.5), (1, 2, 1.0));
+    /// assert_eq!(equdist.lower_border(0.625), (2, 3, 0.5));
+    /// assert_eq!(equdist.lower_border(0.0), (0, 1, 0.0));
+    /// ```
+    ///
+    /// [`upper_border()`]: SortedGenerator::upper_border()
+    fn lower_border(&self, element: R) -> (usize, usize, R) {
+        let scaled = (element - self.offset) / self.step;
+        // extrapolation to the left
+        if element <= self.offset {
+            return (0, 1, scaled);
+        }
+        // now unrwapping is fine as we are above zero.
+        let max_index = scaled.ceil().to_index().unwrap();
+        //extrapolation to the right
+        if max_index >= self.len {
+            return (
+                self.len - 2,
+                self.len - 1,
+                scaled - R::from_index(self.len - 2).unwrap(),
+            );
+        }
+        let factor = scaled - R::from_index(max_index - 1).unwrap();
+        (max_index - 1, max_index, factor)
+    }
 }
 
 /// Struct used as a generator for equidistant elements in constant context.
@@ -626,6 +979,38 @@ where
         let min_index = scaled.floor().to_usize().unwrap();
         self.len().min(min_index + 1)
     }
+    /// Returns the smallest index for which the corresponding element is bigger or equal to the input.
+    /// Such the element before this index is the biggest element which is strictly smaller than the input.
+    /// If all elements are smaller, this function will return self.len().
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    /// May panic if `N-1` can not be converted to type `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, ConstEquidistant};
+    /// let equi = ConstEquidistant::<f64,11>::new();
+    /// assert_eq!(equi.strict_lower_bound(-1.0),0);
+    /// assert_eq!(equi.strict_lower_bound(0.0),0);
+    /// assert_eq!(equi.strict_lower_bound(0.15),2);
+    /// assert_eq!(equi.strict_lower_bound(20.0),11);
+    /// ```
+    fn strict_lower_bound(&self, element: Self::Output) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        // extrapolation to the left
+        if element <= R::zero() {
+            return 0;
+        }
+        let scaled = element * R::from_usize(N - 1).unwrap();
+        // now unrwapping is fine as we are above zero.
+        let max_index = scaled.ceil().to_usize().unwrap();
+        self.len().min(max_index)
+    }
     /// Returns the smallest index between `min` and `max`
     /// for which the corresponding element is bigger then the input.
     /// If all elements are bigger, this function will return the given maximum.
@@ -712,4 +1097,222 @@ where
         let factor = scaled.fract();
         (min_index, max_index, factor)
     }
+    /// Find the values inside the collection for which the given element is inbetween
+    /// and a linear factor at how close it is to which value.
+    ///
+    /// In contrast to [`upper_border()`], an element equal to a knot is associated with
+    /// the segment ending at that knot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is has less than *two* elements.
+    /// Also panics if length-1 as usize can not be converted to `R`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enterpolation::{SortedGenerator, ConstEquidistant};
+    /// let equdist = ConstEquidistant::<f64,5>::new();
+    /// assert_eq!(equdist.lower_border(0.5), (1, 2, 1.0));
+    /// assert_eq!(equdist.lower_border(0.625), (2, 3, 0.5));
+    /// assert_eq!(equdist.lower_border(0.0), (0, 1, 0.0));
+    /// ```
+    ///
+    /// [`upper_border()`]: SortedGenerator::upper_border()
+    fn lower_border(&self, element: R) -> (usize, usize, R)
+    where
+        R: PartialOrd + Sub<Output = R> + Div<Output = R> + Copy + Debug,
+    {
+        let scaled = element * R::from_usize(N - 1).unwrap();
+        // extrapolation to the left
+        if element <= R::zero() {
+            return (0, 1, scaled);
+        }
+        // now unrwapping is fine as we are above zero.
+        let max_index = scaled.ceil().to_usize().unwrap();
+        //extrapolation to the right
+        if max_index >= N {
+            return (N - 2, N - 1, scaled - R::from_usize(N - 2).unwrap());
+        }
+        let factor = scaled - R::from_usize(max_index - 1).unwrap();
+        (max_index - 1, max_index, factor)
+    }
+}
+
+/// Struct used as a generator for equidistant elements with a custom domain in constant context.
+/// Acts like an array of knots.
+///
+/// In contrast to [`ConstEquidistant`], the knots do not have to be within `[0.0,1.0]`.
+/// As floating point numbers can not be used as const generics, the first knot and the distance
+/// between knots are given to the constant constructor [`new()`] instead.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{ConstEquidistantDomain, DiscreteGenerator, Generator};
+/// // knots every 15 seconds within a minute
+/// const KNOTS: ConstEquidistantDomain<f64, 5> = ConstEquidistantDomain::new(0.0, 15.0);
+/// assert_eq!(KNOTS.len(), 5);
+/// assert_eq!(KNOTS.gen(2), 30.0);
+/// assert_eq!(KNOTS.gen(4), 60.0);
+/// ```
+///
+/// [`new()`]: ConstEquidistantDomain::new()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ConstEquidistantDomain<R, const N: usize> {
+    start: R,
+    step: R,
+}
+
+impl<R, const N: usize> ConstEquidistantDomain<R, N> {
+    /// Create a list of `N` equidistant real numbers, beginning with `start` and increasing by `step`.
+    pub const fn new(start: R, step: R) -> Self {
+        ConstEquidistantDomain { start, step }
+    }
+}
+
+impl<R, const N: usize> ConstEquidistantDomain<R, N>
+where
+    R: Copy,
+{
+    /// Returns the equivalent runtime knots, which all calculations are forwarded to.
+    fn equidistant(&self) -> Equidistant<R> {
+        Equidistant {
+            len: N,
+            step: self.step,
+            offset: self.start,
+        }
+    }
+}
+
+impl<R, const N: usize> Generator<usize> for ConstEquidistantDomain<R, N>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: usize) -> R {
+        self.equidistant().gen(input)
+    }
+}
+
+impl<R, const N: usize> DiscreteGenerator for ConstEquidistantDomain<R, N>
+where
+    R: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<R, const N: usize> SortedGenerator for ConstEquidistantDomain<R, N>
+where
+    R: Real + FromPrimitive,
+{
+    fn strict_upper_bound(&self, element: Self::Output) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        self.equidistant().strict_upper_bound(element)
+    }
+    fn strict_lower_bound(&self, element: Self::Output) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        self.equidistant().strict_lower_bound(element)
+    }
+    fn strict_upper_bound_clamped(&self, element: Self::Output, min: usize, max: usize) -> usize
+    where
+        Self::Output: PartialOrd + Copy,
+    {
+        self.equidistant()
+            .strict_upper_bound_clamped(element, min, max)
+    }
+    fn upper_border(&self, element: R) -> (usize, usize, R)
+    where
+        R: PartialOrd + Sub<Output = R> + Div<Output = R> + Copy + Debug,
+    {
+        self.equidistant().upper_border(element)
+    }
+    fn lower_border(&self, element: R) -> (usize, usize, R)
+    where
+        R: PartialOrd + Sub<Output = R> + Div<Output = R> + Copy + Debug,
+    {
+        self.equidistant().lower_border(element)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn new_dedup() {
+        use alloc::vec;
+        // partially equal knots, keeping the last element of each run
+        let table = [(0.0, 1), (0.0, 2), (1.0, 3), (2.0, 4), (2.0, 5), (2.0, 6)];
+        let (knots, elements) = Sorted::new_dedup(table, |_, last| last).unwrap();
+        assert_eq!(knots.to_vec(), vec![0.0, 1.0, 2.0]);
+        assert_eq!(elements, vec![2, 3, 6]);
+        // all equal knots collapse to a single one
+        let table = [(1.0, 1), (1.0, 2), (1.0, 3)];
+        let (knots, elements) = Sorted::new_dedup(table, |a, b| a + b).unwrap();
+        assert_eq!(knots.to_vec(), vec![1.0]);
+        assert_eq!(elements, vec![6]);
+        // unsorted and incomparable knots are rejected
+        let unsorted = Sorted::new_dedup([(1.0, 1), (1.0, 2), (0.5, 3)], |a, _| a);
+        assert_eq!(unsorted.unwrap_err(), NotSorted::new(2));
+        assert!(Sorted::new_dedup([(f64::NAN, 1)], |a, _| a).is_err());
+    }
+
+    #[cfg(all(feature = "linear", feature = "alloc"))]
+    #[test]
+    fn dedup_linear() {
+        use crate::linear::Linear;
+        let table = [(0.0, 0.0), (1.0, 2.0), (1.0, 4.0), (2.0, 0.0)];
+        let (knots, elements) = Sorted::new_dedup(table, |_, last| last).unwrap();
+        let linear = Linear::new(elements, knots, crate::Identity::new()).unwrap();
+        for (input, result) in [(0.5, 2.0), (1.0, 4.0), (1.5, 2.0)] {
+            assert_f64_near!(linear.gen(input), result);
+        }
+    }
+
+    #[test]
+    fn lower_border() {
+        // the equidistant overrides agree with the default implementation
+        let sorted = Sorted::new_unchecked([0.0, 0.25, 0.5, 0.75, 1.0]);
+        let equi = Equidistant::normalized(5);
+        let constant = ConstEquidistant::<f64, 5>::new();
+        for element in [-1.0, 0.0, 0.1, 0.25, 0.6, 0.75, 1.0, 2.0] {
+            let expected = sorted.lower_border(element);
+            for (min, max, factor) in [equi.lower_border(element), constant.lower_border(element)] {
+                assert_eq!((min, max), (expected.0, expected.1));
+                assert_f64_near!(factor, expected.2);
+            }
+            assert_eq!(
+                constant.strict_lower_bound(element),
+                sorted.strict_lower_bound(element)
+            );
+        }
+    }
+
+    #[test]
+    fn const_equidistant_domain() {
+        const KNOTS: ConstEquidistantDomain<f64, 5> = ConstEquidistantDomain::new(0.0, 15.0);
+        let equi = Equidistant::step(5, 0.0, 15.0);
+        assert_eq!(KNOTS.len(), 5);
+        assert_eq!(KNOTS.first(), Some(0.0));
+        assert_eq!(KNOTS.last(), Some(60.0));
+        for element in [-10.0, 0.0, 7.5, 15.0, 40.0, 60.0, 70.0] {
+            assert_eq!(
+                KNOTS.strict_upper_bound(element),
+                equi.strict_upper_bound(element)
+            );
+            let (min, max, factor) = KNOTS.upper_border(element);
+            let expected = equi.upper_border(element);
+            assert_eq!((min, max), (expected.0, expected.1));
+            assert_f64_near!(factor, expected.2);
+        }
+    }
 }
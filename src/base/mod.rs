@@ -1,37 +1,88 @@
 mod adaptors;
 mod generator;
 mod list;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_array;
 mod space;
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+
 // These get re-exported at the library level.
 #[allow(unreachable_pub)]
-pub use adaptors::{Clamp, Composite, Repeat, Slice, Stack, TransformInput, Wrap};
+pub use adaptors::{
+    Affine, CachedCurve, Chunks, Clamp, Cloned, Composite, Concat, DeadZone, EasedInput,
+    Extrapolation, FiniteDifference, Inspect, InvalidDeadZone, Map, Offset, QuantizeOutput,
+    Reflect, ReflectDomain, Repeat, RepeatN, Reversed, ReversedElements, Scale, Slice, Stack,
+    StackArray, TransformInput, Windows, Wrap, WrapDomain, WrapMode, ZipWith,
+};
+#[allow(unreachable_pub)]
+#[cfg(feature = "std")]
+pub use adaptors::{ArcLength, Cache};
 #[allow(unreachable_pub)]
 pub use generator::{
-    ConstDiscreteGenerator, Curve, DiscreteGenerator, Extract, Generator, Stepper, Take,
+    ConstDiscreteGenerator, Curve, DiscreteGenerator, Extract, Generator, Projectable, Stepper,
+    Take, TakeWithTangent,
 };
 #[allow(unreachable_pub)]
-pub use list::{ConstEquidistant, Equidistant, NotSorted, Sorted, SortedGenerator};
+pub use list::{
+    ConstEquidistant, ConstEquidistantDomain, Equidistant, InvalidEquidistant, NotSorted, Sorted,
+    SortedGenerator,
+};
 #[allow(unreachable_pub)]
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use space::DynSpace;
 #[allow(unreachable_pub)]
 pub use space::{ConstSpace, Space};
-
+#[allow(unreachable_pub)]
 #[cfg(feature = "std")]
+pub use space::{PooledSpace, PooledWorkspace};
+
+#[cfg(feature = "alloc")]
 impl<T: Copy> Generator<usize> for Vec<T> {
     type Output = T;
     fn gen(&self, input: usize) -> Self::Output {
         self[input]
     }
 }
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<T: Copy> DiscreteGenerator for Vec<T> {
     fn len(&self) -> usize {
         self.len()
     }
 }
 
+/// Elements of a ring buffer, such that a sliding window of elements can be used without copying them.
+///
+/// Indexing a `VecDeque` is O(1), as is generating an element.
+#[cfg(feature = "alloc")]
+impl<T: Copy> Generator<usize> for VecDeque<T> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self[input]
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: Copy> DiscreteGenerator for VecDeque<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy> Generator<usize> for Box<[T]> {
+    type Output = T;
+    fn gen(&self, input: usize) -> Self::Output {
+        self[input]
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: Copy> DiscreteGenerator for Box<[T]> {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
 // /// A stack of values or generators
 // #[cfg(feature = "std")]
 // impl<G,I> Generator<(usize, I)> for Vec<G>
@@ -0,0 +1,61 @@
+//! (De)serialization of arrays with generic length.
+//!
+//! The derive macros of serde only support arrays up to a length of 32 and not with a generic length.
+//! Use this module with `#[serde(with = "crate::base::serde_array")]` on fields of type `[T; N]`.
+//! Arrays are (de)serialized as tuples, exactly like serde does for arrays of fixed length.
+
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+pub(crate) fn serialize<S, T, const N: usize>(
+    array: &[T; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for element in array {
+        tuple.serialize_element(element)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
+
+struct ArrayVisitor<T, const N: usize>(PhantomData<fn() -> T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "an array of length {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut array: [Option<T>; N] = core::array::from_fn(|_| None);
+        for (index, slot) in array.iter_mut().enumerate() {
+            *slot = Some(
+                seq.next_element()?
+                    .ok_or_else(|| Error::invalid_length(index, &self))?,
+            );
+        }
+        // all slots are filled, otherwise we would have returned early
+        Ok(array.map(|element| element.expect("every element was deserialized")))
+    }
+}
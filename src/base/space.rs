@@ -1,4 +1,8 @@
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::{cell::RefCell, rc::Rc};
 
 /// Trait for constant or dynamic workspace handling.
 ///
@@ -24,7 +28,7 @@ pub trait Space<T> {
 }
 
 /// Struct to handle a constant workspace.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ConstSpace<T, const N: usize> {
     _phantom: PhantomData<fn() -> T>,
@@ -32,14 +36,14 @@ pub struct ConstSpace<T, const N: usize> {
 
 impl<T, const N: usize> Space<T> for ConstSpace<T, N>
 where
-    T: Default + Copy,
+    T: Default,
 {
     type Output = [T; N];
     fn len(&self) -> usize {
         N
     }
     fn workspace(&self) -> Self::Output {
-        [Default::default(); N]
+        core::array::from_fn(|_| Default::default())
     }
 }
 
@@ -64,18 +68,18 @@ impl<T, const N: usize> Default for ConstSpace<T, N> {
 /// This may impact performance as we always allocate memory. However this allows safe concurrency.
 ///
 /// [`workspace()`]: DynSpace::workspace()
-#[cfg(feature = "std")]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct DynSpace<T> {
     len: usize,
     _phantom: PhantomData<fn() -> T>,
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<T> Space<T> for DynSpace<T>
 where
-    T: Default + Copy,
+    T: Default + Clone,
 {
     type Output = Vec<T>;
     fn len(&self) -> usize {
@@ -86,7 +90,7 @@ where
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<T> DynSpace<T> {
     /// Create a workspace with given length at run-time.
     pub fn new(len: usize) -> Self {
@@ -96,3 +100,153 @@ impl<T> DynSpace<T> {
         }
     }
 }
+
+/// Struct which handles workspace at run-time and reuses its buffers.
+///
+/// In contrast to [`DynSpace`], the buffers handed out by [`workspace()`] are returned to an internal pool
+/// when they are dropped, such that repeated generation of values does not allocate memory.
+/// The buffers are cleared every time they are handed out.
+///
+/// As the pool is shared via `Rc<RefCell<_>>`, this struct and all curves using it are neither `Send` nor `Sync`.
+/// For concurrency, either use [`DynSpace`] or create one curve with its own `PooledSpace` per thread.
+/// Cloning a `PooledSpace` creates a new, empty pool.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "bspline", doc = "```rust")]
+#[cfg_attr(not(feature = "bspline"), doc = "```ignore")]
+/// # use enterpolation::{bspline::{BSpline, BSplineError}, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), BSplineError> {
+/// let bspline = BSpline::builder()
+///                 .elements(vec![0.0,5.0,3.0])
+///                 .knots(vec![0.0,0.0,1.0,1.0])
+///                 .pooled()
+///                 .build()?;
+/// // only the first generation allocates memory
+/// for (value,result) in bspline.take(3).zip([0.0,3.25,3.0]) {
+///     assert_f64_near!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`workspace()`]: PooledSpace::workspace()
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct PooledSpace<T> {
+    len: usize,
+    pool: Rc<RefCell<Vec<Vec<T>>>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> PooledSpace<T> {
+    /// Create a pooled workspace with given length at run-time.
+    pub fn new(len: usize) -> Self {
+        PooledSpace {
+            len,
+            pool: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Clone for PooledSpace<T> {
+    fn clone(&self) -> Self {
+        PooledSpace::new(self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> PartialEq for PooledSpace<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Eq for PooledSpace<T> {}
+
+#[cfg(feature = "std")]
+impl<T> core::hash::Hash for PooledSpace<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Space<T> for PooledSpace<T>
+where
+    T: Default + Clone,
+{
+    type Output = PooledWorkspace<T>;
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn workspace(&self) -> Self::Output {
+        let mut buffer = self
+            .pool
+            .borrow_mut()
+            .pop()
+            .unwrap_or_else(|| Vec::with_capacity(self.len));
+        buffer.clear();
+        buffer.resize(self.len, Default::default());
+        PooledWorkspace {
+            buffer,
+            pool: Rc::clone(&self.pool),
+        }
+    }
+}
+
+/// Buffer handed out by [`PooledSpace`], which returns itself to the pool when dropped.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct PooledWorkspace<T> {
+    buffer: Vec<T>,
+    pool: Rc<RefCell<Vec<Vec<T>>>>,
+}
+
+#[cfg(feature = "std")]
+impl<T> AsMut<[T]> for PooledWorkspace<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.buffer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Drop for PooledWorkspace<T> {
+    fn drop(&mut self) {
+        self.pool
+            .borrow_mut()
+            .push(core::mem::take(&mut self.buffer));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pooled_space() {
+        let space = PooledSpace::<f64>::new(3);
+        let mut first = space.workspace();
+        first.as_mut()[0] = 5.0;
+        let address = first.as_mut().as_ptr();
+        // nested workspaces do not share their buffer
+        let mut second = space.workspace();
+        assert_ne!(second.as_mut().as_ptr(), address);
+        drop(second);
+        drop(first);
+        let mut reused = space.workspace();
+        assert_eq!(reused.as_mut().as_ptr(), address);
+        assert_eq!(reused.as_mut(), &[0.0; 3]);
+        assert_eq!(reused.as_mut().len(), space.len());
+        assert_eq!(space.clone(), space);
+        let second = space.workspace();
+        drop(second);
+        drop(reused);
+        assert_eq!(space.pool.borrow().len(), 2);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{DiscreteGenerator, Generator};
+use core::ops::{Mul, Sub};
+use num_traits::cast::FromPrimitive;
+use num_traits::real::Real;
+
+/// DiscreteGenerator Adaptor which generates the elements of the derivative of a bezier curve.
+///
+/// For a bezier curve of degree `n` with elements `P_0,...,P_n`, the elements of its derivative
+/// are `n*(P_{i+1}-P_i)`. Such the derivative is again a bezier curve, but of degree `n-1`.
+///
+/// If the underlying generator has only a single element, the derivative is constant zero.
+/// This is represented by a single element generator returning `P_0 * 0`.
+///
+/// This struct is created by the [`derivative()`] method of bezier curves.
+///
+/// [`derivative()`]: super::Bezier::derivative()
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DerivativeElements<E, R> {
+    inner: E,
+    degree: R,
+}
+
+impl<E, R> DerivativeElements<E, R>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Creates the elements of the derivative of the bezier curve with the given elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree of the curve can not be converted to `R`.
+    pub fn new(inner: E) -> Self {
+        DerivativeElements {
+            degree: R::from_usize(inner.len().saturating_sub(1)).unwrap(),
+            inner,
+        }
+    }
+}
+
+impl<E, R> Generator<usize> for DerivativeElements<E, R>
+where
+    E: DiscreteGenerator,
+    E::Output: Sub<Output = E::Output> + Mul<R, Output = E::Output>,
+    R: Real,
+{
+    type Output = E::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        if self.inner.len() < 2 {
+            return self.inner.gen(0) * R::zero();
+        }
+        (self.inner.gen(input + 1) - self.inner.gen(input)) * self.degree
+    }
+}
+
+impl<E, R> DiscreteGenerator for DerivativeElements<E, R>
+where
+    E: DiscreteGenerator,
+    E::Output: Sub<Output = E::Output> + Mul<R, Output = E::Output>,
+    R: Real,
+{
+    fn len(&self) -> usize {
+        self.inner.len().saturating_sub(1).max(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DerivativeElements;
+    use crate::{DiscreteGenerator, Generator};
+
+    #[test]
+    fn derivative_elements() {
+        let der = DerivativeElements::<_, f64>::new([1.0, 2.0, 4.0, 8.0]);
+        assert_eq!(der.len(), 3);
+        assert_f64_near!(der.gen(0), 3.0);
+        assert_f64_near!(der.gen(1), 6.0);
+        assert_f64_near!(der.gen(2), 12.0);
+        let constant = DerivativeElements::<_, f64>::new([5.0]);
+        assert_eq!(constant.len(), 1);
+        assert_f64_near!(constant.gen(0), 0.0);
+    }
+}
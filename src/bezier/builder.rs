@@ -3,11 +3,13 @@
 //! Each interpolation has it's own builder module, which accumalates all methods to create their interpolation.
 
 use super::error::{BezierError, Empty};
-use super::{Bezier, TooSmallWorkspace};
+use super::{Bezier, BezierEvaluation, TooSmallWorkspace};
 use crate::builder::{InputDomain, NormalizedInput, Unknown, WithWeight, WithoutWeight};
 use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use crate::DynSpace;
+#[cfg(feature = "std")]
+use crate::PooledSpace;
 use crate::{
     ConstDiscreteGenerator, ConstSpace, DiscreteGenerator, Generator, Space, TransformInput,
 };
@@ -42,6 +44,7 @@ pub struct BezierDirector<I, E, S, W> {
     input: I,
     elements: E,
     space: S,
+    evaluation: BezierEvaluation,
     _phantom: PhantomData<*const W>,
 }
 
@@ -111,6 +114,7 @@ impl BezierDirector<Unknown, Unknown, Unknown, Unknown> {
             input: Unknown,
             elements: Unknown,
             space: Unknown,
+            evaluation: BezierEvaluation::DeCasteljau,
             _phantom: PhantomData,
         }
     }
@@ -147,6 +151,7 @@ impl BezierDirector<Unknown, Unknown, Unknown, Unknown> {
             input: self.input,
             space: self.space,
             elements,
+            evaluation: self.evaluation,
             _phantom: PhantomData,
         })
     }
@@ -184,6 +189,7 @@ impl BezierDirector<Unknown, Unknown, Unknown, Unknown> {
             input: self.input,
             space: self.space,
             elements: Weights::new(gen),
+            evaluation: self.evaluation,
             _phantom: PhantomData,
         })
     }
@@ -277,6 +283,7 @@ impl<E, W> BezierDirector<Unknown, E, Unknown, W> {
             input: NormalizedInput::new(),
             space: self.space,
             elements: self.elements,
+            evaluation: self.evaluation,
             _phantom: self._phantom,
         }
     }
@@ -286,6 +293,7 @@ impl<E, W> BezierDirector<Unknown, E, Unknown, W> {
             input: InputDomain::new(start, end),
             space: self.space,
             elements: self.elements,
+            evaluation: self.evaluation,
             _phantom: self._phantom,
         }
     }
@@ -315,12 +323,32 @@ where
     /// Tells the builder to use a vector as workspace,
     /// such you don't need to know the degree of the bezier curve at compile-time,
     /// but every generation of a value an allocation of memory will be necessary.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BezierDirector<I, E, DynSpace<E::Output>, W> {
         BezierDirector {
             input: self.input,
             space: DynSpace::new(self.elements.len()),
             elements: self.elements,
+            evaluation: self.evaluation,
+            _phantom: self._phantom,
+        }
+    }
+
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bezier curve at compile-time
+    /// and the memory is only allocated for the first generation of a value.
+    /// However, the curve is neither `Send` nor `Sync`. See [`PooledSpace`] for more information.
+    ///
+    /// [`PooledSpace`]: crate::PooledSpace
+    #[cfg(feature = "std")]
+    pub fn pooled(self) -> BezierDirector<I, E, PooledSpace<E::Output>, W> {
+        BezierDirector {
+            input: self.input,
+            space: PooledSpace::new(self.elements.len()),
+            elements: self.elements,
+            evaluation: self.evaluation,
             _phantom: self._phantom,
         }
     }
@@ -337,6 +365,7 @@ where
             input: self.input,
             space: ConstSpace::new(),
             elements: self.elements,
+            evaluation: self.evaluation,
             _phantom: self._phantom,
         }
     }
@@ -364,6 +393,7 @@ where
             input: self.input,
             space,
             elements: self.elements,
+            evaluation: self.evaluation,
             _phantom: self._phantom,
         })
     }
@@ -378,13 +408,28 @@ where
     /// Tells the builder to use a vector as workspace,
     /// such you don't need to know the degree of the bezier curve at compile-time,
     /// but every generation of a value an allocation of memory will be necessary.
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BezierBuilder<I, E, DynSpace<E::Output>, W> {
         BezierBuilder {
             inner: self.inner.map(|director| director.dynamic()),
         }
     }
 
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bezier curve at compile-time
+    /// and the memory is only allocated for the first generation of a value.
+    /// However, the curve is neither `Send` nor `Sync`. See [`PooledSpace`] for more information.
+    ///
+    /// [`PooledSpace`]: crate::PooledSpace
+    #[cfg(feature = "std")]
+    pub fn pooled(self) -> BezierBuilder<I, E, PooledSpace<E::Output>, W> {
+        BezierBuilder {
+            inner: self.inner.map(|director| director.pooled()),
+        }
+    }
+
     /// Set the workspace which the interpolation uses.
     ///
     /// Tells the builder the size of the workspace needed such that no memory allocations are needed
@@ -416,6 +461,54 @@ where
     }
 }
 
+impl<I, E, S, W> BezierDirector<I, E, S, W> {
+    /// Set the algorithm used to generate values of the curve.
+    ///
+    /// By default, [`DeCasteljau`] is used. For curves of high degree,
+    /// [`Horner`] may be faster and more accurate.
+    ///
+    /// [`DeCasteljau`]: BezierEvaluation::DeCasteljau
+    /// [`Horner`]: BezierEvaluation::Horner
+    pub fn evaluation(mut self, evaluation: BezierEvaluation) -> Self {
+        self.evaluation = evaluation;
+        self
+    }
+}
+
+impl<I, E, S, W> BezierBuilder<I, E, S, W> {
+    /// Set the algorithm used to generate values of the curve.
+    ///
+    /// By default, [`DeCasteljau`] is used. For curves of high degree,
+    /// [`Horner`] may be faster and more accurate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError, BezierEvaluation}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements([20.0,100.0,0.0,200.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .evaluation(BezierEvaluation::Horner)
+    ///     .build()?;
+    /// assert_f64_near!(bez.gen(0.25), 53.75);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`DeCasteljau`]: BezierEvaluation::DeCasteljau
+    /// [`Horner`]: BezierEvaluation::Horner
+    pub fn evaluation(self, evaluation: BezierEvaluation) -> Self {
+        BezierBuilder {
+            inner: self.inner.map(|director| director.evaluation(evaluation)),
+        }
+    }
+}
+
 impl<R, E, S> BezierDirector<NormalizedInput<R>, E, S, WithoutWeight>
 where
     E: DiscreteGenerator,
@@ -425,7 +518,7 @@ where
 {
     /// Build a bezier interpolation.
     pub fn build(self) -> Bezier<R, E, S> {
-        Bezier::new_unchecked(self.elements, self.space)
+        Bezier::new_unchecked(self.elements, self.space).with_evaluation(self.evaluation)
     }
 }
 
@@ -453,7 +546,7 @@ where
     #[allow(clippy::type_complexity)]
     pub fn build(self) -> TransformInput<Bezier<R, E, S>, R, R> {
         TransformInput::normalized_to_domain(
-            Bezier::new_unchecked(self.elements, self.space),
+            Bezier::new_unchecked(self.elements, self.space).with_evaluation(self.evaluation),
             self.input.start,
             self.input.end,
         )
@@ -486,7 +579,9 @@ where
 {
     /// Build a weighted bezier interpolation.
     pub fn build(self) -> WeightedBezier<R, G, S> {
-        Weighted::new(Bezier::new_unchecked(self.elements, self.space))
+        Weighted::new(
+            Bezier::new_unchecked(self.elements, self.space).with_evaluation(self.evaluation),
+        )
     }
 }
 
@@ -520,7 +615,9 @@ where
     #[allow(clippy::type_complexity)]
     pub fn build(self) -> TransformInput<WeightedBezier<R, G, S>, R, R> {
         TransformInput::normalized_to_domain(
-            Weighted::new(Bezier::new_unchecked(self.elements, self.space)),
+            Weighted::new(
+                Bezier::new_unchecked(self.elements, self.space).with_evaluation(self.evaluation),
+            ),
             self.input.start,
             self.input.end,
         )
@@ -14,6 +14,10 @@ pub enum BezierError {
     Empty(Empty),
     /// Error returned if the given workspace is too small for the interpolation to use.
     TooSmallWorkspace(TooSmallWorkspace),
+    /// Error returned if the number of points and parameters to interpolate are not equal.
+    PointParamInequality(PointParamInequality),
+    /// Error returned if no bezier curve interpolating the given points exists or it is not unique.
+    Singular(Singular),
 }
 
 impl fmt::Display for BezierError {
@@ -21,6 +25,8 @@ impl fmt::Display for BezierError {
         match self {
             BezierError::Empty(inner) => inner.fmt(f),
             BezierError::TooSmallWorkspace(inner) => inner.fmt(f),
+            BezierError::PointParamInequality(inner) => inner.fmt(f),
+            BezierError::Singular(inner) => inner.fmt(f),
         }
     }
 }
@@ -37,5 +43,73 @@ impl From<TooSmallWorkspace> for BezierError {
     }
 }
 
+impl From<PointParamInequality> for BezierError {
+    fn from(from: PointParamInequality) -> Self {
+        BezierError::PointParamInequality(from)
+    }
+}
+
+impl From<Singular> for BezierError {
+    fn from(from: Singular) -> Self {
+        BezierError::Singular(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for BezierError {}
+
+/// Error returned if the number of points and parameters to interpolate are not equal.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PointParamInequality {
+    /// The number of points found.
+    points: usize,
+    /// The number of parameters found.
+    params: usize,
+}
+
+impl PointParamInequality {
+    /// Create a new error with the number of points and parameters found.
+    pub fn new(points: usize, params: usize) -> Self {
+        PointParamInequality { points, params }
+    }
+}
+
+impl fmt::Display for PointParamInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be a parameter for each point. Found {} points and {} parameters.",
+            self.points, self.params
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PointParamInequality {}
+
+/// Error returned if the linear system to calculate the elements of a bezier curve is singular.
+///
+/// This happens for example if a parameter is given more than once.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Singular {}
+
+impl Singular {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        Singular {}
+    }
+}
+
+impl fmt::Display for Singular {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The points can not be interpolated by a unique bezier curve. Are all parameters distinct?"
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Singular {}
@@ -26,17 +26,27 @@
 //!
 //! [`BezierBuilder`]: BezierBuilder
 use crate::builder::Unknown;
-use crate::{Curve, DiscreteGenerator, Generator, Space};
+use crate::easing::Easing;
+use crate::geometry::{self, Cross, EuclideanVector};
+use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
+#[cfg(feature = "alloc")]
+use crate::DynSpace;
+use crate::{ConstDiscreteGenerator, ConstSpace, Curve, DiscreteGenerator, Generator, Space};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
-use core::ops::{Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 use num_traits::cast::FromPrimitive;
 use num_traits::real::Real;
 use topology_traits::Merge;
 
+mod adaptors;
+pub use adaptors::DerivativeElements;
 mod builder;
 pub use builder::{BezierBuilder, BezierDirector};
 mod error;
-pub use error::{BezierError, Empty, TooSmallWorkspace};
+pub use error::{BezierError, Empty, PointParamInequality, Singular, TooSmallWorkspace};
 
 /// Calculate a pascalsche triangle with the given closure until the maximal steps as levels are reached.
 /// If one wants to fold all values into the first position of the given buffer
@@ -66,7 +76,8 @@ where
     let elements = triangle.as_mut();
     let len = elements.len();
     for k in 1..=steps {
-        for i in k..len {
+        // iterate backwards such that the values of the previous level are still available
+        for i in (k..len).rev() {
             elements[i] = func(elements[i - 1], elements[i]);
         }
     }
@@ -90,6 +101,65 @@ where
     elements.as_mut()[0]
 }
 
+/// Bezier curve interpolation by summing up the elements weighted with the bernstein polynomials.
+///
+/// The sum is accumulated in nested form, merging every element once into the weighted average
+/// of all previous ones. Each merge factor lies within `[0.0,1.0]` as long as the scalar does.
+/// Panics if not at least 1 element exists.
+fn bezier_horner<R, E>(elements: &E, scalar: R) -> E::Output
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Copy,
+    R: Real,
+{
+    let degree = elements.len() - 1;
+    let inverse = R::one() - scalar;
+    // the binomial coefficient is calculated iteratively from the degree as scalar
+    let mut degree_scalar = R::zero();
+    for _ in 0..degree {
+        degree_scalar = degree_scalar + R::one();
+    }
+    let mut binomial = R::one();
+    let mut index_scalar = R::zero();
+    let mut weight_sum = inverse.powi(degree as i32);
+    let mut result = elements.gen(0);
+    for k in 1..=degree {
+        index_scalar = index_scalar + R::one();
+        binomial = binomial * (degree_scalar - index_scalar + R::one()) / index_scalar;
+        let weight = binomial * scalar.powi(k as i32) * inverse.powi((degree - k) as i32);
+        weight_sum = weight_sum + weight;
+        // as long as all previous weights are zero, there is nothing to merge with
+        if weight_sum > R::zero() {
+            result = result.merge(elements.gen(k), weight / weight_sum);
+        }
+    }
+    result
+}
+
+/// Integral of the bezier curve from zero to the given scalar.
+///
+/// The elements of the curve restricted to `[0.0,scalar]` are the first elements of each level of de Casteljau's algorithm.
+/// The integral of a bezier curve over `[0.0,1.0]` is the mean of its elements, which is scaled by the length of the interval.
+/// This mutates the elements, such copying them first is necessary!
+/// Panics if not at least 1 element exists.
+fn bezier_integral<R, P, T>(mut elements: P, scalar: R) -> T
+where
+    P: AsMut<[T]>,
+    T: Merge<R> + Add<Output = T> + Mul<R, Output = T> + Copy,
+    R: Real + FromPrimitive,
+{
+    let elements = elements.as_mut();
+    let len = elements.len();
+    let mut sum = elements[0];
+    for k in 1..len {
+        for i in 0..len - k {
+            elements[i] = elements[i].merge(elements[i + 1], scalar);
+        }
+        sum = sum + elements[0];
+    }
+    sum * (scalar / R::from_usize(len).unwrap())
+}
+
 /// Bezier curve interpolate/extrapolate and tangent calculation with the elements given.
 /// This mutates the elements, such copying them first is necessary!
 /// Panics if not at least 1 elements exist.
@@ -126,7 +196,8 @@ where
     R: Real + FromPrimitive,
 {
     let len = elements.as_mut().len();
-    let deg = K.min(len - 1);
+    // highest deriative which is not always zero
+    let deg = K.saturating_sub(1).min(len - 1);
     triangle_folding_inline(
         elements.as_mut(),
         |first, second| first.merge(second, scalar),
@@ -135,8 +206,9 @@ where
     // take a zero out vector which can be copied to initialise the array (and have the right default)
     let mut grad = [elements.as_mut()[0] * R::zero(); K];
     for k in (1..=deg).rev() {
-        //calculate difference folding
+        // the k-th deriative is given by the k-th difference of the first k+1 elements
         let grad_slice = &mut grad[..=k];
+        grad_slice.copy_from_slice(&elements.as_mut()[..=k]);
         lower_triangle_folding_inline(grad_slice, |first, second| second - first, k);
         let prod = R::from_usize((len - k..len).product::<usize>()).unwrap();
         grad[k] = grad[k] * prod;
@@ -146,25 +218,201 @@ where
             |first, second| first.merge(second, scalar),
             1,
         );
-        // copy the necessary data over to grad
-        grad[..k].clone_from_slice(&elements.as_mut()[..k]);
+    }
+    if let Some(value) = grad.first_mut() {
+        *value = elements.as_mut()[0];
     }
     grad
 }
 
+/// Split the bezier curve given by the elements at the given scalar with the de Casteljau algorithm.
+/// The elements of the left and right sub-curves are written into the given buffers.
+/// This mutates the elements, such copying them first is necessary!
+/// Panics if the buffers are smaller than the elements.
+fn bezier_split<R, T>(elements: &mut [T], left: &mut [T], right: &mut [T], scalar: R)
+where
+    T: Merge<R> + Copy,
+    R: Real,
+{
+    let len = elements.len();
+    for k in 0..len {
+        left[k] = elements[0];
+        right[len - 1 - k] = elements[len - 1 - k];
+        triangle_folding_inline(
+            &mut *elements,
+            |first, second| first.merge(second, scalar),
+            1,
+        );
+    }
+}
+
+/// Elevate the degree of the bezier curve given by the elements by one.
+/// The elements of the elevated curve are written into the given buffer.
+/// Panics if the buffer is not exactly one element bigger than the given elements.
+fn bezier_elevate<R, T>(elements: &[T], elevated: &mut [T])
+where
+    T: Merge<R> + Copy,
+    R: Real + FromPrimitive,
+{
+    let len = elements.len();
+    let degree = R::from_usize(len).unwrap();
+    elevated[0] = elements[0];
+    elevated[len] = elements[len - 1];
+    for i in 1..len {
+        let factor = R::from_usize(i).unwrap() / degree;
+        elevated[i] = elements[i].merge(elements[i - 1], factor);
+    }
+}
+
+/// Maximal depth of subdivisions used to isolate roots of a polynomial in bernstein form.
+#[cfg(feature = "alloc")]
+const MAX_ROOT_DEPTH: usize = 64;
+
+/// Push all roots within the open interval `(start,end)` of the polynomial in bernstein form
+/// with the given coefficients over this interval into `roots`.
+///
+/// The roots are isolated by subdividing the polynomial until its coefficients change their sign at most once.
+/// An isolated root is then found with bisection.
+#[cfg(feature = "alloc")]
+fn bernstein_roots<R>(coefficients: &[R], start: R, end: R, depth: usize, roots: &mut Vec<R>)
+where
+    R: Real,
+{
+    let mut signs = coefficients
+        .iter()
+        .filter(|coefficient| !coefficient.is_zero())
+        .map(|coefficient| coefficient.is_sign_positive());
+    let first = match signs.next() {
+        Some(sign) => sign,
+        // the polynomial is zero everywhere
+        None => return,
+    };
+    let mut last = first;
+    let mut variations = 0;
+    for sign in signs {
+        if sign != last {
+            variations += 1;
+            last = sign;
+        }
+    }
+    let two = R::one() + R::one();
+    let len = coefficients.len();
+    match variations {
+        0 => {}
+        1 => {
+            // exactly one root, which we find by bisection
+            let (mut low, mut high) = (R::zero(), R::one());
+            loop {
+                let mid = (low + high) / two;
+                if mid <= low || mid >= high {
+                    break;
+                }
+                // horner's method does not need a workspace, such that we do not allocate for every step
+                let value = bezier_horner(&coefficients, mid);
+                if value.is_zero() {
+                    low = mid;
+                    high = mid;
+                    break;
+                }
+                if value.is_sign_positive() == first {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            roots.push(start + (end - start) * (low + high) / two);
+        }
+        _ => {
+            let mid = (start + end) / two;
+            if depth >= MAX_ROOT_DEPTH {
+                roots.push(mid);
+                return;
+            }
+            let mut left = vec![R::zero(); len];
+            let mut right = vec![R::zero(); len];
+            let half = R::one() / two;
+            bezier_split(&mut coefficients.to_vec(), &mut left, &mut right, half);
+            bernstein_roots(&left, start, mid, depth + 1, roots);
+            if left[len - 1].is_zero() {
+                roots.push(mid);
+            }
+            bernstein_roots(&right, mid, end, depth + 1, roots);
+        }
+    }
+}
+
+/// Algorithm used by a [`Bezier`] curve to generate its values.
+///
+/// Both algorithms generate the same values up to rounding errors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum BezierEvaluation {
+    /// Geometric construction with repeated merging of neighbouring elements.
+    ///
+    /// Needs `n(n+1)/2` merges for `n+1` elements and a workspace to hold them.
+    #[default]
+    DeCasteljau,
+    /// Nested summation of the elements weighted with the bernstein polynomials.
+    ///
+    /// Needs only `n` merges for `n+1` elements and no workspace. As every element is merged only once,
+    /// less rounding errors accumulate, which is noticeable for curves of high degree with `f32`.
+    /// Inputs outside of `[0.0,1.0]` are still generated with de Casteljau's algorithm.
+    Horner,
+}
+
 /// Bezier curve.
 ///
 /// See [bezier module] for more information.
 ///
+/// In contrast to linear interpolations, bezier curves have no [`Extrapolation`] policy.
+/// A bezier curve is a single polynomial, which is evaluated outside of its domain as well.
+/// Use the [`clamp()`] adaptor to hold the first and last element instead.
+///
 /// [bezier module]: self
-#[derive(Debug, Copy, Clone, PartialEq)]
+/// [`Extrapolation`]: crate::Extrapolation
+/// [`clamp()`]: crate::Curve::clamp()
+#[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Bezier<R, E, S> {
     elements: E,
     space: S,
+    #[cfg_attr(feature = "serde", serde(default))]
+    evaluation: BezierEvaluation,
     _input: PhantomData<*const R>,
 }
 
+// The input type is only a marker, such that comparing and hashing do not need any bounds on it.
+impl<R, E, S> PartialEq for Bezier<R, E, S>
+where
+    E: PartialEq,
+    S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements
+            && self.space == other.space
+            && self.evaluation == other.evaluation
+    }
+}
+
+impl<R, E, S> Eq for Bezier<R, E, S>
+where
+    E: Eq,
+    S: Eq,
+{
+}
+
+impl<R, E, S> Hash for Bezier<R, E, S>
+where
+    E: Hash,
+    S: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.elements.hash(state);
+        self.space.hash(state);
+        self.evaluation.hash(state);
+    }
+}
+
 impl Bezier<Unknown, Unknown, Unknown> {
     /// Get a builder for bezier curves.
     ///
@@ -238,6 +486,10 @@ where
 {
     type Output = E::Output;
     fn gen(&self, scalar: R) -> E::Output {
+        if self.evaluation == BezierEvaluation::Horner && scalar >= R::zero() && scalar <= R::one()
+        {
+            return bezier_horner(&self.elements, scalar);
+        }
         // we pass only slices to guarantee the size of workspace to match the number of elements
         bezier(
             &mut self.workspace().as_mut()[..self.elements.len()],
@@ -259,6 +511,15 @@ where
     }
 }
 
+impl<R, E, S> Easing<R> for Bezier<R, E, S>
+where
+    Self: Generator<R, Output = R>,
+{
+    fn ease(&self, factor: R) -> R {
+        self.gen(factor)
+    }
+}
+
 impl<R, E, S> Bezier<R, E, S>
 where
     E: DiscreteGenerator,
@@ -283,6 +544,419 @@ where
             scalar,
         )
     }
+
+    /// Calculate the curvature of the curve at the given input.
+    ///
+    /// See [`geometry::curvature()`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Point};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements([Point::new([-1.0,1.0]),Point::new([0.0,-1.0]),Point::new([1.0,1.0])])
+    ///                 .normalized::<f64>()
+    ///                 .constant()
+    ///                 .build()?;
+    /// // the curve is a parabola y = x^2 with curvature 2 at its vertex
+    /// assert_f64_near!(bezier.curvature(0.5), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`geometry::curvature()`]: crate::geometry::curvature()
+    pub fn curvature(&self, scalar: R) -> R
+    where
+        E::Output: EuclideanVector<R>,
+    {
+        let [_, first, second] = self.gen_with_deriatives::<3>(scalar);
+        geometry::curvature(first, second)
+    }
+
+    /// Calculate the Frenet frame of the curve at the given input.
+    ///
+    /// Returns the unit tangent, the unit normal and the unit binormal, in this order.
+    /// See [`geometry::frenet_frame()`] for more information.
+    ///
+    /// [`geometry::frenet_frame()`]: crate::geometry::frenet_frame()
+    pub fn frenet_frame(&self, scalar: R) -> [E::Output; 3]
+    where
+        E::Output: EuclideanVector<R> + Cross,
+    {
+        let [_, first, second] = self.gen_with_deriatives::<3>(scalar);
+        geometry::frenet_frame(first, second)
+    }
+}
+
+impl<R, G, S, T> Weighted<Bezier<R, Weights<G>, S>>
+where
+    G: DiscreteGenerator,
+    G::Output: IntoWeight<Element = T, Weight = R>,
+    Homogeneous<T, R>:
+        Merge<R> + Mul<R, Output = Homogeneous<T, R>> + Sub<Output = Homogeneous<T, R>> + Copy,
+    T: Sub<Output = T> + Mul<R, Output = T> + Div<R, Output = T> + Copy,
+    S: Space<Homogeneous<T, R>>,
+    R: Real + FromPrimitive,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// In contrast to the tangent of the inner interpolation, the tangent is projected
+    /// with the quotient rule, such that it is the derivative of the weighted curve itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::builder()
+    ///                 .elements_with_weights([(0.0,1.0),(1.0,3.0),(2.0,1.0)])
+    ///                 .normalized::<f64>()
+    ///                 .constant()
+    ///                 .build()?;
+    /// let [value, tangent] = bezier.gen_with_tangent(0.25);
+    /// assert_f64_near!(value, bezier.gen(0.25));
+    /// let delta = 1e-6;
+    /// let difference = (bezier.gen(0.25 + delta) - bezier.gen(0.25 - delta)) / (2.0 * delta);
+    /// assert!((tangent - difference).abs() < 1e-6);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gen_with_tangent(&self, scalar: R) -> [T; 2] {
+        let [value, derivative] = self.get_ref().gen_with_tangent(scalar);
+        value.project_with_derivative(derivative)
+    }
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator,
+    E::Output: Merge<R>
+        + Add<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Sub<Output = E::Output>
+        + Copy,
+    S: Space<E::Output>,
+    R: Real + FromPrimitive,
+{
+    /// Calculate the definite integral of the bezier curve from `start` to `end`.
+    ///
+    /// The integral is calculated exactly from the elements of the curve, such no sampling is involved.
+    /// Inputs outside of `[0.0,1.0]` are extrapolated.
+    /// If `start` is bigger than `end`, the integral is negative.
+    ///
+    /// In contrast to [`Curve::integrate()`], which approximates the integral of any curve, this method is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// // the curve 3x^2
+    /// let bez = Bezier::builder()
+    ///     .elements([0.0,0.0,3.0])
+    ///     .normalized::<f64>()
+    ///     .constant()
+    ///     .build()?;
+    /// assert_f64_near!(bez.integral(0.0, 1.0), 1.0);
+    /// assert_f64_near!(bez.integral(0.5, 2.0), 7.875);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn integral(&self, start: R, end: R) -> E::Output {
+        let len = self.elements.len();
+        // we pass only slices to guarantee the size of workspace to match the number of elements
+        let upper = bezier_integral(&mut self.workspace().as_mut()[..len], end);
+        let lower = bezier_integral(&mut self.workspace().as_mut()[..len], start);
+        upper - lower
+    }
+}
+
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator + Clone,
+    E::Output: Mul<R, Output = E::Output> + Sub<Output = E::Output>,
+    S: Space<E::Output> + Clone,
+    R: Real + FromPrimitive,
+{
+    /// Returns the derivative of the bezier curve, which itself is a bezier curve of one degree less.
+    ///
+    /// The elements of the derivative are calculated lazily from the elements of this curve.
+    /// If the curve is constant, the derivative is a constant curve of zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements([1.0,2.0,4.0])
+    ///     .normalized::<f64>()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let derivative = bez.derivative();
+    /// assert_f64_near!(derivative.gen(0.0), 2.0);
+    /// assert_f64_near!(derivative.gen(0.5), 3.0);
+    /// assert_f64_near!(derivative.gen(1.0), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn derivative(&self) -> Bezier<R, DerivativeElements<E, R>, S> {
+        Bezier {
+            elements: DerivativeElements::new(self.elements.clone()),
+            space: self.space.clone(),
+            evaluation: self.evaluation,
+            _input: PhantomData,
+        }
+    }
+}
+
+impl<R, E, T, const N: usize> Bezier<R, E, ConstSpace<T, N>>
+where
+    E: ConstDiscreteGenerator<N, Output = T>,
+    T: Merge<R> + Copy + Default,
+    R: Real,
+{
+    /// Splits the bezier curve at the given scalar into two bezier curves of the same degree.
+    ///
+    /// The first curve represents the part of this curve before the scalar,
+    /// the second curve the part after it. Both curves are reparameterized to the domain `[0.0,1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements([0.0,5.0,3.0])
+    ///     .normalized::<f64>()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let (left, right) = bez.split(0.5);
+    /// assert_f64_near!(left.gen(0.5), bez.gen(0.25));
+    /// assert_f64_near!(left.gen(1.0), bez.gen(0.5));
+    /// assert_f64_near!(right.gen(0.0), bez.gen(0.5));
+    /// assert_f64_near!(right.gen(0.5), bez.gen(0.75));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        &self,
+        scalar: R,
+    ) -> (
+        Bezier<R, [T; N], ConstSpace<T, N>>,
+        Bezier<R, [T; N], ConstSpace<T, N>>,
+    ) {
+        let mut left = ConstSpace::<T, N>::new().workspace();
+        let mut right = ConstSpace::<T, N>::new().workspace();
+        bezier_split(self.workspace().as_mut(), &mut left, &mut right, scalar);
+        (
+            Bezier::new_unchecked(left, ConstSpace::new()).with_evaluation(self.evaluation),
+            Bezier::new_unchecked(right, ConstSpace::new()).with_evaluation(self.evaluation),
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, E, T> Bezier<R, E, DynSpace<T>>
+where
+    E: DiscreteGenerator<Output = T>,
+    T: Merge<R> + Copy + Default,
+    R: Real,
+{
+    /// Splits the bezier curve at the given scalar into two bezier curves of the same degree.
+    ///
+    /// The first curve represents the part of this curve before the scalar,
+    /// the second curve the part after it. Both curves are reparameterized to the domain `[0.0,1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements(vec![0.0,5.0,3.0])
+    ///     .normalized::<f64>()
+    ///     .dynamic()
+    ///     .build()?;
+    /// let (left, right) = bez.split(0.5);
+    /// assert_f64_near!(left.gen(0.5), bez.gen(0.25));
+    /// assert_f64_near!(right.gen(0.5), bez.gen(0.75));
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn split(
+        &self,
+        scalar: R,
+    ) -> (
+        Bezier<R, Vec<T>, DynSpace<T>>,
+        Bezier<R, Vec<T>, DynSpace<T>>,
+    ) {
+        let len = self.elements.len();
+        let mut left = vec![T::default(); len];
+        let mut right = vec![T::default(); len];
+        bezier_split(
+            &mut self.workspace().as_mut()[..len],
+            &mut left,
+            &mut right,
+            scalar,
+        );
+        (
+            Bezier::new_unchecked(left, DynSpace::new(len)).with_evaluation(self.evaluation),
+            Bezier::new_unchecked(right, DynSpace::new(len)).with_evaluation(self.evaluation),
+        )
+    }
+}
+
+impl<R, E, T, const N: usize> Bezier<R, E, ConstSpace<T, N>>
+where
+    E: ConstDiscreteGenerator<N, Output = T>,
+    T: Merge<R> + Copy + Default,
+    R: Real + FromPrimitive,
+{
+    /// Elevates the degree of the bezier curve by one without changing its shape.
+    ///
+    /// The constant `M` has to be `N+1`, this is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements([0.0,6.0,3.0])
+    ///     .normalized::<f64>()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let elevated = bez.elevate::<4>();
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_f64_near!(elevated.gen(t), bez.gen(t), 8);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn elevate<const M: usize>(self) -> Bezier<R, [T; M], ConstSpace<T, M>> {
+        const {
+            assert!(
+                M == N + 1,
+                "the elevated curve needs exactly one element more"
+            )
+        };
+        let mut elevated = ConstSpace::<T, M>::new().workspace();
+        let elements: [T; N] = core::array::from_fn(|i| self.elements.gen(i));
+        bezier_elevate(&elements, &mut elevated);
+        Bezier::new_unchecked(elevated, ConstSpace::new()).with_evaluation(self.evaluation)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, E, T> Bezier<R, E, DynSpace<T>>
+where
+    E: DiscreteGenerator<Output = T>,
+    T: Merge<R> + Copy + Default,
+    R: Real + FromPrimitive,
+{
+    /// Elevates the degree of the bezier curve by one without changing its shape.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements(vec![0.0,6.0,3.0])
+    ///     .normalized::<f64>()
+    ///     .dynamic()
+    ///     .build()?;
+    /// let elevated = bez.clone().elevate();
+    /// for i in 0..=10 {
+    ///     let t = i as f64 / 10.0;
+    ///     assert_f64_near!(elevated.gen(t), bez.gen(t), 8);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn elevate(self) -> Bezier<R, Vec<T>, DynSpace<T>> {
+        let len = self.elements.len();
+        let elements: Vec<T> = self.elements.into_iter().collect();
+        let mut elevated = vec![T::default(); len + 1];
+        bezier_elevate(&elements, &mut elevated);
+        Bezier::new_unchecked(elevated, DynSpace::new(len + 1)).with_evaluation(self.evaluation)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, E, S> Bezier<R, E, S>
+where
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Returns the inputs within (0.0,1.0) at which the derivative of the curve is zero, sorted ascendingly.
+    ///
+    /// Together with the start and end of the curve, these are the only candidates for the minimum and maximum
+    /// of the curve. As such, the exact range of values of the curve can be calculated without sampling.
+    /// If the curve is constant, no inputs are returned.
+    ///
+    /// This method is only available for curves with scalar output. For vector outputs,
+    /// create a bezier curve for each component and use the union of their extrema,
+    /// which results in a tight axis-aligned bounding box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bez = Bezier::builder()
+    ///     .elements([0.0,4.0,0.0])
+    ///     .normalized::<f64>()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// let extrema = bez.extrema();
+    /// assert_eq!(extrema.len(), 1);
+    /// assert_f64_near!(extrema[0], 0.5);
+    /// assert_f64_near!(bez.gen(extrema[0]), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extrema(&self) -> Vec<R> {
+        // the elements of the derivative, up to a constant factor
+        let derivative: Vec<R> = (1..self.elements.len())
+            .map(|i| self.elements.gen(i) - self.elements.gen(i - 1))
+            .collect();
+        let mut roots = Vec::new();
+        bernstein_roots(&derivative, R::zero(), R::one(), 0, &mut roots);
+        roots
+    }
 }
 
 impl<R, E, S> Bezier<R, E, S>
@@ -303,6 +977,7 @@ where
         Ok(Bezier {
             space,
             elements,
+            evaluation: BezierEvaluation::DeCasteljau,
             _input: PhantomData,
         })
     }
@@ -319,15 +994,152 @@ where
         Bezier {
             space,
             elements,
+            evaluation: BezierEvaluation::DeCasteljau,
             _input: PhantomData,
         }
     }
+
+    /// Set the algorithm used to generate values of the curve.
+    ///
+    /// Other methods, like calculating tangents or splitting the curve, always use de Casteljau's algorithm.
+    pub fn with_evaluation(mut self, evaluation: BezierEvaluation) -> Self {
+        self.evaluation = evaluation;
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, T> Bezier<R, Vec<T>, DynSpace<T>>
+where
+    R: Real + FromPrimitive,
+    T: Mul<R, Output = T> + Sub<Output = T> + Copy + Default,
+{
+    /// Create a bezier curve which passes through the given points at the given parameters.
+    ///
+    /// The curve generates `points[i]` at the input `params[i]`. As the degree of the curve
+    /// is the number of points minus one, the curve is unique. The elements of the curve are
+    /// calculated by solving a linear system of the bernstein polynomials.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bezier::{Bezier, BezierError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BezierError> {
+    /// let bezier = Bezier::through_points([0.0,4.0,1.0], [0.0,0.25,1.0])?;
+    /// assert_f64_near!(bezier.gen(0.0), 0.0, 8);
+    /// assert_f64_near!(bezier.gen(0.25), 4.0, 8);
+    /// assert_f64_near!(bezier.gen(1.0), 1.0, 8);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`Empty`] if no points are given.
+    /// [`PointParamInequality`] if the number of points and parameters differ.
+    /// [`Singular`] if the linear system is singular, for example if parameters are not distinct.
+    ///
+    /// [`Empty`]: BezierError
+    /// [`PointParamInequality`]: BezierError
+    /// [`Singular`]: BezierError
+    pub fn through_points<P, Q>(points: P, params: Q) -> Result<Self, BezierError>
+    where
+        P: DiscreteGenerator<Output = T>,
+        Q: DiscreteGenerator<Output = R>,
+    {
+        let len = points.len();
+        if len == 0 {
+            return Err(Empty::new().into());
+        }
+        if len != params.len() {
+            return Err(PointParamInequality::new(len, params.len()).into());
+        }
+        let degree = len - 1;
+        // matrix of the bernstein polynomials evaluated at the parameters
+        let mut matrix: Vec<Vec<R>> = (0..len)
+            .map(|i| {
+                let t = params.gen(i);
+                let mut binomial = R::one();
+                (0..len)
+                    .map(|j| {
+                        if j > 0 {
+                            binomial = binomial * R::from_usize(degree - j + 1).unwrap()
+                                / R::from_usize(j).unwrap();
+                        }
+                        binomial * t.powi(j as i32) * (R::one() - t).powi((degree - j) as i32)
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut rhs: Vec<T> = points.into_iter().collect();
+        // pivots are compared relative to the biggest entry, such that the scale of the matrix does not matter
+        let scale = matrix
+            .iter()
+            .flatten()
+            .fold(R::zero(), |acc, value| acc.max(value.abs()));
+        let threshold = scale * R::epsilon() * R::from_usize(len).unwrap();
+        // gaussian elimination with partial pivoting
+        for k in 0..len {
+            let pivot = (k..len)
+                .max_by(|&a, &b| {
+                    matrix[a][k]
+                        .abs()
+                        .partial_cmp(&matrix[b][k].abs())
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                })
+                .unwrap();
+            if matrix[pivot][k].abs() <= threshold {
+                return Err(Singular::new().into());
+            }
+            matrix.swap(k, pivot);
+            rhs.swap(k, pivot);
+            let (upper, lower) = matrix.split_at_mut(k + 1);
+            let pivot_row = &upper[k];
+            for (r, row) in lower.iter_mut().enumerate() {
+                let factor = row[k] / pivot_row[k];
+                for (value, pivot_value) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                    *value = *value - *pivot_value * factor;
+                }
+                rhs[k + 1 + r] = rhs[k + 1 + r] - rhs[k] * factor;
+            }
+        }
+        // back substitution
+        let mut elements = vec![T::default(); len];
+        for k in (0..len).rev() {
+            let mut acc = rhs[k];
+            for j in k + 1..len {
+                acc = acc - elements[j] * matrix[k][j];
+            }
+            elements[k] = acc * matrix[k][k].recip();
+        }
+        Ok(Bezier {
+            elements,
+            space: DynSpace::new(len),
+            evaluation: BezierEvaluation::DeCasteljau,
+            _input: PhantomData,
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::ConstSpace;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hash_and_eq() {
+        use std::collections::HashSet;
+        // the input type does not need to be hashable itself
+        let first: Bezier<f64, _, _> = Bezier::new([1, 2, 3], ConstSpace::<_, 3>::new()).unwrap();
+        let second: Bezier<f64, _, _> = Bezier::new([1, 2, 4], ConstSpace::<_, 3>::new()).unwrap();
+        let set: HashSet<_> = [first, second, first].iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&first));
+        assert_ne!(first, second);
+    }
 
     #[test]
     fn extrapolation() {
@@ -387,6 +1199,179 @@ mod test {
         assert_f64_near!(res[2], 0.0);
         assert_f64_near!(res[3], 0.0);
         assert_f64_near!(res[4], 0.0);
+        // second deriative of a parabola and less deriatives than the degree
+        let bez = Bezier::builder()
+            .elements([0.0, 1.0, 0.0, 2.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let res = bez.gen_with_deriatives::<3>(0.0);
+        assert_f64_near!(res[0], 0.0);
+        assert_f64_near!(res[1], 3.0);
+        assert_f64_near!(res[2], -12.0);
+        let res = bez.gen_with_deriatives::<2>(0.5);
+        assert_eq!(res, bez.gen_with_tangent(0.5));
+    }
+
+    #[test]
+    fn frenet_frame() {
+        use crate::Point;
+        let bez = Bezier::builder()
+            .elements([
+                Point::new([0.0, 0.0, 0.0]),
+                Point::new([1.0, 1.0, 0.0]),
+                Point::new([2.0, 0.0, 0.0]),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        assert_f64_near!(bez.curvature(0.5), 1.0);
+        let [tangent, normal, binormal] = bez.frenet_frame(0.5);
+        assert_eq!(tangent.into_array(), [1.0, 0.0, 0.0]);
+        assert_eq!(normal.into_array(), [0.0, -1.0, 0.0]);
+        assert_eq!(binormal.into_array(), [0.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn derivative() {
+        let bez = Bezier::builder()
+            .elements([20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let derivative = bez.derivative();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_f64_near!(derivative.gen(t), bez.gen_with_tangent(t)[1], 32);
+        }
+        let second = derivative.derivative();
+        assert_f64_near!(second.gen(0.0), 1320.0);
+        assert_f64_near!(second.gen(0.5), -690.0);
+        assert_f64_near!(second.gen(1.0), -2700.0);
+        let constant = Bezier::new([5.0], ConstSpace::<_, 1>::new()).unwrap();
+        assert_f64_near!(constant.derivative().gen(0.3), 0.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split() {
+        let bez = Bezier::builder()
+            .elements([20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let dyn_bez = Bezier::builder()
+            .elements(vec![20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        for split in [0.0, 0.3, 0.5, 1.0] {
+            let (left, right) = bez.split(split);
+            let (dyn_left, dyn_right) = dyn_bez.split(split);
+            for i in 0..=10 {
+                let t = i as f64 / 10.0;
+                let expected_left = bez.gen(t * split);
+                let expected_right = bez.gen(split + t * (1.0 - split));
+                assert!((left.gen(t) - expected_left).abs() < 1e-9);
+                assert!((right.gen(t) - expected_right).abs() < 1e-9);
+                assert!((dyn_left.gen(t) - expected_left).abs() < 1e-9);
+                assert!((dyn_right.gen(t) - expected_right).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn elevate() {
+        let bez = Bezier::builder()
+            .elements([20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let dyn_bez = Bezier::builder()
+            .elements(vec![20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .dynamic()
+            .build()
+            .unwrap();
+        let elevated = bez.elevate::<5>();
+        let twice = elevated.elevate::<6>();
+        let dyn_elevated = dyn_bez.clone().elevate();
+        assert_eq!(dyn_elevated.elements.len(), 5);
+        for i in 0..=100 {
+            let t = i as f64 / 100.0;
+            let expected = bez.gen(t);
+            assert!((elevated.gen(t) - expected).abs() < 1e-9);
+            assert!((twice.gen(t) - expected).abs() < 1e-9);
+            assert!((dyn_elevated.gen(t) - expected).abs() < 1e-9);
+        }
+        let constant = Bezier::new([5.0], ConstSpace::<_, 1>::new()).unwrap();
+        assert_f64_near!(constant.elevate::<2>().gen(0.3), 5.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn extrema() {
+        let bez = Bezier::builder()
+            .elements([20.0, -100.0, 200.0, -50.0, 10.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let extrema = bez.extrema();
+        assert_eq!(extrema.len(), 3);
+        for &input in &extrema {
+            assert!(input > 0.0 && input < 1.0);
+            assert!(bez.gen_with_tangent(input)[1].abs() < 1e-9);
+        }
+        let (mut min, mut max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for &input in extrema.as_slice().iter().chain(&[0.0, 1.0]) {
+            min = min.min(bez.gen(input));
+            max = max.max(bez.gen(input));
+        }
+        let (mut sampled_min, mut sampled_max) = (f64::INFINITY, f64::NEG_INFINITY);
+        for value in bez.take(10001) {
+            sampled_min = sampled_min.min(value);
+            sampled_max = sampled_max.max(value);
+        }
+        assert!(min <= sampled_min && sampled_min - min < 1e-4);
+        assert!(max >= sampled_max && max - sampled_max < 1e-4);
+        // symmetric curve with a root exactly at a subdivision
+        let symmetric = Bezier::new([0.0, 1.0, -1.0, 0.0], ConstSpace::<_, 4>::new()).unwrap();
+        assert_eq!(symmetric.extrema().len(), 2);
+        let constant = Bezier::new([5.0, 5.0], ConstSpace::<_, 2>::new()).unwrap();
+        assert!(constant.extrema().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn through_points() {
+        let bez = Bezier::builder()
+            .elements([20.0, 0.0, 200.0, -50.0])
+            .normalized::<f64>()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let params = [0.0, 0.2, 0.7, 1.0];
+        let points: Vec<f64> = bez.sample(params).collect();
+        let interpolated = Bezier::through_points(points, params).unwrap();
+        for (element, expected) in interpolated.elements.iter().zip([20.0, 0.0, 200.0, -50.0]) {
+            assert!((element - expected).abs() < 1e-9);
+        }
+        assert!(Bezier::<f64, Vec<f64>, _>::through_points([1.0, 2.0], [0.5, 0.5]).is_err());
+        assert!(Bezier::<f64, Vec<f64>, _>::through_points([1.0, 2.0], [0.5]).is_err());
+        // nearly equal parameters far outside of [0.0,1.0] are detected independent of the scale
+        assert!(Bezier::<f64, Vec<f64>, _>::through_points(
+            [1.0, 2.0, 3.0],
+            [1e3, 1e3 + 1e-11, 2e3]
+        )
+        .is_err());
     }
 
     #[test]
@@ -405,4 +1390,110 @@ mod test {
             .unwrap();
         assert_eq!(bez, bez2);
     }
+
+    #[test]
+    fn weighted_tangent() {
+        use crate::Point;
+        // rational bezier describing a quarter of the unit circle
+        let circle = Bezier::builder()
+            .elements_with_weights([
+                (Point::new([1.0, 0.0]), 1.0),
+                (Point::new([1.0, 1.0]), core::f64::consts::FRAC_1_SQRT_2),
+                (Point::new([0.0, 1.0]), 1.0),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let delta = 1e-6;
+        for input in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            let [value, tangent] = circle.gen_with_tangent(input);
+            assert_f64_near!(value[0] * value[0] + value[1] * value[1], 1.0);
+            // the tangent of a circle is orthogonal to its radius
+            assert!((value[0] * tangent[0] + value[1] * tangent[1]).abs() < 1e-10);
+            let difference =
+                (circle.gen(input + delta) - circle.gen(input - delta)) / (2.0 * delta);
+            assert!((tangent[0] - difference[0]).abs() < 1e-6);
+            assert!((tangent[1] - difference[1]).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn horner() {
+        // pseudo random elements of a curve with degree 12
+        let mut seed = 7u32;
+        let mut max_errors = [0.0f64; 2];
+        for _ in 0..50 {
+            let elements: [f32; 13] = core::array::from_fn(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as f32 / 327.68 - 100.0
+            });
+            let reference = Bezier::builder()
+                .elements(elements.map(f64::from))
+                .normalized::<f64>()
+                .constant::<13>()
+                .build()
+                .unwrap();
+            let casteljau = Bezier::builder()
+                .elements(elements)
+                .normalized::<f32>()
+                .constant::<13>()
+                .build()
+                .unwrap();
+            let horner = casteljau.with_evaluation(BezierEvaluation::Horner);
+            for i in 0..=100 {
+                let input = i as f32 / 100.0;
+                let expected = reference.gen(f64::from(input));
+                for (max, bez) in max_errors.iter_mut().zip([casteljau, horner]) {
+                    *max = max.max((f64::from(bez.gen(input)) - expected).abs());
+                }
+            }
+        }
+        assert!(max_errors[1] < max_errors[0]);
+        assert!(max_errors[1] < 1e-4);
+        // outside of the domain de casteljau is used
+        let horner = Bezier::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .constant()
+            .evaluation(BezierEvaluation::Horner)
+            .build()
+            .unwrap();
+        assert_f64_near!(horner.gen(0.0), 20.0);
+        assert_f64_near!(horner.gen(1.0), 200.0);
+        assert_f64_near!(horner.gen(2.0), 2180.0);
+    }
+
+    #[test]
+    fn integral() {
+        let bez = Bezier::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        // the mean of the elements
+        assert_f64_near!(bez.integral(0.0, 1.0), 80.0);
+        for [start, end] in [[0.0, 0.3], [0.2, 0.9], [1.0, -0.5], [0.5, 1.5]] {
+            let approximation = bez.integrate(start, end, 1e-10, |x: f64| x.abs());
+            assert_f64_near!(bez.integral(start, end), approximation, 64);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        type Curve = Bezier<f64, [f64; 3], ConstSpace<f64, 3>>;
+        let bez: Curve = Bezier::new([20.0, 0.0, 200.0], ConstSpace::new())
+            .unwrap()
+            .with_evaluation(BezierEvaluation::Horner);
+        let json = serde_json::to_string(&bez).unwrap();
+        let restored: Curve = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, bez);
+        // curves serialized before the evaluation was configurable use de Casteljau's algorithm
+        let old = json.replace(",\"evaluation\":\"Horner\"", "");
+        assert_ne!(old, json);
+        let restored: Curve = serde_json::from_str(&old).unwrap();
+        assert_eq!(restored, bez.with_evaluation(BezierEvaluation::DeCasteljau));
+    }
 }
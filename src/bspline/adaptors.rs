@@ -1,8 +1,10 @@
 use crate::builder::TooFewElements;
 use crate::{DiscreteGenerator, Generator, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
 
 /// DiscreteGenerator Adaptor which repeats its first and last element `n` more times.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BorderBuffer<G> {
     inner: G,
@@ -89,7 +91,7 @@ where
 /// # Panics
 ///
 /// Using this Generator may cause a panic if the underlying generator has less than two elements.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BorderDeletion<G> {
     inner: G,
@@ -145,10 +147,73 @@ where
     }
 }
 
+/// DiscreteGenerator Adaptor which extends knots periodically.
+///
+/// The underlying knots define one period, from their first to their last knot.
+/// Before them `degree - 1` knots and after them `degree - 1` knots of the neighbouring periods are generated,
+/// such that the domain of a bspline of the given degree using these knots is exactly one period.
+///
+/// # Panics
+///
+/// Using this Generator may cause a panic if the underlying generator has less than two elements
+/// or if the degree is zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Periodic<G> {
+    inner: G,
+    degree: usize,
+}
+
+impl<G> Periodic<G>
+where
+    G: DiscreteGenerator,
+{
+    /// Creates a generator which extends the period given by the knots of the given generator,
+    /// such that they can be used for a closed bspline of the given degree.
+    pub fn new(inner: G, degree: usize) -> Self {
+        Periodic { inner, degree }
+    }
+}
+
+impl<G> Generator<usize> for Periodic<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Real + FromPrimitive,
+{
+    type Output = G::Output;
+    fn gen(&self, input: usize) -> Self::Output {
+        let intervals = self.inner.len() - 1;
+        let period = self.inner.gen(intervals) - self.inner.gen(0);
+        // shift the index by whole periods such that it does not get negative
+        let offset = (self.degree - 1).div_ceil(intervals);
+        let shifted = input + offset * intervals - (self.degree - 1);
+        let periods = G::Output::from_usize(shifted / intervals).unwrap()
+            - G::Output::from_usize(offset).unwrap();
+        self.inner.gen(shifted % intervals) + period * periods
+    }
+}
+
+impl<G> DiscreteGenerator for Periodic<G>
+where
+    G: DiscreteGenerator,
+    G::Output: Real + FromPrimitive,
+{
+    fn len(&self) -> usize {
+        self.inner.len() + 2 * self.degree - 2
+    }
+}
+
+impl<G> SortedGenerator for Periodic<G>
+where
+    G: SortedGenerator,
+    G::Output: Real + FromPrimitive,
+{
+}
+
 #[cfg(test)]
 mod test {
-    use super::{BorderBuffer, BorderDeletion};
-    use crate::{DiscreteGenerator, Equidistant, SortedGenerator};
+    use super::{BorderBuffer, BorderDeletion, Periodic};
+    use crate::{DiscreteGenerator, Equidistant, Generator, Sorted, SortedGenerator};
 
     #[test]
     fn borderdeletion() {
@@ -177,4 +242,21 @@ mod test {
         assert_eq!(buf.strict_upper_bound_clamped(0.8, 1, 5), 5);
         assert_eq!(buf.strict_upper_bound_clamped(0.45, 3, 9), 8);
     }
+
+    #[test]
+    fn periodic() {
+        let knots = Sorted::new([0.0, 1.0, 3.0, 4.0]).unwrap();
+        let periodic = Periodic::new(knots, 3);
+        assert_eq!(periodic.len(), 8);
+        let expected = [-3.0, -1.0, 0.0, 1.0, 3.0, 4.0, 5.0, 7.0];
+        for (index, value) in expected.iter().enumerate() {
+            assert_f64_near!(periodic.gen(index), value);
+        }
+        assert_eq!(periodic.strict_upper_bound(0.5), 3);
+        // degree bigger than a period
+        let periodic = Periodic::new(Sorted::new([0.0, 2.0]).unwrap(), 3);
+        assert_eq!(periodic.len(), 6);
+        assert_f64_near!(periodic.gen(0), -4.0);
+        assert_f64_near!(periodic.gen(5), 6.0);
+    }
 }
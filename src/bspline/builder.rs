@@ -2,17 +2,20 @@
 //!
 //! Each interpolation has it's own builder module, which accumalates all methods to create their interpolation.
 
-use super::adaptors::{BorderBuffer, BorderDeletion};
+use super::adaptors::{BorderBuffer, BorderDeletion, Periodic};
 use super::error::{
     BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, TooFewKnots,
 };
-use super::{BSpline, TooFewElements, TooSmallWorkspace};
-use crate::builder::{Type, Unknown, WithWeight, WithoutWeight};
+use super::{BSpline, Nurbs, TooFewElements, TooSmallWorkspace};
+use crate::builder::{validate_knots, Type, Unknown, WithWeight, WithoutWeight};
 use crate::weights::{Homogeneous, IntoWeight, Weighted, Weights};
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use crate::DynSpace;
+#[cfg(feature = "std")]
+use crate::PooledSpace;
 use crate::{
-    ConstSpace, DiscreteGenerator, Equidistant, Generator, Sorted, SortedGenerator, Space,
+    ConstSpace, DiscreteGenerator, Equidistant, Generator, InvalidEquidistant, Sorted,
+    SortedGenerator, Space, Wrap,
 };
 use core::marker::PhantomData;
 use core::ops::{Div, Mul};
@@ -22,7 +25,7 @@ use num_traits::FromPrimitive;
 use topology_traits::Merge;
 // use super::error::{LinearError, ToFewElements, KnotElementInequality};
 
-/// Marker struct to signify the building of a closed curve.
+/// Marker struct to signify the building of a clamped curve.
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Clamped;
@@ -34,9 +37,10 @@ pub struct Open;
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Legacy;
-// #[derive(Debug, Clone, Copy)]
-// #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-// pub struct Closed;
+/// Marker struct to signify the building of a closed curve.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Closed;
 
 /// Marker Struct which saves data for equidistant.
 ///
@@ -67,6 +71,15 @@ impl<R> UnknownDomain<R> {
     }
 }
 
+/// Marker Struct which saves the knots of a closed curve until its degree is known.
+///
+/// The knots of a closed curve get extended depending on the degree of the curve.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct UnknownDegree<K> {
+    knots: K,
+}
+
 /// Builder for bspline interpolation.
 ///
 /// This struct helps create bspline interpolations. The difference between this struct and [`BSplineBuilder`]
@@ -81,8 +94,8 @@ impl<R> UnknownDomain<R> {
 ///     Usually this is done by calling [`constant()`] or [`dynamic()`].
 ///     [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`closed()`] and [`legacy()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineDirector::build()
 /// [`BSplineBuilder`]: BSplineBuilder
@@ -95,6 +108,7 @@ impl<R> UnknownDomain<R> {
 /// [`workspace()`]: BSplineDirector::workspace()
 /// [`open()`]: BSplineDirector::open()
 /// [`clamped()`]: BSplineDirector::clamped()
+/// [`closed()`]: BSplineDirector::closed()
 /// [`legacy()`]: BSplineDirector::legacy()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -120,8 +134,8 @@ pub struct BSplineDirector<K, E, S, W, M> {
 ///     Usually this is done by calling [`constant()`] or [`dynamic()`].
 ///     [`workspace()`] is also posbbile for a custom workspace.
 ///
-/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`]
-/// and [`legacy()`], where [`open()`] is the default one.
+/// Furthermore one may want to use different modes, toggled by the methods [`open()`],[`clamped()`],
+/// [`closed()`] and [`legacy()`], where [`open()`] is the default one.
 ///
 /// [`build()`]: BSplineBuilder::build()
 /// [`builder()`]: super::BSpline::builder()
@@ -134,6 +148,7 @@ pub struct BSplineDirector<K, E, S, W, M> {
 /// [`workspace()`]: BSplineBuilder::workspace()
 /// [`open()`]: BSplineBuilder::open()
 /// [`clamped()`]: BSplineBuilder::clamped()
+/// [`closed()`]: BSplineBuilder::closed()
 /// [`legacy()`]: BSplineBuilder::legacy()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -210,12 +225,20 @@ impl<M> BSplineDirector<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a closed curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal and have a smooth transition.
+    /// To achieve this, the first `degree` elements are repeated after the last one
+    /// and the knots are extended periodically.
+    /// The knots given define one period, such there has to be exactly one more knot than elements.
+    pub fn closed(self) -> BSplineDirector<Unknown, Unknown, Unknown, Unknown, Closed> {
+        BSplineDirector {
+            knots: self.knots,
+            space: self.space,
+            elements: self.elements,
+            _phantoms: (self._phantoms.0, PhantomData),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     ///
@@ -307,12 +330,17 @@ impl<M> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, M> {
         }
     }
 
-    // /// Ensure the curve to be a loop, that is, its start and end point are equal and have a smooth transition.
-    // ///
-    // /// This method changes the underlying knot and element generator, by repeating some.
-    // pub fn loop(self) -> BSplineDirector<K,E, Unknown, W>{
-    //
-    // }
+    /// Change the mode to a closed curve.
+    ///
+    /// The curve becomes a loop, that is, its start and end point are equal and have a smooth transition.
+    /// To achieve this, the first `degree` elements are repeated after the last one
+    /// and the knots are extended periodically.
+    /// The knots given define one period, such there has to be exactly one more knot than elements.
+    pub fn closed(self) -> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, Closed> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.closed()),
+        }
+    }
 
     /// Set the elements of the bspline interpolation.
     pub fn elements<E>(self, elements: E) -> BSplineBuilder<Unknown, E, Unknown, WithoutWeight, M>
@@ -559,6 +587,123 @@ impl<E, W> BSplineBuilder<Unknown, E, Unknown, W, Legacy> {
     }
 }
 
+impl<E> BSplineDirector<Unknown, E, Unknown, WithoutWeight, Closed> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The knots define one period of the curve, such there has to be one knot more than elements.
+    ///
+    /// After this call, you also have to call [`degree()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotSorted`] if a knot is not greater or equal then the knot before him.
+    /// Returns [`TooFewKnots`] if not at least 2 knots are given.
+    /// Returns [`IncongruousElementsKnots`] if the number of knots is not the number of elements + 1.
+    ///
+    /// [`degree()`]: BSplineDirector::degree()
+    /// [`NotSorted`]: super::error::BSplineError
+    /// [`TooFewKnots`]: super::error::BSplineError
+    /// [`IncongruousElementsKnots`]: super::error::BSplineError
+    pub fn knots<K>(self, knots: K) -> Result<ClosedBSplineDirector<K, E>, BSplineError>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if knots.len() < 2 {
+            return Err(TooFewKnots::new(knots.len()).into());
+        }
+        if knots.len() != self.elements.len() + 1 {
+            return Err(IncongruousElementsKnots::closed(self.elements.len(), knots.len()).into());
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDegree {
+                knots: Sorted::new(knots)?,
+            },
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<E> BSplineBuilder<Unknown, E, Unknown, WithoutWeight, Closed> {
+    /// Set the knots of the interpolation.
+    ///
+    /// The knots define one period of the curve, such there has to be one knot more than elements.
+    ///
+    /// After this call, you also have to call [`degree()`].
+    ///
+    /// [`degree()`]: BSplineBuilder::degree()
+    pub fn knots<K>(self, knots: K) -> ClosedBSplineBuilder<K, E>
+    where
+        E: DiscreteGenerator,
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+}
+
+impl<K, E> ClosedBSplineDirector<K, E>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if given degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if degree is not less than the number of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> Result<
+        BSplineDirector<Periodic<Sorted<K>>, Wrap<E>, Unknown, WithoutWeight, Closed>,
+        BSplineError,
+    > {
+        if degree < 1 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::closed(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: Periodic::new(self.knots.knots, degree),
+            elements: Wrap::new(self.elements, degree),
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<K, E> ClosedBSplineBuilder<K, E>
+where
+    E: DiscreteGenerator,
+    K: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> BSplineBuilder<Periodic<Sorted<K>>, Wrap<E>, Unknown, WithoutWeight, Closed> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
 impl<E, W, M> BSplineDirector<Unknown, E, Unknown, W, M> {
     /// Build an interpolation with equidistant knots.
     ///
@@ -896,19 +1041,105 @@ where
     }
 }
 
+impl<R, E> BSplineDirector<Type<R>, E, Unknown, WithoutWeight, Closed>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDegree`] if given degree is 0.
+    /// Returns [`IncongruousElementsDegree`] if degree is not less than the number of elements.
+    ///
+    /// [`InvalidDegree`]: super::error::BSplineError
+    /// [`IncongruousElementsDegree`]: super::error::BSplineError
+    /// [`domain()`]: BSplineDirector::domain()
+    /// [`normalized()`]: BSplineDirector::normalized()
+    /// [`distance()`]: BSplineDirector::distance()
+    #[allow(clippy::type_complexity)]
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> Result<
+        BSplineDirector<UnknownDomain<R>, Wrap<E>, Unknown, WithoutWeight, Closed>,
+        BSplineError,
+    > {
+        if degree < 1 {
+            return Err(InvalidDegree::new(degree).into());
+        }
+        if self.elements.len() <= degree {
+            return Err(IncongruousElementsDegree::closed(self.elements.len(), degree).into());
+        }
+        Ok(BSplineDirector {
+            knots: UnknownDomain::new(self.elements.len() + 1, degree),
+            elements: Wrap::new(self.elements, degree),
+            space: self.space,
+            _phantoms: self._phantoms,
+        })
+    }
+}
+
+impl<R, E> BSplineBuilder<Type<R>, E, Unknown, WithoutWeight, Closed>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the degree of the curve.
+    ///
+    /// The degree of the curve has to be at least 1 and be less than the number of elements.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: BSplineBuilder::domain()
+    /// [`normalized()`]: BSplineBuilder::normalized()
+    /// [`distance()`]: BSplineBuilder::distance()
+    pub fn degree(
+        self,
+        degree: usize,
+    ) -> BSplineBuilder<UnknownDomain<R>, Wrap<E>, Unknown, WithoutWeight, Closed> {
+        BSplineBuilder {
+            inner: self.inner.and_then(|director| director.degree(degree)),
+        }
+    }
+}
+
 impl<R, E, W> BSplineDirector<UnknownDomain<R>, E, Unknown, W, Open>
 where
     E: DiscreteGenerator,
     R: Real + FromPrimitive,
 {
     /// Set the domain of the interpolation.
-    pub fn domain(self, start: R, end: R) -> BSplineDirector<Equidistant<R>, E, Unknown, W, Open> {
-        BSplineDirector {
-            knots: Equidistant::new(self.knots.len(), start, end),
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<BSplineDirector<Equidistant<R>, E, Unknown, W, Open>, InvalidEquidistant> {
+        Ok(BSplineDirector {
+            knots: Equidistant::try_new(self.knots.len(), start, end)?,
             elements: self.elements,
             space: self.space,
             _phantoms: self._phantoms,
-        }
+        })
     }
 
     /// Set the domain of the interpolation to be [0.0,1.0].
@@ -943,7 +1174,9 @@ where
     /// Set the domain of the interpolation.
     pub fn domain(self, start: R, end: R) -> BSplineBuilder<Equidistant<R>, E, Unknown, W, Open> {
         BSplineBuilder {
-            inner: self.inner.map(|director| director.domain(start, end)),
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
         }
     }
 
@@ -971,20 +1204,30 @@ where
     R: Real + FromPrimitive,
 {
     /// Set the domain of the interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    #[allow(clippy::type_complexity)]
     pub fn domain(
         self,
         start: R,
         end: R,
-    ) -> BSplineDirector<BorderBuffer<Equidistant<R>>, E, Unknown, W, Clamped> {
-        BSplineDirector {
+    ) -> Result<
+        BSplineDirector<BorderBuffer<Equidistant<R>>, E, Unknown, W, Clamped>,
+        InvalidEquidistant,
+    > {
+        Ok(BSplineDirector {
             knots: BorderBuffer::new(
-                Equidistant::new(self.knots.len(), start, end),
+                Equidistant::try_new(self.knots.len(), start, end)?,
                 self.knots.deg() - 1,
             ),
             elements: self.elements,
             space: self.space,
             _phantoms: self._phantoms,
-        }
+        })
     }
 
     /// Set the domain of the interpolation to be [0.0,1.0].
@@ -1031,7 +1274,9 @@ where
         end: R,
     ) -> BSplineBuilder<BorderBuffer<Equidistant<R>>, E, Unknown, W, Clamped> {
         BSplineBuilder {
-            inner: self.inner.map(|director| director.domain(start, end)),
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
         }
     }
 
@@ -1055,6 +1300,95 @@ where
     }
 }
 
+impl<R, E> BSplineDirector<UnknownDomain<R>, E, Unknown, WithoutWeight, Closed>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<
+        BSplineDirector<Equidistant<R>, E, Unknown, WithoutWeight, Closed>,
+        InvalidEquidistant,
+    > {
+        let period = Equidistant::try_new(self.knots.len(), start, end)?;
+        let step = period.gen(1) - period.gen(0);
+        Ok(self.distance(start, step))
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> BSplineDirector<Equidistant<R>, E, Unknown, WithoutWeight, Closed> {
+        let step = R::one() / R::from_usize(self.knots.len() - 1).unwrap();
+        self.distance(R::zero(), step)
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(
+        self,
+        start: R,
+        step: R,
+    ) -> BSplineDirector<Equidistant<R>, E, Unknown, WithoutWeight, Closed> {
+        // the knots of one period get extended by `degree - 1` knots on both sides
+        let extension = self.knots.deg() - 1;
+        BSplineDirector {
+            knots: Equidistant::step(
+                self.knots.len() + 2 * extension,
+                start - step * R::from_usize(extension).unwrap(),
+                step,
+            ),
+            elements: self.elements,
+            space: self.space,
+            _phantoms: self._phantoms,
+        }
+    }
+}
+
+impl<R, E> BSplineBuilder<UnknownDomain<R>, E, Unknown, WithoutWeight, Closed>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> BSplineBuilder<Equidistant<R>, E, Unknown, WithoutWeight, Closed> {
+        BSplineBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> BSplineBuilder<Equidistant<R>, E, Unknown, WithoutWeight, Closed> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(
+        self,
+        start: R,
+        step: R,
+    ) -> BSplineBuilder<Equidistant<R>, E, Unknown, WithoutWeight, Closed> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
 impl<K, E, W, M> BSplineDirector<K, E, Unknown, W, M>
 where
     E: DiscreteGenerator,
@@ -1069,7 +1403,7 @@ where
     /// If the degree of the bezier curve is known at compile-time, consider using [`constant()`] instead.
     ///
     /// [`constant()`]: BSplineDirector::constant()
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BSplineDirector<K, E, DynSpace<E::Output>, W, M> {
         BSplineDirector {
             space: DynSpace::new(self.knots.len() - self.elements.len() + 2),
@@ -1079,6 +1413,24 @@ where
         }
     }
 
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bspline curve at compile-time
+    /// and the memory is only allocated for the first generation of a value.
+    /// However, the curve is neither `Send` nor `Sync`. See [`PooledSpace`] for more information.
+    ///
+    /// [`PooledSpace`]: crate::PooledSpace
+    #[cfg(feature = "std")]
+    pub fn pooled(self) -> BSplineDirector<K, E, PooledSpace<E::Output>, W, M> {
+        BSplineDirector {
+            space: PooledSpace::new(self.knots.len() - self.elements.len() + 2),
+            knots: self.knots,
+            elements: self.elements,
+            _phantoms: self._phantoms,
+        }
+    }
+
     /// Set the workspace which the interpolation uses.
     ///
     /// Tells the builder the size of the workspace needed such that no memory allocations are necessary
@@ -1146,13 +1498,28 @@ where
     /// If the degree of the bezier curve is known at compile-time, consider using [`constant()`] instead.
     ///
     /// [`constant()`]: BSplineBuilder::constant()
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     pub fn dynamic(self) -> BSplineBuilder<K, E, DynSpace<E::Output>, W, M> {
         BSplineBuilder {
             inner: self.inner.map(|director| director.dynamic()),
         }
     }
 
+    /// Set the workspace which the interpolation uses.
+    ///
+    /// Tells the builder to use a pool of vectors as workspace,
+    /// such you don't need to know the degree of the bspline curve at compile-time
+    /// and the memory is only allocated for the first generation of a value.
+    /// However, the curve is neither `Send` nor `Sync`. See [`PooledSpace`] for more information.
+    ///
+    /// [`PooledSpace`]: crate::PooledSpace
+    #[cfg(feature = "std")]
+    pub fn pooled(self) -> BSplineBuilder<K, E, PooledSpace<E::Output>, W, M> {
+        BSplineBuilder {
+            inner: self.inner.map(|director| director.pooled()),
+        }
+    }
+
     /// Set the workspace which the interpolation uses.
     ///
     /// Tells the builder the size of the workspace needed such that no memory allocations are necessary
@@ -1192,7 +1559,7 @@ impl<K, E, S, M> BSplineDirector<K, E, S, WithoutWeight, M>
 where
     K: SortedGenerator,
     E: DiscreteGenerator,
-    E::Output: Merge<K::Output> + Copy,
+    E::Output: Merge<K::Output> + Clone,
     S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
@@ -1209,13 +1576,37 @@ where
     pub fn build(self) -> BSpline<K, E, S> {
         BSpline::new_unchecked(self.elements, self.knots, self.space)
     }
+
+    /// Build a bspline and check that its knots are numerically sane.
+    ///
+    /// See [`BSplineBuilder::build_validated()`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// [`NonFiniteKnot`] if any knot is NaN or infinite.
+    /// [`DegenerateDomain`] if the domain of the bspline collapsed to a single point.
+    ///
+    /// [`NonFiniteKnot`]: super::BSplineError
+    /// [`DegenerateDomain`]: super::BSplineError
+    pub fn build_validated(self) -> Result<BSpline<K, E, S>, BSplineError>
+    where
+        K::Output: Real,
+    {
+        let bspline = self.build();
+        let domain = [
+            bspline.knots.gen(bspline.degree - 1),
+            bspline.knots.gen(bspline.knots.len() - bspline.degree),
+        ];
+        validate_knots::<_, BSplineError>(&bspline.knots, domain)?;
+        Ok(bspline)
+    }
 }
 
 impl<K, E, S, M> BSplineBuilder<K, E, S, WithoutWeight, M>
 where
     K: SortedGenerator,
     E: DiscreteGenerator,
-    E::Output: Merge<K::Output> + Copy,
+    E::Output: Merge<K::Output> + Clone,
     S: Space<E::Output>,
 {
     /// Build a bezier interpolation.
@@ -1238,6 +1629,42 @@ where
             Ok(director) => Ok(director.build()),
         }
     }
+
+    /// Build a bspline and check that its knots are numerically sane.
+    ///
+    /// [`build()`] only checks the structure of the given data, like the number of elements and knots.
+    /// This method additionally checks that all knots are finite and that the domain does not
+    /// collapse to a single point, such that the bspline is usable.
+    /// This is useful if the knots come from an external source.
+    ///
+    /// # Errors
+    ///
+    /// All errors of [`build()`].
+    /// [`NonFiniteKnot`] if any knot is NaN or infinite.
+    /// [`DegenerateDomain`] if the domain of the bspline collapsed to a single point.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::bspline::{BSpline, BSplineError};
+    /// let result = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,1.0,3.0])
+    ///                 .knots([0.0,1.0,f64::INFINITY])
+    ///                 .constant::<3>()
+    ///                 .build_validated();
+    /// assert!(matches!(result, Err(BSplineError::NonFiniteKnot(_))));
+    /// ```
+    ///
+    /// [`build()`]: BSplineBuilder::build()
+    /// [`NonFiniteKnot`]: super::BSplineError
+    /// [`DegenerateDomain`]: super::BSplineError
+    pub fn build_validated(self) -> Result<BSpline<K, E, S>, BSplineError>
+    where
+        K::Output: Real,
+    {
+        self.inner.and_then(|director| director.build_validated())
+    }
 }
 
 impl<K, G, S, M> BSplineDirector<K, Weights<G>, S, WithWeight, M>
@@ -1261,7 +1688,7 @@ where
     /// [`TooFewElements`]: super::BSplineError
     /// [`InvalidDegree`]: super::BSplineError
     /// [`TooSmallWorkspace`]: super::BSplineError
-    pub fn build(self) -> WeightedBSpline<K, G, S> {
+    pub fn build(self) -> Nurbs<K, G, S> {
         Weighted::new(BSpline::new_unchecked(
             self.elements,
             self.knots,
@@ -1294,7 +1721,7 @@ where
     /// [`TooSmallWorkspace`]: super::BSplineError
     /// [`NotSorted`]: super::BSplineError
     /// [`knots()`]: BSplineBuilder::knots()
-    pub fn build(self) -> Result<WeightedBSpline<K, G, S>, BSplineError> {
+    pub fn build(self) -> Result<Nurbs<K, G, S>, BSplineError> {
         match self.inner {
             Err(err) => Err(err),
             Ok(director) => Ok(director.build()),
@@ -1302,8 +1729,6 @@ where
     }
 }
 
-/// Type alias for weighted bsplines.
-type WeightedBSpline<K, G, S> = Weighted<BSpline<K, Weights<G>, S>>;
 /// Type alias for ClampedBuilder
 type ClampedBSplineBuilder<K, E, W> =
     BSplineBuilder<BorderBuffer<Sorted<K>>, E, Unknown, W, Clamped>;
@@ -1316,6 +1741,12 @@ type LegacyBSplineBuilder<K, E, W> =
 ///Type alias for LegacyDirector
 type LegacyBSplineDirector<K, E, W> =
     BSplineDirector<BorderDeletion<Sorted<K>>, E, Unknown, W, Legacy>;
+/// Type alias for ClosedBuilder
+type ClosedBSplineBuilder<K, E> =
+    BSplineBuilder<UnknownDegree<Sorted<K>>, E, Unknown, WithoutWeight, Closed>;
+/// Type alias for ClosedDirector
+type ClosedBSplineDirector<K, E> =
+    BSplineDirector<UnknownDegree<Sorted<K>>, E, Unknown, WithoutWeight, Closed>;
 
 #[cfg(test)]
 mod test {
@@ -1340,6 +1771,31 @@ mod test {
             .is_err());
     }
 
+    #[test]
+    fn validated_creations() {
+        use crate::bspline::BSplineError;
+        // open bspline of degree 2, whose domain only consists of the knots at index 1 and 2
+        let collapsed = BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .constant::<3>()
+            .build_validated();
+        assert!(matches!(collapsed, Err(BSplineError::DegenerateDomain(_))));
+        // knots outside of the domain have to be finite as well
+        let infinite = BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0])
+            .knots([0.0, 1.0, 2.0, f64::INFINITY])
+            .constant::<3>()
+            .build_validated();
+        assert!(matches!(infinite, Err(BSplineError::NonFiniteKnot(_))));
+        assert!(BSplineBuilder::new()
+            .elements([1.0, 3.0, 7.0])
+            .knots([0.0, 0.0, 1.0, 1.0])
+            .constant::<3>()
+            .build_validated()
+            .is_ok());
+    }
+
     #[test]
     fn mode_equality() {
         let elements = [1.0, 3.0, 7.0];
@@ -1451,6 +1907,7 @@ mod test {
             .degree(2)
             .unwrap()
             .domain(0.0, 1.0)
+            .unwrap()
             .constant::<2>()
             .is_err());
 
@@ -1527,6 +1984,7 @@ mod test {
             .degree(2)
             .unwrap()
             .domain(0.0, 1.0)
+            .unwrap()
             .constant::<2>()
             .is_err());
 
@@ -1,8 +1,10 @@
 //! All error types for bspline interpolation.
 #[allow(unreachable_pub)]
-pub use crate::builder::{TooFewElements, TooFewKnots, TooSmallWorkspace};
+pub use crate::builder::{
+    DegenerateDomain, NonFiniteKnot, TooFewElements, TooFewKnots, TooSmallWorkspace,
+};
 #[allow(unreachable_pub)]
-pub use crate::NotSorted;
+pub use crate::{InvalidEquidistant, NotSorted};
 
 use core::{convert::From, fmt};
 #[cfg(feature = "std")]
@@ -24,10 +26,18 @@ pub enum BSplineError {
     InvalidDegree(InvalidDegree),
     /// Error returned if knots are not sorted.
     NotSorted(NotSorted),
+    /// Error returned if equidistant knots could not be created.
+    InvalidEquidistant(InvalidEquidistant),
     /// Error returned when elements and knots are not matching together.
     IncongruousElementsKnots(IncongruousElementsKnots),
     /// Error returned when elements and degree are ill-matched.
     IncongruousElementsDegree(IncongruousElementsDegree),
+    /// Error returned when adjacent bezier segments do not share their endpoints.
+    DisconnectedSegments(DisconnectedSegments),
+    /// Error returned if a knot is NaN or infinite.
+    NonFiniteKnot(NonFiniteKnot),
+    /// Error returned if the domain collapsed to a single point.
+    DegenerateDomain(DegenerateDomain),
 }
 
 impl fmt::Display for BSplineError {
@@ -35,11 +45,15 @@ impl fmt::Display for BSplineError {
         match self {
             BSplineError::TooFewElements(inner) => inner.fmt(f),
             BSplineError::NotSorted(inner) => inner.fmt(f),
+            BSplineError::InvalidEquidistant(inner) => inner.fmt(f),
             BSplineError::InvalidDegree(inner) => inner.fmt(f),
             BSplineError::TooSmallWorkspace(inner) => inner.fmt(f),
             BSplineError::TooFewKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsKnots(inner) => inner.fmt(f),
             BSplineError::IncongruousElementsDegree(inner) => inner.fmt(f),
+            BSplineError::DisconnectedSegments(inner) => inner.fmt(f),
+            BSplineError::NonFiniteKnot(inner) => inner.fmt(f),
+            BSplineError::DegenerateDomain(inner) => inner.fmt(f),
         }
     }
 }
@@ -68,6 +82,12 @@ impl From<NotSorted> for BSplineError {
     }
 }
 
+impl From<InvalidEquidistant> for BSplineError {
+    fn from(from: InvalidEquidistant) -> Self {
+        BSplineError::InvalidEquidistant(from)
+    }
+}
+
 impl From<TooSmallWorkspace> for BSplineError {
     fn from(from: TooSmallWorkspace) -> Self {
         BSplineError::TooSmallWorkspace(from)
@@ -86,6 +106,24 @@ impl From<IncongruousElementsDegree> for BSplineError {
     }
 }
 
+impl From<DisconnectedSegments> for BSplineError {
+    fn from(from: DisconnectedSegments) -> Self {
+        BSplineError::DisconnectedSegments(from)
+    }
+}
+
+impl From<NonFiniteKnot> for BSplineError {
+    fn from(from: NonFiniteKnot) -> Self {
+        BSplineError::NonFiniteKnot(from)
+    }
+}
+
+impl From<DegenerateDomain> for BSplineError {
+    fn from(from: DegenerateDomain) -> Self {
+        BSplineError::DegenerateDomain(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for BSplineError {}
 
@@ -124,6 +162,7 @@ enum BSplineBuildMode {
     Open,
     Clamped,
     Legacy,
+    Closed,
 }
 
 /// Error returned when the number of elements and knots are ill-matched.
@@ -160,6 +199,14 @@ impl IncongruousElementsKnots {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a closed bspline
+    pub fn closed(elements: usize, knots: usize) -> Self {
+        IncongruousElementsKnots {
+            elements,
+            knots,
+            mode: BSplineBuildMode::Closed,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsKnots {
@@ -189,6 +236,14 @@ impl fmt::Display for IncongruousElementsKnots {
                     self.elements, self.knots
                 )
             }
+            BSplineBuildMode::Closed => {
+                write!(
+                    f,
+                    "Found {} elements and {} knots, but for a closed bspline 
+                    there must be exactly one knot more than there are elements.",
+                    self.elements, self.knots
+                )
+            }
         }
     }
 }
@@ -230,6 +285,14 @@ impl IncongruousElementsDegree {
             mode: BSplineBuildMode::Legacy,
         }
     }
+    /// Invalid values for a closed bspline
+    pub fn closed(elements: usize, degree: usize) -> Self {
+        IncongruousElementsDegree {
+            elements,
+            degree,
+            mode: BSplineBuildMode::Closed,
+        }
+    }
 }
 
 impl fmt::Display for IncongruousElementsDegree {
@@ -260,9 +323,46 @@ impl fmt::Display for IncongruousElementsDegree {
                     self.elements, self.degree
                 )
             }
+            BSplineBuildMode::Closed => {
+                write!(
+                    f,
+                    "Found {} elements and a degree of {}, but for a closed bspline 
+                    there must be more elements than the degree of the spline.",
+                    self.elements, self.degree
+                )
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl Error for IncongruousElementsDegree {}
+
+/// Error returned when the end of a bezier segment is not the start of the next segment.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DisconnectedSegments {
+    /// The index of the segment whose end does not match the start of the next segment.
+    index: usize,
+}
+
+impl DisconnectedSegments {
+    /// Create a new error with the index of the segment which is not connected to its successor.
+    pub fn new(index: usize) -> Self {
+        DisconnectedSegments { index }
+    }
+}
+
+impl fmt::Display for DisconnectedSegments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The last element of segment {} is not equal to the first element of segment {}.",
+            self.index,
+            self.index + 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DisconnectedSegments {}
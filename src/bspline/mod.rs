@@ -36,29 +36,41 @@
 mod adaptors;
 mod builder;
 mod error;
+mod sampler;
 
-pub use adaptors::{BorderBuffer, BorderDeletion};
+pub use adaptors::{BorderBuffer, BorderDeletion, Periodic};
 pub use builder::{BSplineBuilder, BSplineDirector};
 pub use error::{
-    BSplineError, IncongruousElementsDegree, IncongruousElementsKnots, InvalidDegree, NotSorted,
+    BSplineError, DegenerateDomain, DisconnectedSegments, IncongruousElementsDegree,
+    IncongruousElementsKnots, InvalidDegree, InvalidEquidistant, NonFiniteKnot, NotSorted,
     TooFewElements, TooSmallWorkspace,
 };
+pub use sampler::MonotonicSampler;
 
 use crate::builder::Unknown;
+use crate::easing::Easing;
+use crate::geometry::{self, Cross, EuclideanVector};
+use crate::weights::{Weighted, Weights};
+#[cfg(feature = "alloc")]
+use crate::{ConstSpace, Sorted};
 use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator, Space};
 use builder::Open;
+use num_traits::cast::FromPrimitive;
 use num_traits::real::Real;
 use topology_traits::Merge;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::ops::{Mul, Sub};
 
 /// BSpline curve.
 ///
 /// See [bspline module] for more information.
 ///
 /// [bspline module]: self
-#[derive(Debug, Copy, Clone, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BSpline<K, E, S> {
     elements: E,
     knots: K,
@@ -66,6 +78,30 @@ pub struct BSpline<K, E, S> {
     degree: usize,
 }
 
+/// The degree of a deserialized bspline is not trusted but recalculated from its elements and knots.
+/// As such, deserialization fails with the same errors as [`BSpline::new()`].
+#[cfg(feature = "serde")]
+impl<'de, K, E, S> serde::Deserialize<'de> for BSpline<K, E, S>
+where
+    K: SortedGenerator + serde::Deserialize<'de>,
+    E: DiscreteGenerator + serde::Deserialize<'de>,
+    S: Space<E::Output> + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct Fields<K, E, S> {
+            elements: E,
+            knots: K,
+            space: S,
+        }
+        let fields = Fields::deserialize(deserializer)?;
+        BSpline::new(fields.elements, fields.knots, fields.space).map_err(serde::de::Error::custom)
+    }
+}
+
 impl BSpline<Unknown, Unknown, Unknown> {
     /// Get a builder for bsplines.
     ///
@@ -117,6 +153,131 @@ impl BSpline<Unknown, Unknown, Unknown> {
     }
 }
 
+/// Non-uniform rational bspline curve.
+///
+/// A NURBS is a bspline with weighted elements, whose generated values are projected back,
+/// that is, divided by their interpolated weight.
+/// It is created by [`Nurbs::builder()`] or by giving [`elements_with_weights()`] to the builder of a bspline.
+///
+/// [`elements_with_weights()`]: BSplineBuilder::elements_with_weights()
+pub type Nurbs<K, G, S> = Weighted<BSpline<K, Weights<G>, S>>;
+
+impl Nurbs<Unknown, Unknown, Unknown> {
+    /// Get a builder for non-uniform rational bsplines.
+    ///
+    /// The builder works like the [builder of bsplines], however the elements have to be given
+    /// together with their weights by [`elements_with_weights()`].
+    ///
+    /// # Examples
+    ///
+    /// A quarter of the unit circle.
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{Nurbs, BSplineError}, Generator, Curve, Point};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let weight = core::f64::consts::FRAC_1_SQRT_2;
+    /// let arc = Nurbs::builder()
+    ///     .clamped()
+    ///     .elements_with_weights([
+    ///         (Point::new([1.0,0.0]), 1.0),
+    ///         (Point::new([1.0,1.0]), weight),
+    ///         (Point::new([0.0,1.0]), 1.0),
+    ///     ])
+    ///     .equidistant::<f64>()
+    ///     .degree(2)
+    ///     .normalized()
+    ///     .constant::<3>()
+    ///     .build()?;
+    /// for point in arc.take(10) {
+    ///     assert_f64_near!(point[0].hypot(point[1]), 1.0);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [builder of bsplines]: BSpline::builder()
+    /// [`elements_with_weights()`]: BSplineBuilder::elements_with_weights()
+    pub fn builder() -> BSplineBuilder<Unknown, Unknown, Unknown, Unknown, Open> {
+        BSplineBuilder::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<R, T, const D: usize> BSpline<Sorted<Vec<R>>, Vec<T>, ConstSpace<T, D>>
+where
+    R: Real + FromPrimitive,
+    T: PartialEq + Copy,
+{
+    /// Creates a bspline which consists of the given bezier segments.
+    ///
+    /// Each segment is given by its `D` elements, such the degree of the bspline is `D-1`.
+    /// The last element of each segment has to be equal to the first element of the next segment.
+    /// The resulting curve is continuous but may have corners where two segments meet.
+    /// The domain of the curve is `[0.0, #segments]`, where the input `i + t` with `t` in `[0.0,1.0]`
+    /// corresponds to the input `t` of the segment `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Curve, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let spline = BSpline::from_bezier_segments([[0.0, 1.0, 3.0], [3.0, 2.0, 0.0]])?;
+    /// assert_eq!(spline.domain(), [0.0, 2.0]);
+    /// assert_f64_near!(spline.gen(0.5), 1.25);
+    /// assert_f64_near!(spline.gen(1.0), 3.0);
+    /// assert_f64_near!(spline.gen(1.5), 1.75);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`TooFewElements`] if no segment is given.
+    /// [`InvalidDegree`] if the segments consist of less than two elements.
+    /// [`DisconnectedSegments`] if the end of a segment is not the start of the next segment.
+    ///
+    /// [`TooFewElements`]: BSplineError
+    /// [`InvalidDegree`]: BSplineError
+    /// [`DisconnectedSegments`]: BSplineError
+    pub fn from_bezier_segments<G>(segments: G) -> Result<Self, BSplineError>
+    where
+        G: DiscreteGenerator<Output = [T; D]>,
+    {
+        if segments.is_empty() {
+            return Err(TooFewElements::new(0).into());
+        }
+        if D < 2 {
+            return Err(InvalidDegree::new(D.saturating_sub(1)).into());
+        }
+        let degree = D - 1;
+        let mut elements = Vec::with_capacity(segments.len() * degree + 1);
+        elements.push(segments.gen(0)[0]);
+        for i in 0..segments.len() {
+            let segment = segments.gen(i);
+            if segment[0] != elements[elements.len() - 1] {
+                return Err(DisconnectedSegments::new(i - 1).into());
+            }
+            elements.extend_from_slice(&segment[1..]);
+        }
+        // every knot has multiplicity `degree`, such that each segment is its own span
+        let knots = (0..=segments.len())
+            .flat_map(|i| core::iter::repeat_n(R::from_usize(i).unwrap(), degree))
+            .collect();
+        Ok(BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: ConstSpace::new(),
+            degree,
+        })
+    }
+}
+
 impl<K, E, S> BSpline<K, E, S>
 where
     E: DiscreteGenerator,
@@ -152,10 +313,51 @@ where
         let index = self
             .knots
             .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        self.gen_at(scalar, index)
+    }
+}
 
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Copy,
+    R: Real + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns the index of the span of the given input, searching forward from the given index.
+    ///
+    /// If the input is in front of the span of the given index, binary search is used instead.
+    fn span_from(&self, scalar: R, index: usize) -> usize {
+        let lower_cut = self.degree;
+        let upper_cut = self.knots.len() - self.degree;
+        let mut index = index.clamp(lower_cut, upper_cut);
+        if index > lower_cut && scalar < self.knots.gen(index - 1) {
+            return self
+                .knots
+                .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        }
+        while index < upper_cut && scalar >= self.knots.gen(index) {
+            index += 1;
+        }
+        index
+    }
+
+    /// Generate the value at the given input, which lies in the span of the given index.
+    fn gen_at(&self, scalar: R, index: usize) -> E::Output {
+        self.gen_at_with_steps(scalar, index, |_, _| {})
+    }
+
+    /// Generate the value at the given input, which lies in the span of the given index,
+    /// and call `step` with every level of de Boor's algorithm.
+    fn gen_at_with_steps<F>(&self, scalar: R, index: usize, mut step: F) -> E::Output
+    where
+        F: FnMut(usize, &[E::Output]),
+    {
         //copy elements into workspace
         let mut workspace = self.workspace(index);
         let elements = workspace.as_mut();
+        step(0, &elements[..=self.degree]);
 
         for r in 1..=self.degree {
             for j in 0..=(self.degree - r) {
@@ -164,9 +366,97 @@ where
                     / (self.knots.gen(i + self.degree - r) - self.knots.gen(i - 1));
                 elements[j] = elements[j].merge(elements[j + 1], factor);
             }
+            step(r, &elements[..=(self.degree - r)]);
         }
         elements[0]
     }
+
+    /// Generate the value of the curve at the given input and inspect the steps of de Boor's algorithm.
+    ///
+    /// The closure `step` is called once for every level of de Boor's algorithm, together with the index of the level.
+    /// Level `0` consists of the `degree + 1` elements which influence the value at the given input.
+    /// Level `r` consists of `degree + 1 - r` points, where the `j`-th point is the merge of the points
+    /// `j` and `j + 1` of level `r - 1`. The last level, `degree`, consists only of the generated value,
+    /// which is also returned.
+    ///
+    /// This is useful to visualize the construction of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,4.0,8.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let mut levels = Vec::new();
+    /// let value = bspline.gen_steps(0.5, |level, points| levels.push((level, points.to_vec())));
+    /// assert_f64_near!(value, 4.0);
+    /// assert_eq!(levels, vec![
+    ///     (0, vec![0.0,4.0,8.0]),
+    ///     (1, vec![2.0,6.0]),
+    ///     (2, vec![4.0]),
+    /// ]);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gen_steps<F>(&self, scalar: R, step: F) -> E::Output
+    where
+        F: FnMut(usize, &[E::Output]),
+    {
+        let lower_cut = self.degree;
+        let upper_cut = self.knots.len() - self.degree;
+        let index = self
+            .knots
+            .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        self.gen_at_with_steps(scalar, index, step)
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Clone + Default,
+    R: Real + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value of the curve at the given input by cloning elements.
+    ///
+    /// In contrast to [`gen()`], the elements only have to be clonable instead of copyable.
+    /// This allows the use of elements which own heap memory.
+    /// Collections of such elements can be wrapped in [`Cloned`] to be used as elements.
+    ///
+    /// [`gen()`]: Generator::gen()
+    /// [`Cloned`]: crate::Cloned
+    pub fn gen_cloned(&self, scalar: R) -> E::Output {
+        let lower_cut = self.degree;
+        let upper_cut = self.knots.len() - self.degree;
+        let index = self
+            .knots
+            .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+
+        let mut workspace = self.workspace(index);
+        let elements = workspace.as_mut();
+
+        for r in 1..=self.degree {
+            for j in 0..=(self.degree - r) {
+                let i = j + r + index - self.degree;
+                let factor = (scalar - self.knots.gen(i - 1))
+                    / (self.knots.gen(i + self.degree - r) - self.knots.gen(i - 1));
+                // the element at j is overwritten, such we can take it instead of cloning it
+                let element = core::mem::take(&mut elements[j]);
+                elements[j] = element.merge(elements[j + 1].clone(), factor);
+            }
+        }
+        core::mem::take(&mut elements[0])
+    }
 }
 
 impl<K, E, S, R> Curve<R> for BSpline<K, E, S>
@@ -185,6 +475,295 @@ where
     }
 }
 
+impl<K, E, S, R> Easing<R> for BSpline<K, E, S>
+where
+    Self: Generator<R, Output = R>,
+{
+    fn ease(&self, factor: R) -> R {
+        self.gen(factor)
+    }
+}
+
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value and its deriatives, the order hereby is from value, then first deriative, then second and so on.
+    ///
+    /// Deriatives of higher order than the degree of the curve are always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,1.0,3.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let [value, tangent, curvature, zero] = bspline.gen_with_deriatives::<4>(0.5);
+    /// assert_f64_near!(value, bspline.gen(0.5));
+    /// assert_f64_near!(tangent, 3.0);
+    /// assert_f64_near!(curvature, 2.0);
+    /// assert_f64_near!(zero, 0.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gen_with_deriatives<const N: usize>(&self, scalar: R) -> [E::Output; N] {
+        let lower_cut = self.degree;
+        let upper_cut = self.knots.len() - self.degree;
+        let index = self
+            .knots
+            .strict_upper_bound_clamped(scalar, lower_cut, upper_cut);
+        // knots of the curve with the first and last knot (which do not have any influence) added
+        let knot = |i: usize| self.knots.gen(i - 1);
+
+        // the workspace holds the control points of the current deriative which are relevant to the scalar
+        let mut workspace = self.workspace(index);
+        let control = workspace.as_mut();
+        let mut result = [control[0] * R::zero(); N];
+        for (k, res) in result.iter_mut().enumerate().take(self.degree + 1) {
+            let deg = self.degree - k;
+            // de Boor's algorithm for the deriative of degree `deg`
+            let mut buffer = self.space.workspace();
+            let elements = buffer.as_mut();
+            elements[..=deg].copy_from_slice(&control[..=deg]);
+            for r in 1..=deg {
+                for j in 0..=(deg - r) {
+                    let i = j + r + index - deg;
+                    let factor = (scalar - knot(i)) / (knot(i + deg + 1 - r) - knot(i));
+                    elements[j] = elements[j].merge(elements[j + 1], factor);
+                }
+            }
+            *res = elements[0];
+            // calculate the control points of the next deriative
+            if deg > 0 {
+                let factor = R::from_usize(deg).unwrap();
+                for j in 0..deg {
+                    let i = j + index + k + 1 - self.degree;
+                    control[j] =
+                        (control[j + 1] - control[j]) * (factor / (knot(i + deg) - knot(i)));
+                }
+            }
+        }
+        result
+    }
+
+    /// Calculate the curvature of the curve at the given input.
+    ///
+    /// See [`geometry::curvature()`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Point};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([Point::new([-1.0,1.0]),Point::new([0.0,-1.0]),Point::new([1.0,1.0])])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// // the curve is a parabola y = x^2 with curvature 2 at its vertex
+    /// assert_f64_near!(bspline.curvature(0.5), 2.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`geometry::curvature()`]: crate::geometry::curvature()
+    pub fn curvature(&self, scalar: R) -> R
+    where
+        E::Output: EuclideanVector<R>,
+    {
+        let [_, first, second] = self.gen_with_deriatives::<3>(scalar);
+        geometry::curvature(first, second)
+    }
+
+    /// Calculate the Frenet frame of the curve at the given input.
+    ///
+    /// Returns the unit tangent, the unit normal and the unit binormal, in this order.
+    /// See [`geometry::frenet_frame()`] for more information.
+    ///
+    /// [`geometry::frenet_frame()`]: crate::geometry::frenet_frame()
+    pub fn frenet_frame(&self, scalar: R) -> [E::Output; 3]
+    where
+        E::Output: EuclideanVector<R> + Cross,
+    {
+        let [_, first, second] = self.gen_with_deriatives::<3>(scalar);
+        geometry::frenet_frame(first, second)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output> + Clone,
+    E::Output: Mul<R, Output = E::Output> + Sub<Output = E::Output> + Copy,
+    R: Real + FromPrimitive,
+    K: SortedGenerator<Output = R> + Clone,
+{
+    /// Returns the deriative of the curve as a bspline.
+    ///
+    /// The deriative of a bspline with degree `p` is a bspline with degree `p-1`, whose elements
+    /// are the scaled differences of consecutive elements and whose knots are the knots of the curve
+    /// without the first and last knot.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,1.0,3.0])
+    ///                 .equidistant::<f64>()
+    ///                 .degree(2)
+    ///                 .normalized()
+    ///                 .constant::<3>()
+    ///                 .build()?;
+    /// let velocity = bspline.derivative()?;
+    /// assert_eq!(velocity.domain(), bspline.domain());
+    /// assert_f64_near!(velocity.gen(0.5), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidDegree`] if the curve has degree 1, as its deriative is not continuous.
+    ///
+    /// [`InvalidDegree`]: BSplineError
+    #[allow(clippy::type_complexity)]
+    pub fn derivative(
+        &self,
+    ) -> Result<BSpline<BorderDeletion<K>, Vec<E::Output>, S>, BSplineError> {
+        if self.degree < 2 {
+            return Err(InvalidDegree::new(self.degree - 1).into());
+        }
+        let factor = R::from_usize(self.degree).unwrap();
+        let elements = (0..self.elements.len() - 1)
+            .map(|i| {
+                let difference = self.elements.gen(i + 1) - self.elements.gen(i);
+                let length = self.knots.gen(i + self.degree) - self.knots.gen(i);
+                // elements which belong to a span of length zero have no influence
+                if length == R::zero() {
+                    difference * R::zero()
+                } else {
+                    difference * (factor / length)
+                }
+            })
+            .collect();
+        Ok(BSpline {
+            elements,
+            // we always have at least degree + 1 > 2 knots
+            knots: BorderDeletion::new(self.knots.clone()).unwrap(),
+            space: self.space.clone(),
+            degree: self.degree - 1,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, E, S, R> BSpline<K, E, S>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output> + Clone,
+    E::Output: Merge<R> + Copy,
+    R: Real,
+    K: SortedGenerator<Output = R>,
+{
+    /// Returns the same curve with the given knot inserted.
+    ///
+    /// The shape of the curve does not change, however the curve has one more element
+    /// which allows for more local control. The new elements are calculated with Boehm's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), BSplineError> {
+    /// let bspline = BSpline::builder()
+    ///                 .clamped()
+    ///                 .elements([0.0,5.0,3.0,10.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .constant::<4>()
+    ///                 .build()?;
+    /// let inserted = bspline.insert_knot(0.5);
+    /// assert_eq!(inserted.domain(), bspline.domain());
+    /// for (value, result) in inserted.take(11).zip(bspline.take(11)) {
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the knot lies outside of the domain of the curve or is equal to its end,
+    /// as the last span of the domain would become empty.
+    pub fn insert_knot(&self, knot: R) -> BSpline<Sorted<Vec<R>>, Vec<E::Output>, S> {
+        let degree = self.degree;
+        let lower_cut = degree;
+        let upper_cut = self.knots.len() - degree;
+        assert!(
+            knot >= self.knots.gen(lower_cut - 1) && knot < self.knots.gen(upper_cut),
+            "the knot to insert lies outside of the domain of the curve"
+        );
+        let index = self
+            .knots
+            .strict_upper_bound_clamped(knot, lower_cut, upper_cut);
+        let elements = (0..=self.elements.len())
+            .map(|i| {
+                if i + degree <= index {
+                    self.elements.gen(i)
+                } else if i > index {
+                    self.elements.gen(i - 1)
+                } else {
+                    let start = self.knots.gen(i - 1);
+                    let length = self.knots.gen(i + degree - 1) - start;
+                    let factor = if length == R::zero() {
+                        R::zero()
+                    } else {
+                        (knot - start) / length
+                    };
+                    self.elements.gen(i - 1).merge(self.elements.gen(i), factor)
+                }
+            })
+            .collect();
+        let mut knots: Vec<R> = (0..self.knots.len()).map(|i| self.knots.gen(i)).collect();
+        knots.insert(index, knot);
+        BSpline {
+            elements,
+            knots: Sorted::new_unchecked(knots),
+            space: self.space.clone(),
+            degree,
+        }
+    }
+}
+
 impl<K, E, S> BSpline<K, E, S>
 where
     E: DiscreteGenerator,
@@ -397,6 +976,57 @@ mod test {
         }
     }
 
+    #[test]
+    fn deriatives() {
+        let points = [0.0, 0.0, 0.0, 6.0, 0.0, 0.0, 0.0];
+        let knots = [-2.0, -2.0, -2.0, -1.0, 0.0, 1.0, 2.0, 2.0, 2.0];
+        let spline = BSpline::builder()
+            .elements(points)
+            .knots(knots)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let res = spline.gen_with_deriatives::<5>(0.0);
+        assert_f64_near!(res[0], 4.0);
+        assert_f64_near!(res[1], 0.0);
+        assert_f64_near!(res[2], -12.0);
+        assert_f64_near!(res[4], 0.0);
+        // compare with finite differences
+        let h = 1e-4;
+        for x in [-1.7, -1.0, -0.6, 0.3, 0.5, 1.5] {
+            let res = spline.gen_with_deriatives::<4>(x);
+            assert_f64_near!(res[0], spline.gen(x), 32);
+            let tangent = (spline.gen(x + h) - spline.gen(x - h)) / (2.0 * h);
+            assert!((res[1] - tangent).abs() < 1e-6);
+            let [_, before, ..] = spline.gen_with_deriatives::<3>(x - h);
+            let [_, after, ..] = spline.gen_with_deriatives::<3>(x + h);
+            assert!((res[2] - (after - before) / (2.0 * h)).abs() < 1e-3);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bezier_segments() {
+        let segments = [[0.0, 2.0, 4.0, 2.0], [2.0, 0.0, -2.0, 6.0]];
+        let spline = BSpline::<Sorted<Vec<f64>>, _, _>::from_bezier_segments(segments).unwrap();
+        assert_eq!(spline.domain(), [0.0, 2.0]);
+        assert_f64_near!(spline.gen(0.0), 0.0);
+        // cubic bezier at 0.5 is (p0 + 3p1 + 3p2 + p3) / 8
+        assert_f64_near!(spline.gen(0.5), 2.5);
+        assert_f64_near!(spline.gen(1.0), 2.0);
+        assert_f64_near!(spline.gen(1.5), 0.25);
+        assert_f64_near!(spline.gen(2.0), 6.0);
+        assert!(
+            BSpline::<Sorted<Vec<f64>>, _, _>::from_bezier_segments([[0.0, 1.0], [2.0, 3.0]])
+                .is_err()
+        );
+        assert!(
+            BSpline::<Sorted<Vec<f64>>, Vec<f64>, _>::from_bezier_segments::<[[f64; 2]; 0]>([])
+                .is_err()
+        );
+        assert!(BSpline::<Sorted<Vec<f64>>, _, _>::from_bezier_segments([[1.0]]).is_err());
+    }
+
     #[test]
     fn partial_eq() {
         let spline = BSpline::builder()
@@ -413,4 +1043,320 @@ mod test {
             .unwrap();
         assert_eq!(spline, spline2);
     }
+
+    #[cfg(feature = "alloc")]
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Heap(Vec<f64>);
+
+    #[cfg(feature = "alloc")]
+    impl Merge<f64> for Heap {
+        fn merge(self, other: Self, factor: f64) -> Self {
+            Heap(
+                self.0
+                    .iter()
+                    .zip(other.0.iter())
+                    .map(|(a, b)| a.merge(b, factor))
+                    .collect(),
+            )
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn cloned() {
+        use crate::Cloned;
+        use alloc::vec;
+        let elements = [0.0, 1.0, 3.0, 2.0];
+        let heap = Cloned::new(elements.map(|x| Heap(vec![x, -x])).to_vec());
+        let copied = BSpline::builder()
+            .clamped()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .unwrap();
+        let cloned = BSpline::builder()
+            .clamped()
+            .elements(heap)
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .dynamic()
+            .build()
+            .unwrap();
+        for input in [-0.5, 0.0, 0.3, 0.5, 0.9, 1.0, 1.5] {
+            let value = cloned.gen_cloned(input);
+            assert_f64_near!(value.0[0], copied.gen(input));
+            assert_f64_near!(value.0[1], -copied.gen(input));
+            assert_f64_near!(copied.gen_cloned(input), copied.gen(input));
+        }
+    }
+    #[test]
+    fn gen_steps() {
+        let bspline = BSpline::builder()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 0.5, 1.0, 3.0, 3.5, 4.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        for input in [1.0, 2.0, 3.0, 3.2] {
+            let mut sizes = [0; 4];
+            let value = bspline.gen_steps(input, |level, points| {
+                sizes[level] = points.len();
+                if level == 3 {
+                    assert_eq!(points[0], bspline.gen(input));
+                }
+            });
+            assert_eq!(value, bspline.gen(input));
+            assert_eq!(sizes, [4, 3, 2, 1]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn insert_knot() {
+        fn check<C, D>(curve: &C, inserted: &D)
+        where
+            C: Curve<f64, Output = f64>,
+            D: Curve<f64, Output = f64>,
+        {
+            assert_eq!(curve.domain(), inserted.domain());
+            let [start, end] = curve.domain();
+            for i in 0..=40 {
+                let input = start + (end - start) * i as f64 / 40.0;
+                assert!((curve.gen(input) - inserted.gen(input)).abs() < 1e-12);
+            }
+        }
+        let clamped = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 1.0, 3.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        for knot in [0.0, 0.5, 1.0, 2.0, 2.9] {
+            let inserted = clamped.insert_knot(knot);
+            assert_eq!(inserted.elements.len(), 6);
+            check(&clamped, &inserted);
+        }
+        let open = BSpline::builder()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 0.5, 1.0, 3.0, 3.5, 4.0, 6.0])
+            .dynamic()
+            .build()
+            .unwrap();
+        let twice = open.insert_knot(2.0).insert_knot(2.0);
+        check(&open, &twice);
+        // the curve interpolates an element after inserting a knot degree times
+        let thrice = twice.insert_knot(2.0);
+        assert!(thrice.elements.contains(&open.gen(2.0)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn insert_knot_outside_domain() {
+        let bspline = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0])
+            .knots([0.0, 1.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        bspline.insert_knot(1.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn derivative() {
+        fn check<C, D>(curve: &C, derivative: &D)
+        where
+            C: Curve<f64, Output = f64>,
+            D: Generator<f64, Output = f64>,
+        {
+            let delta = 1e-6;
+            let [start, end] = curve.domain();
+            for i in 1..20 {
+                let input = start + (end - start) * i as f64 / 20.0;
+                let difference =
+                    (curve.gen(input + delta) - curve.gen(input - delta)) / (2.0 * delta);
+                assert!((derivative.gen(input) - difference).abs() < 1e-4);
+            }
+        }
+        let clamped = BSpline::builder()
+            .clamped()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 1.0, 3.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        check(&clamped, &clamped.derivative().unwrap());
+        let open = BSpline::builder()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 0.5, 1.0, 3.0, 3.5, 4.0, 6.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        check(&open, &open.derivative().unwrap());
+        let legacy = BSpline::builder()
+            .legacy()
+            .elements([0.0, 5.0, 3.0, 10.0, 7.0])
+            .knots([0.0, 0.5, 1.0, 2.0, 3.0, 3.5, 4.0, 6.0, 7.0])
+            .constant::<4>()
+            .build()
+            .unwrap();
+        let velocity = legacy.derivative().unwrap();
+        check(&legacy, &velocity);
+        check(&velocity, &velocity.derivative().unwrap());
+        assert!(velocity.derivative().unwrap().derivative().is_err());
+    }
+
+    #[test]
+    fn too_few_knots() {
+        // the degree is not less than the number of elements, such no valid knots exist
+        assert!(BSpline::builder()
+            .clamped()
+            .elements([0.0, 1.0, 2.0])
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .is_err());
+        // a single interval is valid
+        let bspline = BSpline::builder()
+            .clamped()
+            .elements([0.0, 1.0, 2.0, 3.0])
+            .equidistant::<f64>()
+            .degree(3)
+            .normalized()
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_f64_near!(bspline.gen(0.5), 1.5);
+    }
+
+    #[test]
+    fn nurbs_circle() {
+        use crate::Point;
+        // use the methods of `Real`, such that the test also runs without std
+        let weight = core::f64::consts::FRAC_1_SQRT_2;
+        let nurbs = Nurbs::builder()
+            .elements_with_weights([
+                (Point::new([1.0, 0.0]), 1.0),
+                (Point::new([1.0, 1.0]), weight),
+                (Point::new([0.0, 1.0]), 1.0),
+                (Point::new([-1.0, 1.0]), weight),
+                (Point::new([-1.0, 0.0]), 1.0),
+                (Point::new([-1.0, -1.0]), weight),
+                (Point::new([0.0, -1.0]), 1.0),
+                (Point::new([1.0, -1.0]), weight),
+                (Point::new([1.0, 0.0]), 1.0),
+            ])
+            .knots([0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0])
+            .constant::<3>()
+            .build()
+            .unwrap();
+        assert_eq!(nurbs.domain(), [0.0, 4.0]);
+        for point in nurbs.take(33) {
+            assert!((Real::hypot(point[0], point[1]) - 1.0).abs() < 1e-12);
+        }
+        // at the knots the curve coincides with the parametrization by angle
+        for input in [0.0f64, 1.0, 2.0, 3.0, 4.0] {
+            let point = nurbs.gen(input);
+            let angle = input * core::f64::consts::FRAC_PI_2;
+            assert!((point[0] - Real::cos(angle)).abs() < 1e-12);
+            assert!((point[1] - Real::sin(angle)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn closed() {
+        let elements = [0.0, 1.0, 3.0, -2.0, 4.0];
+        let equidistant = BSpline::builder()
+            .closed()
+            .elements(elements)
+            .equidistant::<f64>()
+            .degree(3)
+            .domain(1.0, 6.0)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_eq!(equidistant.domain(), [1.0, 6.0]);
+        let knots = BSpline::builder()
+            .closed()
+            .elements(elements)
+            .knots([1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+            .degree(3)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        assert_eq!(knots.domain(), [1.0, 6.0]);
+        for input in [1.0, 1.5, 3.2, 4.0, 5.9] {
+            assert_f64_near!(equidistant.gen(input), knots.gen(input), 16);
+        }
+        // value and first derivative are continuous across the seam
+        let uneven = BSpline::builder()
+            .closed()
+            .elements(elements)
+            .knots([0.0, 0.5, 2.0, 2.5, 3.0, 4.5])
+            .degree(3)
+            .constant::<4>()
+            .build()
+            .unwrap();
+        for spline in [&knots, &uneven] {
+            let [start, end] = spline.domain();
+            let [first, first_tangent] = spline.gen_with_deriatives::<2>(start);
+            let [last, last_tangent] = spline.gen_with_deriatives::<2>(end);
+            assert_f64_near!(first, last, 16);
+            assert!((first_tangent - last_tangent).abs() < 1e-10);
+        }
+        // a uniform closed spline does not interpolate its elements
+        assert_f64_near!(equidistant.gen(1.0), (0.0 + 4.0 * 1.0 + 3.0) / 6.0);
+        assert!(BSpline::builder()
+            .closed()
+            .elements(elements)
+            .knots([0.0, 1.0, 2.0])
+            .degree(3)
+            .constant::<4>()
+            .build()
+            .is_err());
+        assert!(BSpline::builder()
+            .closed()
+            .elements([0.0, 1.0])
+            .equidistant::<f64>()
+            .degree(2)
+            .normalized()
+            .constant::<3>()
+            .build()
+            .is_err());
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn serde() {
+        use crate::DynSpace;
+        use alloc::vec;
+        type Spline = BSpline<Sorted<Vec<f64>>, Vec<f64>, DynSpace<f64>>;
+        let spline = BSpline::builder()
+            .elements(vec![0.0, 0.0, 1.0, 0.0, 0.0])
+            .knots(vec![0.0, 0.0, 1.0, 2.0, 3.0, 3.0])
+            .dynamic()
+            .build()
+            .unwrap();
+        let json = serde_json::to_string(&spline).unwrap();
+        let restored: Spline = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, spline);
+        // the degree is recalculated
+        let tampered = json.replace("\"degree\":2", "\"degree\":7");
+        assert_ne!(tampered, json);
+        let restored: Spline = serde_json::from_str(&tampered).unwrap();
+        assert_eq!(restored, spline);
+        // too few knots for the elements given
+        let invalid = json.replace("[0.0,0.0,1.0,2.0,3.0,3.0]", "[0.0,1.0,2.0]");
+        assert_ne!(invalid, json);
+        assert!(serde_json::from_str::<Spline>(&invalid).is_err());
+    }
 }
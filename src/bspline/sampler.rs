@@ -0,0 +1,96 @@
+use super::BSpline;
+use crate::{DiscreteGenerator, SortedGenerator, Space};
+use core::fmt::Debug;
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+/// Cursor to efficiently sample a curve at increasing inputs.
+///
+/// The sampler remembers the span of the last input. For the next input, the search for its span
+/// starts at the remembered one and walks forward, which is amortized O(1) if the inputs are increasing.
+/// If an input lies in front of the remembered span, binary search is used as usual.
+/// This is useful for playback, where the input is the time of a playhead.
+///
+/// This struct is created by the [`monotonic_sampler()`] method of bsplines.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{bspline::{BSpline, BSplineError}, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), BSplineError> {
+/// let bspline = BSpline::builder()
+///                 .elements([0.0,1.0,4.0,2.0,3.0])
+///                 .knots([0.0,0.0,0.5,2.0,3.0,3.0])
+///                 .constant::<3>()
+///                 .build()?;
+/// let mut sampler = bspline.monotonic_sampler();
+/// for input in [0.0,0.2,0.7,1.5,2.9,3.0] {
+///     assert_f64_near!(sampler.gen(input), bspline.gen(input));
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`monotonic_sampler()`]: BSpline::monotonic_sampler()
+#[derive(Debug, Clone)]
+pub struct MonotonicSampler<'a, C> {
+    curve: &'a C,
+    index: usize,
+}
+
+impl<K, E, S> BSpline<K, E, S> {
+    /// Create a sampler which is efficient for increasing inputs.
+    ///
+    /// See [`MonotonicSampler`] for more information.
+    pub fn monotonic_sampler(&self) -> MonotonicSampler<'_, Self> {
+        MonotonicSampler {
+            curve: self,
+            index: self.degree,
+        }
+    }
+}
+
+impl<K, E, S, R> MonotonicSampler<'_, BSpline<K, E, S>>
+where
+    E: DiscreteGenerator,
+    S: Space<E::Output>,
+    E::Output: Merge<R> + Copy,
+    R: Real + Debug,
+    K: SortedGenerator<Output = R>,
+{
+    /// Generate the value of the curve at the given input.
+    ///
+    /// The result is the same as calling [`gen()`] on the curve itself.
+    ///
+    /// [`gen()`]: crate::Generator::gen()
+    pub fn gen(&mut self, scalar: R) -> E::Output {
+        self.index = self.curve.span_from(scalar, self.index);
+        self.curve.gen_at(scalar, self.index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ConstSpace, Generator, Sorted};
+
+    #[test]
+    fn monotonic_sampler() {
+        let bspline = BSpline::new(
+            [0.0, 4.0, -2.0, 3.0, 1.0, 5.0, 0.0],
+            Sorted::new([0.0, 0.0, 0.3, 0.4, 1.5, 2.0, 2.5, 3.0, 3.0]).unwrap(),
+            ConstSpace::<_, 4>::new(),
+        )
+        .unwrap();
+        let mut sampler = bspline.monotonic_sampler();
+        // increasing, repeated, backward and out of domain inputs
+        for input in [
+            -1.0, 0.0, 0.1, 0.3, 0.3, 0.35, 2.9, 0.2, 1.0, 2.0, 3.0, 4.0, -0.5,
+        ] {
+            assert_f64_near!(sampler.gen(input), bspline.gen(input));
+        }
+    }
+}
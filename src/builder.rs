@@ -1,13 +1,51 @@
 //! Module with structures, utilities and errors used in many builders
 
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "natural_cubic"
+))]
 use core::fmt;
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "catmull_rom",
+    feature = "hermite",
+    feature = "step"
+))]
 use core::marker::PhantomData;
 
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    all(feature = "alloc", feature = "catmull_rom")
+))]
+use crate::DiscreteGenerator;
+#[cfg(all(feature = "alloc", any(feature = "linear", feature = "catmull_rom")))]
+use crate::Sorted;
+#[cfg(all(feature = "alloc", any(feature = "linear", feature = "catmull_rom")))]
+use alloc::vec::Vec;
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    all(feature = "alloc", feature = "catmull_rom")
+))]
+use num_traits::real::Real;
+
 #[cfg(all(
     feature = "std",
-    any(feature = "linear", feature = "bezier", feature = "bspline")
+    any(
+        feature = "linear",
+        feature = "bezier",
+        feature = "bspline",
+        feature = "hermite",
+        feature = "step",
+        feature = "natural_cubic"
+    )
 ))]
 use std::error::Error;
 
@@ -24,7 +62,15 @@ pub struct WithoutWeight;
 pub struct WithWeight;
 
 /// Struct indicator to mark information not yet given.
-#[cfg(any(feature = "linear", feature = "bezier", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bezier",
+    feature = "bspline",
+    feature = "catmull_rom",
+    feature = "hermite",
+    feature = "step",
+    feature = "natural_cubic"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Unknown;
@@ -59,18 +105,54 @@ impl<R> InputDomain<R> {
 }
 
 /// Struct indicator to mark which type to use
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmull_rom",
+    feature = "hermite",
+    feature = "step"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Type<R = f64>(PhantomData<*const R>);
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "catmull_rom",
+    feature = "hermite",
+    feature = "step"
+))]
 impl<R> Type<R> {
     pub const fn new() -> Self {
         Type(PhantomData)
     }
 }
 
+/// Calculate knots from the distances between consecutive elements.
+///
+/// The first knot is zero and the distance between two knots is given by
+/// `distance(first, second).abs().powf(alpha)`.
+#[cfg(all(feature = "alloc", any(feature = "linear", feature = "catmull_rom")))]
+pub(crate) fn chordal_knots<E, R, F>(elements: &E, alpha: R, distance: F) -> Sorted<Vec<R>>
+where
+    E: DiscreteGenerator,
+    F: Fn(E::Output, E::Output) -> R,
+    R: Real,
+{
+    let mut knots = Vec::with_capacity(elements.len());
+    let mut knot = R::zero();
+    knots.push(knot);
+    for i in 1..elements.len() {
+        knot = knot
+            + distance(elements.gen(i - 1), elements.gen(i))
+                .abs()
+                .powf(alpha);
+        knots.push(knot);
+    }
+    Sorted::new_unchecked(knots)
+}
+
 /// Error returned if if there are no elements.
 #[cfg(feature = "bezier")]
 #[derive(Debug, Copy, Clone)]
@@ -96,7 +178,13 @@ impl fmt::Display for Empty {
 impl Error for Empty {}
 
 /// Error returned if the elements are to few for the specific interpolation.
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "natural_cubic"
+))]
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct TooFewElements {
@@ -104,17 +192,38 @@ pub struct TooFewElements {
     found: usize,
 }
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "natural_cubic"
+))]
 impl fmt::Display for TooFewElements {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "To few elements given for the interpolation. {} elements were given, but at least 2 are necessary.", self.found)
     }
 }
 
-#[cfg(all(feature = "std", any(feature = "linear", feature = "bspline")))]
+#[cfg(all(
+    feature = "std",
+    any(
+        feature = "linear",
+        feature = "bspline",
+        feature = "hermite",
+        feature = "step",
+        feature = "natural_cubic"
+    )
+))]
 impl Error for TooFewElements {}
 
-#[cfg(any(feature = "linear", feature = "bspline"))]
+#[cfg(any(
+    feature = "linear",
+    feature = "bspline",
+    feature = "hermite",
+    feature = "step",
+    feature = "natural_cubic"
+))]
 impl TooFewElements {
     /// Create a new error and document the number of elements found.
     pub fn new(found: usize) -> Self {
@@ -179,3 +288,83 @@ impl TooSmallWorkspace {
         TooSmallWorkspace { found, necessary }
     }
 }
+
+/// Error returned if a knot is not finite, that is NaN or infinite.
+#[cfg(any(feature = "linear", feature = "bspline"))]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NonFiniteKnot {
+    /// The index of the knot.
+    index: usize,
+}
+
+#[cfg(any(feature = "linear", feature = "bspline"))]
+impl fmt::Display for NonFiniteKnot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The knot at index {} is not finite. All knots have to be finite numbers.",
+            self.index
+        )
+    }
+}
+
+#[cfg(all(feature = "std", any(feature = "linear", feature = "bspline")))]
+impl Error for NonFiniteKnot {}
+
+#[cfg(any(feature = "linear", feature = "bspline"))]
+impl NonFiniteKnot {
+    /// Create a new error with the index of the knot which is not finite.
+    pub fn new(index: usize) -> Self {
+        NonFiniteKnot { index }
+    }
+}
+
+/// Error returned if the domain of the interpolation collapsed to a single point.
+///
+/// This happens if all knots inside the domain are equal, for example if equidistant knots
+/// were created with the same start and end.
+#[cfg(any(feature = "linear", feature = "bspline"))]
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct DegenerateDomain {}
+
+#[cfg(any(feature = "linear", feature = "bspline"))]
+impl fmt::Display for DegenerateDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The domain of the interpolation is degenerate. Its start has to be smaller than its end."
+        )
+    }
+}
+
+#[cfg(all(feature = "std", any(feature = "linear", feature = "bspline")))]
+impl Error for DegenerateDomain {}
+
+#[cfg(any(feature = "linear", feature = "bspline"))]
+impl DegenerateDomain {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        DegenerateDomain {}
+    }
+}
+
+/// Check that all knots are finite and that the domain does not collapse to a single point.
+#[cfg(any(feature = "linear", feature = "bspline"))]
+pub(crate) fn validate_knots<K, Err>(knots: &K, domain: [K::Output; 2]) -> Result<(), Err>
+where
+    K: DiscreteGenerator,
+    K::Output: Real,
+    Err: From<NonFiniteKnot> + From<DegenerateDomain>,
+{
+    // only finite numbers are at most as big as the maximal value, NaN is not comparable at all
+    let finite = |knot: K::Output| knot.abs() <= <K::Output as Real>::max_value();
+    if let Some(index) = knots.iter().position(|knot| !finite(knot)) {
+        return Err(NonFiniteKnot::new(index).into());
+    }
+    if domain[0] >= domain[1] {
+        return Err(DegenerateDomain::new().into());
+    }
+    Ok(())
+}
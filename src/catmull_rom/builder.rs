@@ -0,0 +1,549 @@
+//! Builder module for catmull-rom splines.
+
+use super::error::CatmullRomError;
+use super::{CatmullRom, KnotElementInequality, TooFewElements};
+#[cfg(feature = "alloc")]
+use crate::builder::chordal_knots;
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, InvalidEquidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::{FromPrimitive, Zero};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// The parameterization of a catmull-rom spline, which defines its knots.
+///
+/// The knots are calculated such that the distance between two knots is given by
+/// `distance.powf(alpha)`, where `distance` is the distance between the corresponding elements.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Parameterization {
+    /// All knots are equidistant (`alpha = 0`). This may result in cusps and self-intersections.
+    Uniform,
+    /// The distance between knots is the square root of the distance between elements (`alpha = 0.5`).
+    /// This guarantees that there are no cusps and self-intersections within a segment.
+    Centripetal,
+    /// The distance between knots is the distance between elements (`alpha = 1`).
+    Chordal,
+}
+
+impl Parameterization {
+    /// Returns the exponent used for the distances between elements.
+    pub fn alpha<R>(&self) -> R
+    where
+        R: Real + FromPrimitive,
+    {
+        match self {
+            Parameterization::Uniform => R::zero(),
+            Parameterization::Centripetal => R::from_f64(0.5).unwrap(),
+            Parameterization::Chordal => R::one(),
+        }
+    }
+}
+
+/// Builder for catmull-rom splines.
+///
+/// This struct helps create catmull-rom splines. The difference between this struct and [`CatmullRomBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. Either by giving them directly with [`knots()`],
+///   by using equidistant knots with [`equidistant()`] or by deriving them from the elements
+///   with [`parameterization()`].
+///
+/// [`CatmullRomBuilder`]: CatmullRomBuilder
+/// [`build()`]: CatmullRomDirector::build()
+/// [`elements()`]: CatmullRomDirector::elements()
+/// [`knots()`]: CatmullRomDirector::knots()
+/// [`equidistant()`]: CatmullRomDirector::equidistant()
+/// [`parameterization()`]: CatmullRomDirector::parameterization()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRomDirector<K, E, R> {
+    knots: K,
+    elements: E,
+    tension: R,
+}
+
+/// Builder for catmull-rom splines.
+///
+/// This struct helps create catmull-rom splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The knots the spline uses. Either by giving them directly with [`knots()`],
+///   by using equidistant knots with [`equidistant()`] or by deriving them from the elements
+///   with [`parameterization()`].
+///
+/// ```rust
+/// # use enterpolation::{catmull_rom::{CatmullRom, CatmullRomError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), CatmullRomError> {
+/// let catmull_rom = CatmullRom::builder()
+///                 .elements([0.0,1.0,3.0,2.0])
+///                 .knots([0.0,1.0,2.0,3.0])
+///                 .tension(0.5)
+///                 .build()?;
+/// assert_f64_near!(catmull_rom.gen(2.0), 3.0);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: CatmullRomBuilder::build()
+/// [`builder()`]: super::CatmullRom::builder()
+/// [`elements()`]: CatmullRomBuilder::elements()
+/// [`knots()`]: CatmullRomBuilder::knots()
+/// [`equidistant()`]: CatmullRomBuilder::equidistant()
+/// [`parameterization()`]: CatmullRomBuilder::parameterization()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRomBuilder<K, E, R> {
+    inner: Result<CatmullRomDirector<K, E, R>, CatmullRomError>,
+}
+
+impl Default for CatmullRomDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CatmullRomDirector::new()
+    }
+}
+
+impl Default for CatmullRomBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        CatmullRomBuilder::new()
+    }
+}
+
+impl CatmullRomDirector<Unknown, Unknown, Unknown> {
+    /// Create a new catmull-rom spline builder.
+    pub const fn new() -> Self {
+        CatmullRomDirector {
+            knots: Unknown,
+            elements: Unknown,
+            tension: Unknown,
+        }
+    }
+
+    /// Set the elements of the catmull-rom spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 4 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::CatmullRomError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<CatmullRomDirector<Unknown, E, Unknown>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 4 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(CatmullRomDirector {
+            knots: self.knots,
+            elements,
+            tension: self.tension,
+        })
+    }
+}
+
+impl CatmullRomBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new catmull-rom spline builder.
+    pub const fn new() -> Self {
+        CatmullRomBuilder {
+            inner: Ok(CatmullRomDirector::new()),
+        }
+    }
+
+    /// Set the elements of the catmull-rom spline.
+    pub fn elements<E>(self, elements: E) -> CatmullRomBuilder<Unknown, E, Unknown>
+    where
+        E: DiscreteGenerator,
+    {
+        CatmullRomBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> CatmullRomDirector<Unknown, E, Unknown>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::CatmullRomError
+    /// [`NotSorted`]: super::error::CatmullRomError
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> Result<CatmullRomDirector<Sorted<K>, E, K::Output>, CatmullRomError>
+    where
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(CatmullRomDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            tension: Zero::zero(),
+        })
+    }
+
+    /// Build a spline with equidistant knots, which results in an uniform catmull-rom spline.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: CatmullRomDirector::domain()
+    /// [`normalized()`]: CatmullRomDirector::normalized()
+    /// [`distance()`]: CatmullRomDirector::distance()
+    pub fn equidistant<R>(self) -> CatmullRomDirector<Type<R>, E, R>
+    where
+        R: Real,
+    {
+        CatmullRomDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            tension: R::zero(),
+        }
+    }
+
+    /// Derive the knots from the distances between the elements.
+    ///
+    /// The first knot is zero and the distance between two knots is given by
+    /// `distance(first, second).powf(alpha)`, where `alpha` is defined by the given [`Parameterization`].
+    /// The closure should return the distance between the given elements,
+    /// for example the absolute value of their difference or the euclidean distance.
+    ///
+    /// Consecutive elements should not be equal, as otherwise the spline may generate NaN.
+    ///
+    /// [`Parameterization`]: Parameterization
+    #[cfg(feature = "alloc")]
+    pub fn parameterization<R, F>(
+        self,
+        parameterization: Parameterization,
+        distance: F,
+    ) -> CatmullRomDirector<Sorted<Vec<R>>, E, R>
+    where
+        F: Fn(E::Output, E::Output) -> R,
+        E::Output: Copy,
+        R: Real + FromPrimitive,
+    {
+        CatmullRomDirector {
+            knots: chordal_knots(&self.elements, parameterization.alpha::<R>(), distance),
+            elements: self.elements,
+            tension: R::zero(),
+        }
+    }
+}
+
+impl<E> CatmullRomBuilder<Unknown, E, Unknown>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    pub fn knots<K>(self, knots: K) -> CatmullRomBuilder<Sorted<K>, E, K::Output>
+    where
+        K: DiscreteGenerator,
+        K::Output: Real,
+    {
+        CatmullRomBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build a spline with equidistant knots, which results in an uniform catmull-rom spline.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: CatmullRomBuilder::domain()
+    /// [`normalized()`]: CatmullRomBuilder::normalized()
+    /// [`distance()`]: CatmullRomBuilder::distance()
+    pub fn equidistant<R>(self) -> CatmullRomBuilder<Type<R>, E, R>
+    where
+        R: Real,
+    {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+
+    /// Derive the knots from the distances between the elements.
+    ///
+    /// The first knot is zero and the distance between two knots is given by
+    /// `distance(first, second).powf(alpha)`, where `alpha` is defined by the given [`Parameterization`].
+    /// The closure should return the distance between the given elements,
+    /// for example the absolute value of their difference or the euclidean distance.
+    ///
+    /// Consecutive elements should not be equal, as otherwise the spline may generate NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{catmull_rom::{CatmullRom, CatmullRomError, Parameterization}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), CatmullRomError> {
+    /// let catmull_rom = CatmullRom::builder()
+    ///                 .elements([0.0,1.0,5.0,6.0])
+    ///                 .parameterization(Parameterization::Centripetal, |a: f64, b: f64| b - a)
+    ///                 .build()?;
+    /// assert_eq!(catmull_rom.domain(), [0.0,4.0]);
+    /// assert_f64_near!(catmull_rom.gen(1.0), 1.0);
+    /// assert_f64_near!(catmull_rom.gen(3.0), 5.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Parameterization`]: Parameterization
+    #[cfg(feature = "alloc")]
+    pub fn parameterization<R, F>(
+        self,
+        parameterization: Parameterization,
+        distance: F,
+    ) -> CatmullRomBuilder<Sorted<Vec<R>>, E, R>
+    where
+        F: Fn(E::Output, E::Output) -> R,
+        E::Output: Copy,
+        R: Real + FromPrimitive,
+    {
+        CatmullRomBuilder {
+            inner: self
+                .inner
+                .map(|director| director.parameterization(parameterization, distance)),
+        }
+    }
+}
+
+impl<R, E> CatmullRomDirector<Type<R>, E, R>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<CatmullRomDirector<Equidistant<R>, E, R>, InvalidEquidistant> {
+        Ok(CatmullRomDirector {
+            knots: Equidistant::try_new(self.elements.len(), start, end)?,
+            elements: self.elements,
+            tension: self.tension,
+        })
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CatmullRomDirector<Equidistant<R>, E, R> {
+        CatmullRomDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            tension: self.tension,
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> CatmullRomDirector<Equidistant<R>, E, R> {
+        CatmullRomDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            tension: self.tension,
+        }
+    }
+}
+
+impl<R, E> CatmullRomBuilder<Type<R>, E, R>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> CatmullRomBuilder<Equidistant<R>, E, R> {
+        CatmullRomBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> CatmullRomBuilder<Equidistant<R>, E, R> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> CatmullRomBuilder<Equidistant<R>, E, R> {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E, R> CatmullRomDirector<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+{
+    /// Set the tension of the spline.
+    ///
+    /// The tangents of the spline are scaled by `1 - tension`. Such the default tension of zero
+    /// results in the standard catmull-rom spline and a tension of one results in zero tangents.
+    pub fn tension(self, tension: R) -> Self {
+        CatmullRomDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tension,
+        }
+    }
+}
+
+impl<K, E, R> CatmullRomBuilder<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+{
+    /// Set the tension of the spline.
+    ///
+    /// The tangents of the spline are scaled by `1 - tension`. Such the default tension of zero
+    /// results in the standard catmull-rom spline and a tension of one results in zero tangents.
+    pub fn tension(self, tension: R) -> Self {
+        CatmullRomBuilder {
+            inner: self.inner.map(|director| director.tension(tension)),
+        }
+    }
+}
+
+impl<K, E, R> CatmullRomDirector<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real,
+{
+    /// Build a catmull-rom spline.
+    pub fn build(self) -> CatmullRom<K, E, R> {
+        CatmullRom::new_unchecked(self.elements, self.knots, self.tension)
+    }
+}
+
+impl<K, E, R> CatmullRomBuilder<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real,
+{
+    /// Build a catmull-rom spline.
+    pub fn build(self) -> Result<CatmullRom<K, E, R>, CatmullRomError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::{Generator, Point};
+
+    fn distance(a: Point<f64, 2>, b: Point<f64, 2>) -> f64 {
+        ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn parameterization_knots() {
+        // the distances between the elements are 5, 9 and 1
+        let elements = [[0.0, 0.0], [3.0, 4.0], [3.0, 13.0], [4.0, 13.0]].map(Point::new);
+        let chordal = CatmullRomBuilder::new()
+            .elements(elements)
+            .parameterization(Parameterization::Chordal, distance)
+            .build()
+            .unwrap();
+        for (knot, result) in chordal
+            .knots
+            .sample([0, 1, 2, 3])
+            .zip([0.0, 5.0, 14.0, 15.0])
+        {
+            assert_f64_near!(knot, result);
+        }
+        let centripetal = CatmullRomBuilder::new()
+            .elements(elements)
+            .parameterization(Parameterization::Centripetal, distance)
+            .build()
+            .unwrap();
+        let root = 5.0_f64.sqrt();
+        for (knot, result) in
+            centripetal
+                .knots
+                .sample([0, 1, 2, 3])
+                .zip([0.0, root, root + 3.0, root + 4.0])
+        {
+            assert_f64_near!(knot, result);
+        }
+    }
+
+    #[test]
+    fn centripetal_avoids_cusp() {
+        // the outer elements pull the tangents of the short middle segment far outwards
+        let elements = [[8.0, -4.0], [0.0, 0.0], [1.0, 0.0], [-7.0, -4.0]].map(Point::new);
+        // returns true if the middle segment always moves along its chord
+        let advances = |parameterization| {
+            let catmull_rom = CatmullRomBuilder::new()
+                .elements(elements)
+                .parameterization(parameterization, distance)
+                .build()
+                .unwrap();
+            let (start, end) = (catmull_rom.knots.gen(1), catmull_rom.knots.gen(2));
+            let xs: Vec<f64> = (0..=100)
+                .map(|i| catmull_rom.gen(start + (end - start) * i as f64 / 100.0)[0])
+                .collect();
+            xs.windows(2).all(|pair| pair[0] < pair[1])
+        };
+        // the uniform spline loops back and intersects itself
+        assert!(!advances(Parameterization::Uniform));
+        assert!(advances(Parameterization::Centripetal));
+    }
+}
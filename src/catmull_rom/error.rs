@@ -0,0 +1,117 @@
+//! All error types for catmull-rom splines.
+
+pub use crate::{InvalidEquidistant, NotSorted};
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when using or creating a catmull-rom spline.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum CatmullRomError {
+    /// Error returned if the elements are to few for a catmull-rom spline.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+    /// Error returned if equidistant knots could not be created.
+    InvalidEquidistant(InvalidEquidistant),
+}
+
+impl fmt::Display for CatmullRomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatmullRomError::TooFewElements(inner) => inner.fmt(f),
+            CatmullRomError::KnotElementInequality(inner) => inner.fmt(f),
+            CatmullRomError::NotSorted(inner) => inner.fmt(f),
+            CatmullRomError::InvalidEquidistant(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for CatmullRomError {
+    fn from(from: TooFewElements) -> Self {
+        CatmullRomError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for CatmullRomError {
+    fn from(from: KnotElementInequality) -> Self {
+        CatmullRomError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for CatmullRomError {
+    fn from(from: NotSorted) -> Self {
+        CatmullRomError::NotSorted(from)
+    }
+}
+
+impl From<InvalidEquidistant> for CatmullRomError {
+    fn from(from: InvalidEquidistant) -> Self {
+        CatmullRomError::InvalidEquidistant(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for CatmullRomError {}
+
+/// Error returned if less than four elements are given.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TooFewElements {
+    /// The number of elements found.
+    found: usize,
+}
+
+impl TooFewElements {
+    /// Create a new error and document the number of elements found.
+    pub fn new(found: usize) -> Self {
+        TooFewElements { found }
+    }
+}
+
+impl fmt::Display for TooFewElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "To few elements given for a catmull-rom spline. {} elements were given, but at least 4 are necessary.",
+            self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for TooFewElements {}
+
+/// Error returned if the number of elements and the number of knots are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotElementInequality {}
@@ -0,0 +1,278 @@
+//! Catmull-Rom splines.
+//!
+//! The easist way to create a catmull-rom spline is by using the builder pattern of [`CatmullRomBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{catmull_rom::{CatmullRom, CatmullRomError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), CatmullRomError> {
+//! let catmull_rom = CatmullRom::builder()
+//!                 .elements([0.0,1.0,3.0,2.0])
+//!                 .equidistant::<f64>()
+//!                 .distance(0.0,1.0)
+//!                 .build()?;
+//! let results = [0.0,1.0,2.125,3.0,2.0];
+//! for (value,result) in catmull_rom.sample([0.0,1.0,1.5,2.0,3.0]).zip(results.iter().copied()){
+//!     assert_f64_near!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Catmull-rom splines are cubic splines which interpolate all their elements.
+//! The tangent at each element is derived from its neighbours, such only the elements and
+//! their knots have to be given. At the first and last element the missing neighbour is
+//! substituted by duplicating the element itself.
+//!
+//! The knots define the parameterization of the spline. Equidistant knots result in the uniform
+//! catmull-rom spline, which may produce cusps and self-intersections. Knots derived from the distances
+//! between elements avoid this, see [`Parameterization`].
+//!
+//! [`CatmullRomBuilder`]: CatmullRomBuilder
+//! [`Parameterization`]: Parameterization
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+mod builder;
+pub use builder::{CatmullRomBuilder, CatmullRomDirector, Parameterization};
+
+pub mod error;
+pub use error::{
+    CatmullRomError, InvalidEquidistant, KnotElementInequality, NotSorted, TooFewElements,
+};
+
+/// Catmull-Rom spline.
+///
+/// See the [catmull-rom module] for more information.
+///
+/// [catmull-rom module]: self
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CatmullRom<K, E, R> {
+    elements: E,
+    knots: K,
+    tension: R,
+}
+
+impl CatmullRom<Unknown, Unknown, Unknown> {
+    /// Get the builder for a catmull-rom spline.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`], [`equidistant()`] or [`parameterization()`]
+    /// - optionally a tension with [`tension()`]
+    ///
+    /// [`elements()`]: CatmullRomBuilder::elements()
+    /// [`knots()`]: CatmullRomBuilder::knots()
+    /// [`equidistant()`]: CatmullRomBuilder::equidistant()
+    /// [`parameterization()`]: CatmullRomBuilder::parameterization()
+    /// [`tension()`]: CatmullRomBuilder::tension()
+    pub fn builder() -> CatmullRomBuilder<Unknown, Unknown, Unknown> {
+        CatmullRomBuilder::new()
+    }
+}
+
+impl<K, E, R> CatmullRom<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real,
+{
+    /// Create a catmull-rom spline with the given elements, knots and tension.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than four elements are given.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`TooFewElements`]: CatmullRomError
+    /// [`KnotElementInequality`]: CatmullRomError
+    pub fn new(elements: E, knots: K, tension: R) -> Result<Self, CatmullRomError> {
+        if elements.len() < 4 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(CatmullRom {
+            elements,
+            knots,
+            tension,
+        })
+    }
+}
+
+impl<K, E, R> CatmullRom<K, E, R> {
+    /// Create a catmull-rom spline with the given elements, knots and tension.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be strictly increasing, there should be as many knots as elements
+    /// and there has to be at least *four* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, tension: R) -> Self {
+        CatmullRom {
+            elements,
+            knots,
+            tension,
+        }
+    }
+}
+
+impl<K, E, R> Generator<R> for CatmullRom<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R>
+        + Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Copy,
+    R: Real,
+{
+    type Output = E::Output;
+    fn gen(&self, scalar: R) -> E::Output {
+        let len = self.elements.len();
+        // the segment from index - 1 to index contains the scalar
+        let index = self.knots.strict_upper_bound_clamped(scalar, 1, len - 1);
+        let (first, second) = (self.elements.gen(index - 1), self.elements.gen(index));
+        let (start, end) = (self.knots.gen(index - 1), self.knots.gen(index));
+        let length = end - start;
+        // missing neighbours are substituted by duplicating the element
+        let (before, before_knot) = if index >= 2 {
+            (self.elements.gen(index - 2), self.knots.gen(index - 2))
+        } else {
+            (first, start - length)
+        };
+        let (after, after_knot) = if index + 1 < len {
+            (self.elements.gen(index + 1), self.knots.gen(index + 1))
+        } else {
+            (second, end + length)
+        };
+        // tangents of the segment, already scaled to be the offset of the inner bezier elements
+        let scale = length * (R::one() - self.tension) / (R::one() + R::one() + R::one());
+        let first_tangent = ((first - before) * (start - before_knot).recip()
+            - (second - before) * (end - before_knot).recip()
+            + (second - first) * length.recip())
+            * scale;
+        let second_tangent = ((second - first) * length.recip()
+            - (after - first) * (after_knot - start).recip()
+            + (after - second) * (after_knot - end).recip())
+            * scale;
+        let first_inner = first + first_tangent;
+        let second_inner = second - second_tangent;
+        // de Casteljau's algorithm
+        let factor = (scalar - start) / length;
+        let first = first.merge(first_inner, factor);
+        let inner = first_inner.merge(second_inner, factor);
+        let second = second_inner.merge(second, factor);
+        first
+            .merge(inner, factor)
+            .merge(inner.merge(second, factor), factor)
+    }
+}
+
+impl<K, E, R> Curve<R> for CatmullRom<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R>
+        + Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Copy,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Sorted;
+
+    #[test]
+    fn uniform() {
+        let catmull_rom = CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0, 5.0])
+            .equidistant::<f64>()
+            .distance(0.0, 1.0)
+            .build()
+            .unwrap();
+        // interpolates all elements
+        for (value, result) in catmull_rom
+            .sample([0.0, 1.0, 2.0, 3.0, 4.0])
+            .zip([0.0, 1.0, 3.0, 2.0, 5.0])
+        {
+            assert_f64_near!(value, result);
+        }
+        // (-p0 + 9p1 + 9p2 - p3)/16 in the middle of inner segments
+        assert_f64_near!(catmull_rom.gen(1.5), 2.125);
+        assert_f64_near!(catmull_rom.gen(2.5), 2.4375);
+        // the first segment uses the first element twice
+        assert_f64_near!(catmull_rom.gen(0.5), 0.375);
+        assert_eq!(catmull_rom.domain(), [0.0, 4.0]);
+    }
+
+    #[test]
+    fn tension() {
+        let catmull_rom = CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .tension(1.0)
+            .build()
+            .unwrap();
+        // a tension of one results in zero tangents
+        assert_f64_near!(catmull_rom.gen(1.5), 2.0);
+        assert_f64_near!(catmull_rom.gen(1.25), 1.3125);
+    }
+
+    #[test]
+    fn non_uniform() {
+        // non-uniform knots which are equally spaced inside of the segment behave like uniform knots
+        let uniform = CatmullRom::new(
+            [0.0, 1.0, 3.0, 2.0],
+            Sorted::new([0.0, 1.0, 2.0, 3.0]).unwrap(),
+            0.0,
+        )
+        .unwrap();
+        let scaled = CatmullRom::new(
+            [0.0, 1.0, 3.0, 2.0],
+            Sorted::new([0.0, 2.0, 4.0, 6.0]).unwrap(),
+            0.0,
+        )
+        .unwrap();
+        for i in 0..=10 {
+            let input = i as f64 * 0.3;
+            assert_f64_near!(uniform.gen(input), scaled.gen(input * 2.0), 16);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0])
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .is_err());
+        assert!(CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .is_err());
+        assert!(CatmullRom::builder()
+            .elements([0.0, 1.0, 3.0, 2.0])
+            .knots([0.0, 2.0, 1.0, 3.0])
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use super::Easing;
+use crate::{Curve, Generator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Maximal number of newton iterations before falling back to bisection.
+const NEWTON_ITERATIONS: usize = 8;
+/// Maximal number of bisection iterations.
+const BISECTION_ITERATIONS: usize = 64;
+
+/// CubicBezierEase is an easing curve defined by two control points, like the `cubic-bezier()`
+/// timing functions of CSS.
+///
+/// The curve is a cubic bezier curve from (0.0,0.0) to (1.0,1.0) with the two given control points.
+/// For an input `t`, the parameter of the bezier curve whose x-component is `t` is searched
+/// and the y-component at this parameter is returned. Inputs outside of [0.0,1.0] are clamped.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{easing::CubicBezierEase, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// // the "ease" timing function of CSS
+/// let ease = CubicBezierEase::new(0.25, 0.1, 0.25, 1.0);
+/// assert_f64_near!(ease.gen(0.0), 0.0);
+/// assert_f64_near!(ease.gen(0.5), 0.802403387584857, 16);
+/// assert_f64_near!(ease.gen(1.0), 1.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CubicBezierEase<R> {
+    x: [R; 3],
+    y: [R; 3],
+}
+
+impl<R> CubicBezierEase<R>
+where
+    R: Real + FromPrimitive,
+{
+    /// Create a new easing curve with the control points (x1,y1) and (x2,y2).
+    ///
+    /// `x1` and `x2` have to be in [0.0,1.0], otherwise the x-component of the bezier curve
+    /// is not monotone and the curve is not a function anymore.
+    /// `y1` and `y2` may be outside of [0.0,1.0] to create overshooting easings.
+    pub fn new(x1: R, y1: R, x2: R, y2: R) -> Self {
+        CubicBezierEase {
+            x: Self::coefficients(x1, x2),
+            y: Self::coefficients(y1, y2),
+        }
+    }
+
+    /// Calculate the polynomial coefficients [a,b,c] of `a*u^3 + b*u^2 + c*u`
+    /// for a bezier curve with the elements `[0.0, first, second, 1.0]`.
+    fn coefficients(first: R, second: R) -> [R; 3] {
+        let three = R::from_usize(3).expect("Could not convert 3 to a real number");
+        let c = three * first;
+        let b = three * (second - first) - c;
+        let a = R::one() - c - b;
+        [a, b, c]
+    }
+
+    fn polynomial(coefficients: [R; 3], u: R) -> R {
+        let [a, b, c] = coefficients;
+        ((a * u + b) * u + c) * u
+    }
+
+    fn derivative(coefficients: [R; 3], u: R) -> R {
+        let [a, b, c] = coefficients;
+        let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+        let three = R::from_usize(3).expect("Could not convert 3 to a real number");
+        (three * a * u + two * b) * u + c
+    }
+
+    /// Search the parameter of the bezier curve whose x-component is the given input.
+    ///
+    /// Newton iteration is used first. If it does not converge, bisection is used.
+    fn parameter(&self, input: R) -> R {
+        let tolerance = R::epsilon() * R::from_usize(4).unwrap();
+        let mut u = input;
+        for _ in 0..NEWTON_ITERATIONS {
+            let error = Self::polynomial(self.x, u) - input;
+            if error.abs() <= tolerance {
+                return u;
+            }
+            let slope = Self::derivative(self.x, u);
+            if slope.abs() <= R::epsilon() {
+                break;
+            }
+            u = u - error / slope;
+        }
+        let mut low = R::zero();
+        let mut high = R::one();
+        u = input;
+        for _ in 0..BISECTION_ITERATIONS {
+            let x = Self::polynomial(self.x, u);
+            if (x - input).abs() <= tolerance {
+                break;
+            }
+            if x < input {
+                low = u;
+            } else {
+                high = u;
+            }
+            u = (low + high) / R::from_usize(2).unwrap();
+        }
+        u
+    }
+}
+
+impl<R> Generator<R> for CubicBezierEase<R>
+where
+    R: Real + FromPrimitive,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        if input <= R::zero() {
+            return R::zero();
+        }
+        if input >= R::one() {
+            return R::one();
+        }
+        Self::polynomial(self.y, self.parameter(input))
+    }
+}
+
+impl<R> Curve<R> for CubicBezierEase<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
+
+impl<R> Easing<R> for CubicBezierEase<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn ease(&self, factor: R) -> R {
+        self.gen(factor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn css_timing_functions() {
+        let linear = CubicBezierEase::new(0.0, 0.0, 1.0, 1.0);
+        for input in [0.1, 0.3, 0.5, 0.9] {
+            assert_f64_near!(linear.gen(input), input, 16);
+        }
+        let ease_in_out = CubicBezierEase::new(0.42, 0.0, 0.58, 1.0);
+        assert_f64_near!(ease_in_out.gen(0.5), 0.5, 16);
+        assert_f64_near!(ease_in_out.gen(0.25) + ease_in_out.gen(0.75), 1.0, 16);
+        assert_f64_near!(ease_in_out.gen(-1.0), 0.0);
+        assert_f64_near!(ease_in_out.gen(2.0), 1.0);
+        // flat start, such that newton iteration has to fall back to bisection
+        let steep = CubicBezierEase::new(1.0, 0.0, 1.0, 1.0);
+        let u = steep.parameter(0.001);
+        assert!((CubicBezierEase::polynomial(steep.x, u) - 0.001).abs() < 1e-12);
+    }
+}
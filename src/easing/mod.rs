@@ -2,14 +2,128 @@
 //!
 //! Easing function, in the context of this crate, are function which take as only input
 //! a real number in [0.0,1.0] and return a real number in [0.0,1.0].
+//!
+//! All easings implement the [`Easing`] trait, which is implemented for closures and functions
+//! as well as for the easing structs of this module. To write a custom easing, implement this trait.
+//! Curves can be used as easing with the [`CurveEasing`] wrapper.
 
 use crate::{Curve, Generator};
 use num_traits::real::Real;
-use num_traits::FromPrimitive;
+use num_traits::{FloatConst, FromPrimitive};
 
+mod cubic_bezier;
 mod plateau;
+pub use cubic_bezier::CubicBezierEase;
 pub use plateau::Plateau;
 
+/// Trait for easing functions.
+///
+/// An easing takes a real number in [0.0,1.0] and returns a real number in [0.0,1.0].
+/// Most easings map 0.0 to 0.0 and 1.0 to 1.0, such that they only change the velocity
+/// in between, but this is not required.
+///
+/// This trait is implemented for all functions and closures `Fn(R) -> R`, for the easing structs
+/// of this module and for linear, bezier and bspline interpolations. Other curves can be used as easing
+/// by wrapping them in [`CurveEasing`]. It is accepted by the [`easing()`] method of the linear builder.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::easing::Easing;
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let square = |x: f64| x * x;
+/// assert_f64_near!(square.ease(0.5), 0.25);
+/// let inverted = square.invert();
+/// assert_f64_near!(inverted.ease(0.5), 0.75);
+/// ```
+///
+/// [`easing()`]: crate::linear::LinearBuilder::easing()
+pub trait Easing<R> {
+    /// Reshape the given factor.
+    fn ease(&self, factor: R) -> R;
+    /// Returns the derivative of the easing at the given factor.
+    ///
+    /// The default implementation approximates the derivative by a central difference quotient
+    /// which stays inside of [0.0,1.0]. Easings which know their derivative should overwrite this method.
+    fn ease_derivative(&self, factor: R) -> R
+    where
+        R: Real,
+    {
+        let step = R::epsilon().cbrt();
+        let lower = (factor - step).max(factor.min(R::zero()));
+        let upper = (factor + step).min(factor.max(R::one()));
+        (self.ease(upper) - self.ease(lower)) / (upper - lower)
+    }
+    /// Flip the easing, such that an ease-in becomes an ease-out and vice versa.
+    ///
+    /// For an easing `f`, the result is `1 - f(1 - x)`. See [`Inverted`] for more information.
+    fn invert(self) -> Inverted<Self>
+    where
+        Self: Sized,
+    {
+        Inverted::new(self)
+    }
+}
+
+impl<F, R> Easing<R> for F
+where
+    F: Fn(R) -> R,
+{
+    fn ease(&self, factor: R) -> R {
+        self(factor)
+    }
+}
+
+/// Easing which flips the graph of another easing, such that `1 - f(1 - x)` is returned.
+///
+/// This struct is created by the [`invert()`] method of easings. See its documentation for more.
+///
+/// [`invert()`]: Easing::invert()
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Inverted<E>(E);
+
+impl<E> Inverted<E> {
+    /// Create a new struct flipping the given easing.
+    pub fn new(easing: E) -> Self {
+        Inverted(easing)
+    }
+}
+
+impl<E, R> Easing<R> for Inverted<E>
+where
+    E: Easing<R>,
+    R: Real,
+{
+    fn ease(&self, factor: R) -> R {
+        flip(self.0.ease(flip(factor)))
+    }
+    fn ease_derivative(&self, factor: R) -> R {
+        self.0.ease_derivative(flip(factor))
+    }
+}
+
+impl<E, R> Generator<R> for Inverted<E>
+where
+    E: Easing<R>,
+    R: Real,
+{
+    type Output = R;
+    fn gen(&self, input: R) -> R {
+        self.ease(input)
+    }
+}
+
+impl<E, R> Curve<R> for Inverted<E>
+where
+    E: Easing<R>,
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
+
 /// This is just a wrapper for easing functions.
 ///
 /// We expect the domain to be normalized.
@@ -46,9 +160,63 @@ where
     }
 }
 
-/// Identity as Curve.
+impl<F, R> Easing<R> for FuncEase<F>
+where
+    F: Fn(R) -> R,
+{
+    fn ease(&self, factor: R) -> R {
+        (self.func)(factor)
+    }
+}
+
+/// Wrapper to use any curve as easing.
+///
+/// The curve is used with inputs in [0.0,1.0], independent of its domain.
+/// Interpolations of this crate, like bezier curves, implement [`Easing`] directly,
+/// such that this wrapper is only needed for other curves, like adaptors or curves of other crates.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "linear", doc = "```rust")]
+#[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+/// # use enterpolation::{linear::{Linear, LinearError}, easing::{CurveEasing, FuncEase}, Curve, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// let clamped = FuncEase::new(|x: f64| x * x).clamp();
+/// let linear = Linear::builder()
+///                 .elements([0.0,10.0])
+///                 .knots([0.0,1.0])
+///                 .easing(CurveEasing::new(clamped))
+///                 .build()?;
+/// assert_f64_near!(linear.gen(0.5), 2.5);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CurveEasing<C>(C);
+
+impl<C> CurveEasing<C> {
+    /// Create a new easing from the given curve.
+    pub fn new(curve: C) -> Self {
+        CurveEasing(curve)
+    }
+}
+
+impl<C, R> Easing<R> for CurveEasing<C>
+where
+    C: Generator<R, Output = R>,
+{
+    fn ease(&self, factor: R) -> R {
+        self.0.gen(factor)
+    }
+}
+
+/// Identity as Curve.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Identity {}
 
 impl Identity {
@@ -80,6 +248,18 @@ where
     }
 }
 
+impl<R> Easing<R> for Identity {
+    fn ease(&self, factor: R) -> R {
+        factor
+    }
+    fn ease_derivative(&self, _factor: R) -> R
+    where
+        R: Real,
+    {
+        R::one()
+    }
+}
+
 /// Flips the "start" and "end".
 ///
 /// For easing functions seen as a graph, this flips the graph on the x axis.
@@ -110,6 +290,59 @@ where
     flip(smoothstart::<R, N>(flip(x)))
 }
 
+/// Creates an ease-out from the given ease-in by flipping its graph.
+///
+/// For an easing function `f`, the result is `1 - f(1 - x)`.
+/// This is the same as [`Easing::invert()`], but returns a function instead of an [`Inverted`] struct.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::easing::{ease_out, smoothend, smoothstart};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let out = ease_out(smoothstart::<f64, 3>);
+/// assert_f64_near!(out(0.3), smoothend::<f64, 3>(0.3));
+/// ```
+pub fn ease_out<R, F>(func: F) -> impl Fn(R) -> R + Copy
+where
+    F: Fn(R) -> R + Copy,
+    R: Real,
+{
+    let inverted = func.invert();
+    move |x: R| inverted.ease(x)
+}
+
+/// Creates an ease-in-out from the given ease-in.
+///
+/// The first half of the result is the given easing function scaled into [0.0,0.5],
+/// the second half is its flipped version scaled into [0.5,1.0].
+/// If the given function maps 1.0 to 1.0, the result is exactly 0.5 at 0.5.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::easing::{mirror, smoothstart};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let in_out = mirror(smoothstart::<f64, 2>);
+/// assert_f64_near!(in_out(0.25), 0.125);
+/// assert_eq!(in_out(0.5), 0.5);
+/// assert_f64_near!(in_out(0.75), 0.875);
+/// ```
+pub fn mirror<R, F>(func: F) -> impl Fn(R) -> R + Copy
+where
+    F: Fn(R) -> R + Copy,
+    R: Real + FromPrimitive,
+{
+    move |x: R| {
+        let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+        if x < R::one() / two {
+            func(x * two) / two
+        } else {
+            (two - func(two - x * two)) / two
+        }
+    }
+}
+
 /// Smoothstep function, see <https://en.wikipedia.org/wiki/Smoothstep>
 pub fn smoothstep<R>(x: R) -> R
 where
@@ -130,3 +363,414 @@ where
     let fifteen = R::from_usize(15).expect("Could not convert 15 to a real number");
     x * x * x * (x * (x * six - fifteen) + ten)
 }
+
+/// Creates an easing function which oscillates `cycles` times between 0.0 and 1.0.
+///
+/// Each cycle is a full sine wave starting and ending at 0.0 and reaching 1.0 in its middle.
+/// In contrast to most other easing functions, the created function is *not* monotone and
+/// also ends at 0.0 instead of 1.0. This is useful for pulsing or breathing animations.
+///
+/// The input is clamped to [0.0,1.0], such the output is always in [0.0,1.0].
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{easing::{oscillate, FuncEase}, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let pulse = FuncEase::new(oscillate::<f64>(2));
+/// assert_f64_near!(pulse.gen(0.0), 0.0);
+/// assert_f64_near!(pulse.gen(0.25), 1.0);
+/// assert_f64_near!(pulse.gen(0.75), 1.0);
+/// assert_f64_near!(pulse.gen(2.0), pulse.gen(1.0));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `cycles` can not be converted to `R`.
+pub fn oscillate<R>(cycles: usize) -> impl Fn(R) -> R + Copy
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let frequency =
+        two * R::PI() * R::from_usize(cycles).expect("Could not convert cycles to a real number");
+    move |x: R| {
+        let x = x.max(R::zero()).min(R::one());
+        (R::one() - (frequency * x).cos()) / two
+    }
+}
+
+/// Sine ease-in, which starts slowly following a quarter of a cosine wave.
+pub fn sine_in<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    flip(sine_out(flip(x)))
+}
+
+/// Sine ease-out, which ends slowly following a quarter of a sine wave.
+pub fn sine_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    (x * R::FRAC_PI_2()).sin()
+}
+
+/// Sine ease-in-out, which starts and ends slowly following half of a cosine wave.
+pub fn sine_in_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    (R::one() - (x * R::PI()).cos()) / two
+}
+
+/// Exponential ease-in, following `2^(10*(x-1))`.
+///
+/// As the formula does not reach 0.0 at the start, `expo_in(0.0)` is defined to be exactly 0.0.
+pub fn expo_in<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    if x <= R::zero() {
+        return R::zero();
+    }
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let ten = R::from_usize(10).expect("Could not convert 10 to a real number");
+    two.powf(ten * (x - R::one()))
+}
+
+/// Exponential ease-out, following `1-2^(-10*x)`.
+///
+/// As the formula does not reach 1.0 at the end, `expo_out(1.0)` is defined to be exactly 1.0.
+pub fn expo_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    flip(expo_in(flip(x)))
+}
+
+/// Exponential ease-in-out, combining [`expo_in()`] and [`expo_out()`].
+///
+/// The start and end are exactly 0.0 and 1.0 respectively.
+pub fn expo_in_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    mirror(expo_in)(x)
+}
+
+/// Circular ease-in, following a quarter of a circle.
+pub fn circ_in<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    R::one() - (R::one() - x * x).sqrt()
+}
+
+/// Circular ease-out, following a quarter of a circle.
+pub fn circ_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    flip(circ_in(flip(x)))
+}
+
+/// Circular ease-in-out, combining [`circ_in()`] and [`circ_out()`].
+pub fn circ_in_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    mirror(circ_in)(x)
+}
+
+/// The overshoot commonly used with [`back_in()`], [`back_out()`] and [`back_in_out()`].
+///
+/// Use [`default_overshoot()`] to get this value as another real type.
+pub const DEFAULT_OVERSHOOT: f64 = 1.70158;
+
+/// Returns [`DEFAULT_OVERSHOOT`] as the given real type.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{easing::{back_out, default_overshoot, FuncEase}, Generator};
+/// let back = FuncEase::new(back_out(default_overshoot::<f32>()));
+/// assert!(back.gen(0.5f32) > 1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if [`DEFAULT_OVERSHOOT`] can not be converted to `R`.
+pub fn default_overshoot<R>() -> R
+where
+    R: FromPrimitive,
+{
+    R::from_f64(DEFAULT_OVERSHOOT)
+        .expect("Could not convert the default overshoot to a real number")
+}
+
+/// Creates a back ease-in, which pulls back below 0.0 before moving to 1.0.
+///
+/// The higher the overshoot, the further the easing pulls back. Usually [`default_overshoot()`] is used.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{easing::{back_in, FuncEase, DEFAULT_OVERSHOOT}, Generator};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let back = FuncEase::new(back_in(DEFAULT_OVERSHOOT));
+/// assert_f64_near!(back.gen(0.0), 0.0);
+/// assert!(back.gen(0.5) < 0.0);
+/// assert_f64_near!(back.gen(1.0), 1.0);
+/// ```
+pub fn back_in<R>(overshoot: R) -> impl Fn(R) -> R + Copy
+where
+    R: Real,
+{
+    move |x: R| x * x * (overshoot * (x - R::one()) + x)
+}
+
+/// Creates a back ease-out, which overshoots 1.0 before settling at 1.0.
+///
+/// The higher the overshoot, the further the easing overshoots. Usually [`default_overshoot()`] is used.
+pub fn back_out<R>(overshoot: R) -> impl Fn(R) -> R + Copy
+where
+    R: Real,
+{
+    ease_out(back_in(overshoot))
+}
+
+/// Creates a back ease-in-out, which pulls back at the start and overshoots at the end.
+///
+/// As in the equations of Robert Penner, the overshoot given is scaled by 1.525,
+/// such that [`DEFAULT_OVERSHOOT`] results in an overshoot of about 10%.
+///
+/// # Panics
+///
+/// Panics if 1.525 can not be converted to `R`.
+pub fn back_in_out<R>(overshoot: R) -> impl Fn(R) -> R + Copy
+where
+    R: Real + FromPrimitive,
+{
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let scale = R::from_f64(1.525).expect("Could not convert 1.525 to a real number");
+    let back = back_in(overshoot * scale);
+    move |x: R| {
+        if x < R::one() / two {
+            back(x * two) / two
+        } else {
+            (two - back(two - x * two)) / two
+        }
+    }
+}
+
+/// Elastic ease-in, which oscillates with growing amplitude before reaching 1.0.
+pub fn elastic_in<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    if x <= R::zero() {
+        return R::zero();
+    }
+    if x >= R::one() {
+        return R::one();
+    }
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let three = R::from_usize(3).expect("Could not convert 3 to a real number");
+    let ten = R::from_usize(10).expect("Could not convert 10 to a real number");
+    let shift = R::from_f64(10.75).expect("Could not convert 10.75 to a real number");
+    let frequency = two * R::PI() / three;
+    -two.powf(ten * x - ten) * ((ten * x - shift) * frequency).sin()
+}
+
+/// Elastic ease-out, which overshoots 1.0 and oscillates with decaying amplitude around it.
+pub fn elastic_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    flip(elastic_in(flip(x)))
+}
+
+/// Elastic ease-in-out, combining [`elastic_in()`] and [`elastic_out()`].
+pub fn elastic_in_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive + FloatConst,
+{
+    if x <= R::zero() {
+        return R::zero();
+    }
+    if x >= R::one() {
+        return R::one();
+    }
+    let two = R::from_usize(2).expect("Could not convert 2 to a real number");
+    let ten = R::from_usize(10).expect("Could not convert 10 to a real number");
+    let twenty = R::from_usize(20).expect("Could not convert 20 to a real number");
+    let shift = R::from_f64(11.125).expect("Could not convert 11.125 to a real number");
+    let period = R::from_f64(4.5).expect("Could not convert 4.5 to a real number");
+    let frequency = two * R::PI() / period;
+    let wave = ((twenty * x - shift) * frequency).sin();
+    if x < R::one() / two {
+        -(two.powf(twenty * x - ten) * wave) / two
+    } else {
+        two.powf(ten - twenty * x) * wave / two + R::one()
+    }
+}
+
+/// Bounce ease-in, which bounces with growing height before reaching 1.0.
+pub fn bounce_in<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    flip(bounce_out(flip(x)))
+}
+
+/// Bounce ease-out, which bounces like a dropped ball before settling at 1.0.
+///
+/// The bounces are four pieces of quadratic functions.
+pub fn bounce_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    let real =
+        |value: f64| R::from_f64(value).expect("Could not convert constant to a real number");
+    let strength = real(7.5625);
+    let width = real(2.75);
+    let bounce = |x: R, offset: f64, height: f64| {
+        let x = x - real(offset) / width;
+        strength * x * x + real(height)
+    };
+    if x < R::one() / width {
+        strength * x * x
+    } else if x < real(2.0) / width {
+        bounce(x, 1.5, 0.75)
+    } else if x < real(2.5) / width {
+        bounce(x, 2.25, 0.9375)
+    } else {
+        bounce(x, 2.625, 0.984375)
+    }
+}
+
+/// Bounce ease-in-out, combining [`bounce_in()`] and [`bounce_out()`].
+pub fn bounce_in_out<R>(x: R) -> R
+where
+    R: Real + FromPrimitive,
+{
+    mirror(bounce_in)(x)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn easing_trait() {
+        fn check<E: Easing<f64> + Copy>(easing: E) {
+            let inverted = easing.invert();
+            assert_f64_near!(inverted.ease(0.0), 0.0);
+            assert_f64_near!(inverted.ease(1.0), 1.0);
+            assert_f64_near!(inverted.ease(0.3), 1.0 - easing.ease(0.7));
+        }
+        check(Identity::new());
+        check(smoothstart::<f64, 3>);
+        check(FuncEase::new(|x: f64| x.sqrt()));
+        check(Plateau::new(0.5));
+        check(CubicBezierEase::new(0.42, 0.0, 1.0, 1.0));
+        check(Inverted::new(Identity::new()));
+        // inverting twice results in the same easing
+        assert_f64_near!(
+            smoothend::<f64, 2>.invert().invert().ease(0.2),
+            smoothend::<f64, 2>(0.2)
+        );
+    }
+
+    #[test]
+    fn endpoints() {
+        let functions: [fn(f64) -> f64; 9] = [
+            sine_in,
+            sine_out,
+            sine_in_out,
+            expo_in,
+            expo_out,
+            expo_in_out,
+            circ_in,
+            circ_out,
+            circ_in_out,
+        ];
+        for func in functions {
+            assert_eq!(func(0.0), 0.0);
+            assert_eq!(func(1.0), 1.0);
+        }
+        for func in [sine_in_out, expo_in_out, circ_in_out] {
+            assert_f64_near!(func(0.5), 0.5);
+        }
+    }
+
+    #[test]
+    fn penner() {
+        assert_f64_near!(sine_in(0.5), 0.29289321881345254);
+        assert_f64_near!(sine_out(0.5), core::f64::consts::FRAC_1_SQRT_2);
+        assert_f64_near!(expo_in(0.5), 0.03125);
+        assert_f64_near!(expo_out(0.5), 0.96875);
+        assert_f64_near!(circ_in(0.5), 0.1339745962155614);
+        assert_f64_near!(circ_out(0.5), 0.8660254037844386);
+    }
+
+    #[test]
+    fn overshooting() {
+        let functions: [fn(f64) -> f64; 6] = [
+            elastic_in,
+            elastic_out,
+            elastic_in_out,
+            bounce_in,
+            bounce_out,
+            bounce_in_out,
+        ];
+        for func in functions {
+            assert_f64_near!(func(0.0), 0.0);
+            assert_f64_near!(func(1.0), 1.0);
+        }
+        let backs: [&dyn Fn(f64) -> f64; 3] = [
+            &back_in(DEFAULT_OVERSHOOT),
+            &back_out(DEFAULT_OVERSHOOT),
+            &back_in_out(DEFAULT_OVERSHOOT),
+        ];
+        for back in backs {
+            assert_f64_near!(back(0.0), 0.0);
+            assert_f64_near!(back(1.0), 1.0);
+        }
+        type Ease = fn(f64) -> f64;
+        let expected: [(Ease, [f64; 3]); 3] = [
+            (bounce_out, [0.47265625, 0.765625, 0.97265625]),
+            (bounce_in, [0.02734375, 0.234375, 0.52734375]),
+            (bounce_in_out, [0.1171875, 0.5, 0.8828125]),
+        ];
+        for (func, results) in expected {
+            for (input, result) in [0.25, 0.5, 0.75].into_iter().zip(results) {
+                assert_f64_near!(func(input), result);
+            }
+        }
+        assert_f64_near!(back_in(DEFAULT_OVERSHOOT)(0.5), -0.0876975);
+        assert_f64_near!(back_out(DEFAULT_OVERSHOOT)(0.5), 1.0876975);
+        assert_f64_near!(back_in_out(DEFAULT_OVERSHOOT)(0.5), 0.5);
+        assert_eq!(default_overshoot::<f64>(), DEFAULT_OVERSHOOT);
+        assert!(back_in(default_overshoot::<f32>())(0.5) < 0.0);
+        assert_f64_near!(elastic_in(0.5), -0.015625, 16);
+        assert_f64_near!(elastic_out(0.5), 1.015625, 16);
+        assert_f64_near!(elastic_in_out(0.5), 0.5);
+    }
+
+    #[test]
+    fn mirror() {
+        let functions: [fn(f64) -> f64; 4] = [smoothstart::<f64, 2>, sine_in, expo_in, bounce_in];
+        for func in functions {
+            let in_out = super::mirror(func);
+            assert_eq!(in_out(0.0), 0.0);
+            assert_eq!(in_out(0.5), 0.5);
+            assert_eq!(in_out(1.0), 1.0);
+            assert_f64_near!(in_out(0.2) + in_out(0.8), 1.0);
+            assert_f64_near!(ease_out(func)(0.3), 1.0 - func(0.7));
+        }
+        assert_f64_near!(super::mirror(smoothstart::<f64, 3>)(0.3), 0.108);
+    }
+}
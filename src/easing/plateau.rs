@@ -1,4 +1,4 @@
-use crate::easing::smoothstep;
+use crate::easing::{smoothstep, Easing};
 use crate::{Curve, Generator};
 use num_traits::real::Real;
 use num_traits::FromPrimitive;
@@ -65,3 +65,12 @@ where
         [R::zero(), R::one()]
     }
 }
+
+impl<R> Easing<R> for Plateau<R>
+where
+    R: Real + FromPrimitive,
+{
+    fn ease(&self, factor: R) -> R {
+        self.gen(factor)
+    }
+}
@@ -0,0 +1,158 @@
+//! Module with differential geometry of curves, like their curvature and their Frenet frame.
+//!
+//! The functions of this module take the first and second deriative of a curve at some input,
+//! as returned by `gen_with_deriatives()` of bezier curves and bsplines.
+//! Both curves also provide the methods `curvature()` and `frenet_frame()` as a shortcut.
+//!
+//! As elements of curves are generic, the functions are bound by the traits [`EuclideanVector`] and [`Cross`],
+//! which are implemented for [`Point`] of dimension 2 and 3.
+//!
+//! [`Point`]: crate::Point
+
+use crate::Point;
+use core::ops::{Mul, Sub};
+use num_traits::real::Real;
+
+/// Trait for vectors of an euclidean space.
+pub trait EuclideanVector<R>: Copy
+where
+    R: Real,
+{
+    /// Returns the dot product of both vectors.
+    fn dot(self, other: Self) -> R;
+    /// Returns the magnitude of the cross product of both vectors.
+    ///
+    /// This is the area of the parallelogram spanned by both vectors.
+    fn cross_norm(self, other: Self) -> R;
+    /// Returns the euclidean norm of the vector.
+    fn norm(self) -> R {
+        self.dot(self).sqrt()
+    }
+}
+
+/// Trait for vectors with a cross product.
+pub trait Cross {
+    /// Returns the cross product of both vectors.
+    fn cross(self, other: Self) -> Self;
+}
+
+impl<R> EuclideanVector<R> for Point<R, 2>
+where
+    R: Real,
+{
+    fn dot(self, other: Self) -> R {
+        self[0] * other[0] + self[1] * other[1]
+    }
+    fn cross_norm(self, other: Self) -> R {
+        (self[0] * other[1] - self[1] * other[0]).abs()
+    }
+}
+
+impl<R> EuclideanVector<R> for Point<R, 3>
+where
+    R: Real,
+{
+    fn dot(self, other: Self) -> R {
+        self[0] * other[0] + self[1] * other[1] + self[2] * other[2]
+    }
+    fn cross_norm(self, other: Self) -> R {
+        self.cross(other).norm()
+    }
+}
+
+impl<R> Cross for Point<R, 3>
+where
+    R: Real,
+{
+    fn cross(self, other: Self) -> Self {
+        Point::new([
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+}
+
+/// Calculate the curvature of a curve given its first and second deriative.
+///
+/// The curvature is given by `|r' x r''| / |r'|^3`.
+/// If the first deriative is zero, the curvature is not defined and NaN or infinity is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{geometry, Point};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// // deriatives of a circle with radius 2 at angle 0
+/// let first = Point::new([0.0, 2.0]);
+/// let second = Point::new([-2.0, 0.0]);
+/// assert_f64_near!(geometry::curvature(first, second), 0.5);
+/// ```
+pub fn curvature<V, R>(first: V, second: V) -> R
+where
+    V: EuclideanVector<R>,
+    R: Real,
+{
+    let speed = first.norm();
+    first.cross_norm(second) / (speed * speed * speed)
+}
+
+/// Calculate the Frenet frame of a curve given its first and second deriative.
+///
+/// Returns the unit tangent, the unit normal and the unit binormal, in this order.
+/// The normal is the part of the second deriative orthogonal to the tangent.
+/// If the curve has no curvature at this point, the normal and binormal are not defined and contain NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// # use enterpolation::{geometry, Point};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// // deriatives of a helix at angle 0
+/// let first = Point::new([0.0, 1.0, 1.0]);
+/// let second = Point::new([-1.0, 0.0, 0.0]);
+/// let [tangent, normal, binormal] = geometry::frenet_frame(first, second);
+/// let root = 0.5f64.sqrt();
+/// for (value, result) in tangent.into_array().into_iter().zip([0.0, root, root]) {
+///     assert_f64_near!(value, result);
+/// }
+/// assert_eq!(normal.into_array(), [-1.0, 0.0, 0.0]);
+/// for (value, result) in binormal.into_array().into_iter().zip([0.0, -root, root]) {
+///     assert_f64_near!(value, result);
+/// }
+/// ```
+pub fn frenet_frame<V, R>(first: V, second: V) -> [V; 3]
+where
+    V: EuclideanVector<R> + Cross + Sub<Output = V> + Mul<R, Output = V>,
+    R: Real,
+{
+    let tangent = first * first.norm().recip();
+    let normal = second - tangent * tangent.dot(second);
+    let normal = normal * normal.norm().recip();
+    [tangent, normal, tangent.cross(normal)]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn curvature_of_circle() {
+        // circle with radius 4 in 3D at angle t
+        for t in [0.0f64, 0.5, 2.0] {
+            let first = Point::new([-4.0 * t.sin(), 4.0 * t.cos(), 0.0]);
+            let second = Point::new([-4.0 * t.cos(), -4.0 * t.sin(), 0.0]);
+            assert_f64_near!(curvature(first, second), 0.25);
+            let [tangent, normal, binormal] = frenet_frame(first, second);
+            assert_f64_near!(tangent.norm(), 1.0);
+            assert_f64_near!(normal[0], -t.cos());
+            assert_f64_near!(normal[1], -t.sin());
+            assert_eq!(binormal[0], 0.0);
+            assert_eq!(binormal[1], 0.0);
+            assert_f64_near!(binormal[2], 1.0);
+        }
+        // a straight line has no curvature
+        let line = Point::new([1.0, 2.0]);
+        assert_eq!(curvature(line, line * 3.0), 0.0);
+    }
+}
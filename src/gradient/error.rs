@@ -0,0 +1,91 @@
+//! All error types for gradients.
+
+use core::{convert::From, fmt};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a gradient.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GradientError {
+    /// Error returned if no color stops were given.
+    NoStops(NoStops),
+    /// Error returned if the position of a color stop is NaN.
+    InvalidPosition(InvalidPosition),
+}
+
+impl fmt::Display for GradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GradientError::NoStops(inner) => inner.fmt(f),
+            GradientError::InvalidPosition(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<NoStops> for GradientError {
+    fn from(from: NoStops) -> Self {
+        GradientError::NoStops(from)
+    }
+}
+
+impl From<InvalidPosition> for GradientError {
+    fn from(from: InvalidPosition) -> Self {
+        GradientError::InvalidPosition(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for GradientError {}
+
+/// Error returned if no color stops were given.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoStops {}
+
+impl NoStops {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        NoStops {}
+    }
+}
+
+impl fmt::Display for NoStops {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "No color stops given, a gradient needs at least one color stop."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NoStops {}
+
+/// Error returned if the position of a color stop is NaN.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InvalidPosition {
+    /// The index of the color stop.
+    index: usize,
+}
+
+impl InvalidPosition {
+    /// Create a new error with the index of the color stop with the invalid position.
+    pub fn new(index: usize) -> Self {
+        InvalidPosition { index }
+    }
+}
+
+impl fmt::Display for InvalidPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The position of color stop {} is not a number.",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidPosition {}
@@ -0,0 +1,203 @@
+//! Gradients, which interpolate colors over the unit interval.
+//!
+//! A gradient is defined by color stops, each consisting of a position in `[0.0,1.0]` and a color.
+//! In between the stops, the colors are interpolated linearly with their [`Merge`] implementation.
+//! Before the first and after the last stop, the color of the nearest stop is used.
+//!
+//! ```rust
+//! # use enterpolation::{gradient::{Gradient, GradientError}, Generator, Curve, Point};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), GradientError> {
+//! let red = Point::new([1.0, 0.0, 0.0]);
+//! let blue = Point::new([0.0, 0.0, 1.0]);
+//! let gradient = Gradient::new([(0.2, red), (0.6, blue)])?;
+//! assert_eq!(gradient.domain(), [0.0, 1.0]);
+//! assert_eq!(gradient.gen(0.0), red);
+//! assert_f64_near!(gradient.gen(0.4)[0], 0.5);
+//! assert_f64_near!(gradient.gen(0.4)[2], 0.5);
+//! assert_eq!(gradient.gen(0.8), blue);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`Merge`]: crate::Merge
+
+use crate::linear::Linear;
+use crate::{Curve, Generator, Identity, Sorted};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+pub mod error;
+pub use error::{GradientError, InvalidPosition, NoStops};
+
+/// Gradient of colors over the domain `[0.0,1.0]`.
+///
+/// See [gradient module] for more information.
+///
+/// [gradient module]: self
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient<R, E> {
+    linear: Linear<Sorted<Vec<R>>, Vec<E>, Identity>,
+    repeating: bool,
+}
+
+impl<R, E> Gradient<R, E>
+where
+    R: Real,
+{
+    /// Create a gradient from color stops, given as pairs of positions and colors.
+    ///
+    /// The positions are normalized the same way as in CSS gradients: positions outside of `[0.0,1.0]`
+    /// are clamped and a position smaller than the one of a previous stop is raised to that one.
+    /// Such the order of the stops is kept and two stops at the same position create a hard edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoStops`] if no color stops were given
+    /// and [`InvalidPosition`] if a position is NaN.
+    pub fn new<I>(stops: I) -> Result<Self, GradientError>
+    where
+        I: IntoIterator<Item = (R, E)>,
+        E: Merge<R> + Copy,
+    {
+        let mut knots: Vec<R> = Vec::new();
+        let mut elements = Vec::new();
+        for (index, (position, color)) in stops.into_iter().enumerate() {
+            // NaN is not comparable at all
+            if position.partial_cmp(&R::zero()).is_none() {
+                return Err(InvalidPosition::new(index).into());
+            }
+            let position = position.max(R::zero()).min(R::one());
+            let position = match knots.last() {
+                Some(&previous) => position.max(previous),
+                None => position,
+            };
+            knots.push(position);
+            elements.push(color);
+        }
+        // a single stop is a gradient with only one color
+        match elements.first() {
+            None => return Err(NoStops::new().into()),
+            Some(&color) if elements.len() == 1 => {
+                knots.push(knots[0]);
+                elements.push(color);
+            }
+            Some(_) => (),
+        }
+        Ok(Gradient {
+            linear: Linear::new_unchecked(elements, Sorted::new_unchecked(knots), Identity::new()),
+            repeating: false,
+        })
+    }
+
+    /// Repeat the gradient outside of `[0.0,1.0]`.
+    ///
+    /// Such the input `1.5` generates the same color as `0.5`.
+    /// Without this option, inputs outside of the domain generate the color of the nearest end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{gradient::{Gradient, GradientError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), GradientError> {
+    /// let gradient = Gradient::new([(0.0, 0.0), (1.0, 4.0)])?.repeating();
+    /// assert_f64_near!(gradient.gen(1.25), 1.0);
+    /// assert_f64_near!(gradient.gen(-0.25), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn repeating(mut self) -> Self {
+        self.repeating = true;
+        self
+    }
+
+    /// Returns `true` if the gradient repeats outside of `[0.0,1.0]`.
+    pub fn is_repeating(&self) -> bool {
+        self.repeating
+    }
+}
+
+impl<R, E> Generator<R> for Gradient<R, E>
+where
+    E: Merge<R> + Copy + Debug,
+    R: Real + Debug,
+{
+    type Output = E;
+    fn gen(&self, input: R) -> E {
+        let input = if self.repeating {
+            input - input.floor()
+        } else {
+            input
+        };
+        let [start, end] = self.linear.domain();
+        self.linear.gen(input.max(start).min(end))
+    }
+}
+
+impl<R, E> Curve<R> for Gradient<R, E>
+where
+    E: Merge<R> + Copy + Debug,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [R::zero(), R::one()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stops() {
+        // unsorted and out of range positions get normalized
+        let gradient = Gradient::new([(-1.0, 0.0), (0.5, 2.0), (0.25, 4.0), (2.0, 6.0)]).unwrap();
+        let expect = [
+            (-0.5, 0.0),
+            (0.0, 0.0),
+            (0.25, 1.0),
+            (0.5, 4.0),
+            (0.75, 5.0),
+            (1.0, 6.0),
+            (2.0, 6.0),
+        ];
+        for (input, result) in expect {
+            assert_f64_near!(gradient.gen(input), result);
+        }
+        let single = Gradient::new([(0.3, 1.0)]).unwrap();
+        assert_f64_near!(single.gen(0.0), 1.0);
+        assert_f64_near!(single.gen(0.3), 1.0);
+        assert_f64_near!(single.gen(1.0), 1.0);
+        assert!(Gradient::<f64, f64>::new([]).is_err());
+        assert!(Gradient::new([(0.0, 1.0), (f64::NAN, 2.0)]).is_err());
+    }
+
+    #[test]
+    fn repeating() {
+        let gradient = Gradient::new([(0.0, 0.0), (0.5, 2.0), (1.0, 0.0)])
+            .unwrap()
+            .repeating();
+        assert!(gradient.is_repeating());
+        for (input, result) in [(0.25, 1.0), (1.25, 1.0), (-0.75, 1.0), (2.5, 2.0)] {
+            assert_f64_near!(gradient.gen(input), result);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let gradient = Gradient::new([(0.0, 0.0), (0.5, 2.0)]).unwrap().repeating();
+        let json = serde_json::to_string(&gradient).unwrap();
+        let restored: Gradient<f64, f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, gradient);
+        assert_f64_near!(restored.gen(1.25), 1.0);
+    }
+}
@@ -0,0 +1,390 @@
+//! Builder module for hermite splines.
+
+use super::error::HermiteError;
+use super::{Hermite, KnotElementInequality, TangentElementInequality, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, InvalidEquidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for hermite splines.
+///
+/// This struct helps create hermite splines. The difference between this struct and [`HermiteBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The tangents at each element with [`tangents()`].
+/// - The knots the spline uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// [`HermiteBuilder`]: HermiteBuilder
+/// [`build()`]: HermiteDirector::build()
+/// [`elements()`]: HermiteDirector::elements()
+/// [`tangents()`]: HermiteDirector::tangents()
+/// [`knots()`]: HermiteDirector::knots()
+/// [`equidistant()`]: HermiteDirector::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HermiteDirector<K, E, T> {
+    knots: K,
+    elements: E,
+    tangents: T,
+}
+
+/// Builder for hermite splines.
+///
+/// This struct helps create hermite splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should use with [`elements()`].
+/// - The tangents at each element with [`tangents()`].
+/// - The knots the spline uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// ```rust
+/// # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), HermiteError> {
+/// let hermite = Hermite::builder()
+///                 .elements([0.0,1.0])
+///                 .tangents([1.0,1.0])
+///                 .equidistant::<f64>()
+///                 .domain(0.0,1.0)
+///                 .build()?;
+/// assert_f64_near!(hermite.gen(0.5), 0.5);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: HermiteBuilder::build()
+/// [`builder()`]: super::Hermite::builder()
+/// [`elements()`]: HermiteBuilder::elements()
+/// [`tangents()`]: HermiteBuilder::tangents()
+/// [`knots()`]: HermiteBuilder::knots()
+/// [`equidistant()`]: HermiteBuilder::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct HermiteBuilder<K, E, T> {
+    inner: Result<HermiteDirector<K, E, T>, HermiteError>,
+}
+
+impl Default for HermiteDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        HermiteDirector::new()
+    }
+}
+
+impl Default for HermiteBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        HermiteBuilder::new()
+    }
+}
+
+impl HermiteDirector<Unknown, Unknown, Unknown> {
+    /// Create a new hermite spline builder.
+    pub const fn new() -> Self {
+        HermiteDirector {
+            knots: Unknown,
+            elements: Unknown,
+            tangents: Unknown,
+        }
+    }
+
+    /// Set the elements of the hermite spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::HermiteError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<HermiteDirector<Unknown, E, Unknown>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(HermiteDirector {
+            knots: self.knots,
+            elements,
+            tangents: self.tangents,
+        })
+    }
+}
+
+impl HermiteBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new hermite spline builder.
+    pub const fn new() -> Self {
+        HermiteBuilder {
+            inner: Ok(HermiteDirector::new()),
+        }
+    }
+
+    /// Set the elements of the hermite spline.
+    pub fn elements<E>(self, elements: E) -> HermiteBuilder<Unknown, E, Unknown>
+    where
+        E: DiscreteGenerator,
+    {
+        HermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> HermiteDirector<Unknown, E, Unknown>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the tangents of the hermite spline.
+    ///
+    /// The tangents are the derivatives of the curve at the corresponding knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TangentElementInequality`] if the number of tangents is not equal to the number of elements.
+    ///
+    /// [`TangentElementInequality`]: super::error::HermiteError
+    pub fn tangents<T>(
+        self,
+        tangents: T,
+    ) -> Result<HermiteDirector<Unknown, E, T>, TangentElementInequality>
+    where
+        T: DiscreteGenerator,
+    {
+        if self.elements.len() != tangents.len() {
+            return Err(TangentElementInequality::new(
+                self.elements.len(),
+                tangents.len(),
+            ));
+        }
+        Ok(HermiteDirector {
+            knots: self.knots,
+            elements: self.elements,
+            tangents,
+        })
+    }
+}
+
+impl<E> HermiteBuilder<Unknown, E, Unknown>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the tangents of the hermite spline.
+    ///
+    /// The tangents are the derivatives of the curve at the corresponding knots.
+    pub fn tangents<T>(self, tangents: T) -> HermiteBuilder<Unknown, E, T>
+    where
+        T: DiscreteGenerator,
+    {
+        HermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.tangents(tangents).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E, T> HermiteDirector<Unknown, E, T>
+where
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::HermiteError
+    /// [`NotSorted`]: super::error::HermiteError
+    pub fn knots<K>(self, knots: K) -> Result<HermiteDirector<Sorted<K>, E, T>, HermiteError>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(HermiteDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            tangents: self.tangents,
+        })
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: HermiteDirector::domain()
+    /// [`normalized()`]: HermiteDirector::normalized()
+    /// [`distance()`]: HermiteDirector::distance()
+    pub fn equidistant<R>(self) -> HermiteDirector<Type<R>, E, T> {
+        HermiteDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<E, T> HermiteBuilder<Unknown, E, T>
+where
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    pub fn knots<K>(self, knots: K) -> HermiteBuilder<Sorted<K>, E, T>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        HermiteBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build a spline with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the spline and the spacing of the knots.
+    ///
+    /// [`domain()`]: HermiteBuilder::domain()
+    /// [`normalized()`]: HermiteBuilder::normalized()
+    /// [`distance()`]: HermiteBuilder::distance()
+    pub fn equidistant<R>(self) -> HermiteBuilder<Type<R>, E, T> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E, T> HermiteDirector<Type<R>, E, T>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<HermiteDirector<Equidistant<R>, E, T>, InvalidEquidistant> {
+        Ok(HermiteDirector {
+            knots: Equidistant::try_new(self.elements.len(), start, end)?,
+            elements: self.elements,
+            tangents: self.tangents,
+        })
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> HermiteDirector<Equidistant<R>, E, T> {
+        HermiteDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> HermiteDirector<Equidistant<R>, E, T> {
+        HermiteDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            tangents: self.tangents,
+        }
+    }
+}
+
+impl<R, E, T> HermiteBuilder<Type<R>, E, T>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the spline.
+    pub fn domain(self, start: R, end: R) -> HermiteBuilder<Equidistant<R>, E, T> {
+        HermiteBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the domain of the spline to be [0.0,1.0].
+    pub fn normalized(self) -> HermiteBuilder<Equidistant<R>, E, T> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the spline by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> HermiteBuilder<Equidistant<R>, E, T> {
+        HermiteBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E, T> HermiteDirector<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Build a hermite spline.
+    pub fn build(self) -> Hermite<K, E, T> {
+        Hermite::new_unchecked(self.elements, self.tangents, self.knots)
+    }
+}
+
+impl<K, E, T> HermiteBuilder<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Build a hermite spline.
+    pub fn build(self) -> Result<Hermite<K, E, T>, HermiteError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Cubic hermite splines.
+//!
+//! The easist way to create a hermite spline is by using the builder pattern of [`HermiteBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{hermite::{Hermite, HermiteError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), HermiteError> {
+//! // the function x^3 and its derivative 3x^2
+//! let hermite = Hermite::builder()
+//!                 .elements([0.0,1.0,8.0])
+//!                 .tangents([0.0,3.0,12.0])
+//!                 .knots([0.0,1.0,2.0])
+//!                 .build()?;
+//! let results = [0.0,0.125,1.0,3.375,8.0];
+//! for (value,result) in hermite.take(5).zip(results.iter().copied()){
+//!     assert_f64_near!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Hermite splines are cubic splines which interpolate all their elements and whose derivative
+//! at each knot is given by the corresponding tangent. This is useful if the velocity at each
+//! element is known, for example from measured data.
+//!
+//! The tangents are derivatives with respect to the input of the curve. Such segments of
+//! different lengths join smoothly, as the tangents are scaled with the length of their segment.
+//!
+//! [`HermiteBuilder`]: HermiteBuilder
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::fmt::Debug;
+use core::ops::{Add, Mul};
+use num_traits::real::Real;
+use topology_traits::Merge;
+
+mod builder;
+pub use builder::{HermiteBuilder, HermiteDirector};
+
+pub mod error;
+pub use error::{
+    HermiteError, InvalidEquidistant, KnotElementInequality, NotSorted, TangentElementInequality,
+    TooFewElements,
+};
+
+/// Cubic hermite spline.
+///
+/// See the [hermite module] for more information.
+///
+/// [hermite module]: self
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Hermite<K, E, T> {
+    elements: E,
+    tangents: T,
+    knots: K,
+}
+
+impl Hermite<Unknown, Unknown, Unknown> {
+    /// Get the builder for a hermite spline.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - tangents with [`tangents()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    ///
+    /// [`elements()`]: HermiteBuilder::elements()
+    /// [`tangents()`]: HermiteBuilder::tangents()
+    /// [`knots()`]: HermiteBuilder::knots()
+    /// [`equidistant()`]: HermiteBuilder::equidistant()
+    pub fn builder() -> HermiteBuilder<Unknown, Unknown, Unknown> {
+        HermiteBuilder::new()
+    }
+}
+
+impl<K, E, T> Hermite<K, E, T>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+    T: DiscreteGenerator,
+{
+    /// Create a hermite spline with the given elements, tangents and knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than two elements are given.
+    /// Returns [`TangentElementInequality`] if the number of tangents is not equal to the number of elements.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`TooFewElements`]: HermiteError
+    /// [`TangentElementInequality`]: HermiteError
+    /// [`KnotElementInequality`]: HermiteError
+    pub fn new(elements: E, tangents: T, knots: K) -> Result<Self, HermiteError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if tangents.len() != elements.len() {
+            return Err(TangentElementInequality::new(elements.len(), tangents.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Hermite {
+            elements,
+            tangents,
+            knots,
+        })
+    }
+}
+
+impl<K, E, T> Hermite<K, E, T> {
+    /// Create a hermite spline with the given elements, tangents and knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be strictly increasing, there should be as many knots and tangents as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, tangents: T, knots: K) -> Self {
+        Hermite {
+            elements,
+            tangents,
+            knots,
+        }
+    }
+}
+
+impl<K, E, T, R> Generator<R> for Hermite<K, E, T>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Add<Output = E::Output>,
+    T: DiscreteGenerator,
+    T::Output: Mul<R, Output = E::Output>,
+    R: Real + Debug,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> E::Output {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        // tangents are derivatives with respect to the input, such they have to be scaled
+        // with the length of the segment to be used with the normalized factor
+        let length = self.knots.gen(max_index) - self.knots.gen(min_index);
+        let square = factor * factor;
+        let cube = square * factor;
+        let two = R::one() + R::one();
+        let three = two + R::one();
+        let h01 = three * square - two * cube;
+        let h10 = cube - two * square + factor;
+        let h11 = cube - square;
+        // h00 = 1 - h01, such the positions are blended with a single merge
+        self.elements
+            .gen(min_index)
+            .merge(self.elements.gen(max_index), h01)
+            + self.tangents.gen(min_index) * (h10 * length)
+            + self.tangents.gen(max_index) * (h11 * length)
+    }
+}
+
+impl<K, E, T, R> Curve<R> for Hermite<K, E, T>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Add<Output = E::Output>,
+    T: DiscreteGenerator,
+    T::Output: Mul<R, Output = E::Output>,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cubic() {
+        // hermite splines reproduce cubic polynomials, even with non-uniform knots
+        let hermite = Hermite::builder()
+            .elements([-1.0, 0.0, 1.0, 27.0])
+            .tangents([3.0, 0.0, 3.0, 27.0])
+            .knots([-1.0, 0.0, 1.0, 3.0])
+            .build()
+            .unwrap();
+        for i in 0..=16 {
+            let input = -1.0 + i as f64 * 0.25;
+            assert_f64_near!(hermite.gen(input), input * input * input, 16);
+        }
+        assert_eq!(hermite.domain(), [-1.0, 3.0]);
+    }
+
+    #[test]
+    fn equidistant() {
+        let hermite = Hermite::builder()
+            .elements([0.0, 1.0, 0.0])
+            .tangents([0.0, 0.0, 0.0])
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .unwrap();
+        let results = [0.0, 0.5, 1.0, 0.5, 0.0];
+        for (value, result) in hermite.take(5).zip(results) {
+            assert_f64_near!(value, result);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            Hermite::builder()
+                .elements([0.0])
+                .tangents([1.0])
+                .knots([0.0])
+                .build(),
+            Err(HermiteError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            Hermite::builder()
+                .elements([0.0, 1.0])
+                .tangents([1.0, 1.0, 1.0])
+                .knots([0.0, 1.0])
+                .build(),
+            Err(HermiteError::TangentElementInequality(_))
+        ));
+        assert!(matches!(
+            Hermite::builder()
+                .elements([0.0, 1.0])
+                .tangents([1.0, 1.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(HermiteError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            Hermite::builder()
+                .elements([0.0, 1.0])
+                .tangents([1.0, 1.0])
+                .knots([1.0, 0.0])
+                .build(),
+            Err(HermiteError::NotSorted(_))
+        ));
+    }
+}
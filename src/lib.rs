@@ -18,6 +18,8 @@
 
 #[macro_use]
 extern crate assert_float_eq;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(not(any(feature = "std", feature = "libm")))]
 compile_error!(
@@ -28,23 +30,48 @@ compile_error!(
 pub mod bezier;
 #[cfg(feature = "bspline")]
 pub mod bspline;
+#[cfg(feature = "catmull_rom")]
+pub mod catmull_rom;
 pub mod easing;
+pub mod geometry;
+#[cfg(all(feature = "linear", feature = "alloc"))]
+pub mod gradient;
+#[cfg(feature = "hermite")]
+pub mod hermite;
 #[cfg(feature = "linear")]
 pub mod linear;
+#[cfg(feature = "monotone_cubic")]
+pub mod monotone_cubic;
+#[cfg(feature = "natural_cubic")]
+pub mod natural_cubic;
+#[cfg(feature = "alloc")]
+pub mod piecewise;
+#[cfg(feature = "step")]
+pub mod step;
 pub mod utils;
 pub mod weights;
 
 mod base;
 mod builder;
+mod point;
+mod scalar;
 
 pub use topology_traits::Merge;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub use base::DynSpace;
 pub use base::{
-    Clamp, Composite, ConstDiscreteGenerator, ConstEquidistant, ConstSpace, Curve,
-    DiscreteGenerator, Equidistant, Extract, Generator, NotSorted, Repeat, Slice, Sorted,
-    SortedGenerator, Space, Stack, Stepper, TransformInput, Wrap,
+    Affine, CachedCurve, Chunks, Clamp, Cloned, Composite, Concat, ConstDiscreteGenerator,
+    ConstEquidistant, ConstEquidistantDomain, ConstSpace, Curve, DeadZone, DiscreteGenerator,
+    EasedInput, Equidistant, Extract, Extrapolation, FiniteDifference, Generator, Inspect,
+    InvalidDeadZone, InvalidEquidistant, Map, NotSorted, Offset, Projectable, QuantizeOutput,
+    Reflect, ReflectDomain, Repeat, RepeatN, Reversed, ReversedElements, Scale, Slice, Sorted,
+    SortedGenerator, Space, Stack, StackArray, Stepper, TakeWithTangent, TransformInput, Windows,
+    Wrap, WrapDomain, WrapMode, ZipWith,
 };
+#[cfg(feature = "std")]
+pub use base::{ArcLength, Cache, PooledSpace, PooledWorkspace};
 pub use easing::Identity;
+pub use point::Point;
+pub use scalar::Scalar;
 // pub use weights::{Homogeneous, Weighted, Weights, IntoWeight};
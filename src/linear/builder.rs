@@ -1,17 +1,32 @@
 //! Builder module for linear interpolations.
 
 use super::error::LinearError;
-use super::{KnotElementInequality, Linear, TooFewElements};
-use crate::builder::{Type, Unknown, WithWeight, WithoutWeight};
+#[cfg(feature = "alloc")]
+use super::NotSorted;
+use super::{
+    EasingSegmentInequality, KnotElementInequality, Linear, SegmentEasings, TooFewElements,
+};
+#[cfg(feature = "alloc")]
+use crate::builder::chordal_knots;
+use crate::builder::{validate_knots, Type, Unknown, WithWeight, WithoutWeight};
 use crate::weights::{IntoWeight, Weighted, Weights};
-use crate::{DiscreteGenerator, Equidistant, Generator, Identity, Sorted, SortedGenerator};
+use crate::{
+    DiscreteGenerator, Equidistant, Extrapolation, Generator, Identity, InvalidEquidistant, Scalar,
+    Sorted, SortedGenerator, Wrap,
+};
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
 use core::marker::PhantomData;
 use core::ops::Mul;
 use num_traits::identities::Zero;
 use num_traits::real::Real;
-use num_traits::FromPrimitive;
 use topology_traits::Merge;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Builder for linear interpolation.
 ///
 /// This struct helps create linear interpolations. The difference between this struct and [`LinearBuilder`]
@@ -21,7 +36,8 @@ use topology_traits::Merge;
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights()`]
 ///   exist for that cause.
 /// - The knots the interpolation uses. This can be seen as the spacing between those elements.
-///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///   Either by giving them directly with [`knots()`], by using equidistant knots with [`equidistant()`]
+///   or by deriving them from the distances between elements with [`chordal()`].
 ///
 /// ```rust
 /// # use enterpolation::{linear::{LinearDirector, LinearError}, Generator, Curve};
@@ -58,6 +74,7 @@ use topology_traits::Merge;
 /// [`elements_with_weights()`]: LinearDirector::elements_with_weights()
 /// [`knots()`]: LinearDirector::knots()
 /// [`equidistant()`]: LinearDirector::equidistant()
+/// [`chordal()`]: LinearDirector::chordal()
 /// [`equidistant_unchecked()`]: super::Linear::equidistant_unchecked()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -65,6 +82,7 @@ pub struct LinearDirector<K, E, F, W> {
     knots: K,
     elements: E,
     easing: F,
+    extrapolation: Extrapolation,
     _phantom: PhantomData<*const W>,
 }
 
@@ -77,7 +95,8 @@ pub struct LinearDirector<K, E, F, W> {
 /// - The elements the interpolation should use. Methods like [`elements()`] and [`elements_with_weights()`]
 ///   exist for that cause.
 /// - The knots the interpolation uses. This can be seen as the spacing between those elements.
-///   Either by giving them directly with [`knots()`] or by using equidistant knots with [`equidistant()`].
+///   Either by giving them directly with [`knots()`], by using equidistant knots with [`equidistant()`]
+///   or by deriving them from the distances between elements with [`chordal()`].
 ///
 /// ```rust
 /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
@@ -114,6 +133,7 @@ pub struct LinearDirector<K, E, F, W> {
 /// [`elements_with_weights()`]: LinearBuilder::elements_with_weights()
 /// [`knots()`]: LinearBuilder::knots()
 /// [`equidistant()`]: LinearBuilder::equidistant()
+/// [`chordal()`]: LinearBuilder::chordal()
 /// [`equidistant_unchecked()`]: super::Linear::equidistant_unchecked()
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -140,6 +160,7 @@ impl LinearDirector<Unknown, Unknown, Identity, Unknown> {
             knots: Unknown,
             elements: Unknown,
             easing: Identity::new(),
+            extrapolation: Extrapolation::Extend,
             _phantom: PhantomData,
         }
     }
@@ -176,10 +197,30 @@ impl<F> LinearDirector<Unknown, Unknown, F, Unknown> {
             knots: self.knots,
             elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: PhantomData,
         })
     }
 
+    /// Set the elements of the linear interpolation by collecting the given iterator into a vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::LinearError
+    #[cfg(feature = "alloc")]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> Result<ElementsDirector<I::Item, F>, TooFewElements>
+    where
+        I: IntoIterator,
+        I::Item: Copy,
+    {
+        self.elements(iter.into_iter().collect::<Vec<_>>())
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -234,9 +275,107 @@ impl<F> LinearDirector<Unknown, Unknown, F, Unknown> {
             knots: self.knots,
             elements: Weights::new(gen),
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: PhantomData,
         })
     }
+
+    /// Set the elements and already sorted knots of the linear interpolation.
+    ///
+    /// In contrast to [`knots()`], the knots are not checked for being sorted.
+    /// Instead the type of the knots has to guarantee this by implementing [`SortedGenerator`].
+    /// This is useful if the knots were already sorted elsewhere, for example with [`Sorted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`knots()`]: LinearDirector::knots()
+    /// [`SortedGenerator`]: crate::SortedGenerator
+    /// [`Sorted`]: crate::Sorted
+    /// [`TooFewElements`]: super::error::LinearError
+    /// [`KnotElementInequality`]: super::error::LinearError
+    pub fn from_sorted<E, K>(
+        self,
+        elements: E,
+        knots: K,
+    ) -> Result<LinearDirector<K, E, F, WithoutWeight>, LinearError>
+    where
+        E: DiscreteGenerator,
+        K: SortedGenerator,
+    {
+        let director = self.elements(elements)?;
+        if director.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(director.elements.len(), knots.len()).into());
+        }
+        Ok(LinearDirector {
+            knots,
+            elements: director.elements,
+            easing: director.easing,
+            extrapolation: director.extrapolation,
+            _phantom: director._phantom,
+        })
+    }
+
+    /// Set the knots and elements of the linear interpolation with pairs of a knot and its element.
+    ///
+    /// This is useful if the data is given as a table of `(x, y)` values.
+    /// Arrays, slices and vectors of pairs can be given, as well as any other iterator over pairs.
+    /// The knots have to be strictly increasing. Use [`points_with_duplicates()`] to allow equal knots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 pairs are given.
+    /// Returns [`NotSorted`] if the knots are not sorted.
+    ///
+    /// [`points_with_duplicates()`]: LinearDirector::points_with_duplicates()
+    /// [`TooFewElements`]: super::error::LinearError
+    /// [`NotSorted`]: super::error::LinearError
+    #[cfg(feature = "alloc")]
+    pub fn points<I, P, R, T>(self, points: I) -> Result<PointsDirector<R, T, F>, LinearError>
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<(R, T)>,
+        R: PartialOrd + Copy,
+        T: Copy,
+    {
+        let (knots, elements) = split_points(points);
+        if let Some(index) =
+            (1..knots.len()).find(|&i| knots[i - 1].partial_cmp(&knots[i]) != Some(Ordering::Less))
+        {
+            return Err(NotSorted::new(index).into());
+        }
+        self.from_sorted(elements, Sorted::new_unchecked(knots))
+    }
+
+    /// Set the knots and elements of the linear interpolation with pairs of a knot and its element.
+    ///
+    /// In contrast to [`points()`], the knots only have to be increasing, such that equal knots are allowed.
+    /// At such knots, the interpolation jumps from one element to the next.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 pairs are given.
+    /// Returns [`NotSorted`] if the knots are not sorted.
+    ///
+    /// [`points()`]: LinearDirector::points()
+    /// [`TooFewElements`]: super::error::LinearError
+    /// [`NotSorted`]: super::error::LinearError
+    #[cfg(feature = "alloc")]
+    pub fn points_with_duplicates<I, P, R, T>(
+        self,
+        points: I,
+    ) -> Result<PointsDirector<R, T, F>, LinearError>
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<(R, T)>,
+        R: PartialOrd + Copy,
+        T: Copy,
+    {
+        let (knots, elements) = split_points(points);
+        self.from_sorted(elements, Sorted::new(knots)?)
+    }
 }
 
 impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
@@ -252,6 +391,44 @@ impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
         }
     }
 
+    /// Set the elements of the linear interpolation by collecting the given iterator into a vector.
+    ///
+    /// This allows to pass the result of iterator adaptors directly to the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let data = "1.0 3.0 invalid 5.0";
+    /// let linear = Linear::builder()
+    ///                 .elements_from_iter(data.split(' ').filter_map(|word| word.parse::<f64>().ok()))
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(0.75), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn elements_from_iter<I>(
+        self,
+        iter: I,
+    ) -> LinearBuilder<Unknown, Vec<I::Item>, F, WithoutWeight>
+    where
+        I: IntoIterator,
+        I::Item: Copy,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements_from_iter(iter).map_err(|err| err.into())),
+        }
+    }
+
     /// Set the elements and their weights for this interpolation.
     ///
     /// Weights of `Zero` can achieve unwanted results as their corresponding elements are considered
@@ -301,6 +478,109 @@ impl<F> LinearBuilder<Unknown, Unknown, F, Unknown> {
             }),
         }
     }
+
+    /// Set the elements and already sorted knots of the linear interpolation.
+    ///
+    /// In contrast to [`knots()`], the knots are not checked for being sorted.
+    /// Instead the type of the knots has to guarantee this by implementing [`SortedGenerator`].
+    /// This is useful if the knots were already sorted elsewhere, for example with [`Sorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Sorted};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let knots = Sorted::new([0.0,1.0,3.0])?;
+    /// let linear = Linear::builder()
+    ///                 .from_sorted([1.0,5.0,3.0], knots)
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(2.0), 4.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`knots()`]: LinearBuilder::knots()
+    /// [`SortedGenerator`]: crate::SortedGenerator
+    /// [`Sorted`]: crate::Sorted
+    pub fn from_sorted<E, K>(self, elements: E, knots: K) -> LinearBuilder<K, E, F, WithoutWeight>
+    where
+        E: DiscreteGenerator,
+        K: SortedGenerator,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.from_sorted(elements, knots)),
+        }
+    }
+
+    /// Set the knots and elements of the linear interpolation with pairs of a knot and its element.
+    ///
+    /// This is useful if the data is given as a table of `(x, y)` values.
+    /// Arrays, slices and vectors of pairs can be given, as well as any other iterator over pairs.
+    /// The knots have to be strictly increasing. Use [`points_with_duplicates()`] to allow equal knots.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .points([(0.0,1.0),(1.0,3.0),(3.0,0.0)])
+    ///                 .build()?;
+    /// assert_eq!(linear.domain(), [0.0,3.0]);
+    /// assert_f64_near!(linear.gen(0.5), 2.0);
+    /// assert_f64_near!(linear.gen(2.0), 1.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`points_with_duplicates()`]: LinearBuilder::points_with_duplicates()
+    #[cfg(feature = "alloc")]
+    pub fn points<I, P, R, T>(
+        self,
+        points: I,
+    ) -> LinearBuilder<Sorted<Vec<R>>, Vec<T>, F, WithoutWeight>
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<(R, T)>,
+        R: PartialOrd + Copy,
+        T: Copy,
+    {
+        LinearBuilder {
+            inner: self.inner.and_then(|director| director.points(points)),
+        }
+    }
+
+    /// Set the knots and elements of the linear interpolation with pairs of a knot and its element.
+    ///
+    /// In contrast to [`points()`], the knots only have to be increasing, such that equal knots are allowed.
+    /// At such knots, the interpolation jumps from one element to the next.
+    ///
+    /// [`points()`]: LinearBuilder::points()
+    #[cfg(feature = "alloc")]
+    pub fn points_with_duplicates<I, P, R, T>(
+        self,
+        points: I,
+    ) -> LinearBuilder<Sorted<Vec<R>>, Vec<T>, F, WithoutWeight>
+    where
+        I: IntoIterator<Item = P>,
+        P: Borrow<(R, T)>,
+        R: PartialOrd + Copy,
+        T: Copy,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.points_with_duplicates(points)),
+        }
+    }
 }
 
 impl<E, F, W> LinearDirector<Unknown, E, F, W> {
@@ -335,6 +615,7 @@ impl<E, F, W> LinearDirector<Unknown, E, F, W> {
             knots: Sorted::new(knots)?,
             elements: self.elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
         })
     }
@@ -364,6 +645,32 @@ impl<E, F, W> LinearDirector<Unknown, E, F, W> {
             knots: Type::new(),
             elements: self.elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
+            _phantom: self._phantom,
+        }
+    }
+
+    /// Derive the knots from the distances between the elements.
+    ///
+    /// The first knot is zero and the distance between two knots is given by
+    /// `distance(first, second).abs().powf(alpha)`. An `alpha` of 0.5 results in a centripetal,
+    /// an `alpha` of 1.0 in a chordal parameterization.
+    /// The closure should return the distance between the given elements, for example the euclidean distance.
+    /// As the absolute value of the distance is used, the closure may also return signed differences like `b - a`.
+    ///
+    /// Consecutive elements should not be equal, as otherwise the interpolation may generate NaN.
+    #[cfg(feature = "alloc")]
+    pub fn chordal<R, D>(self, alpha: R, distance: D) -> LinearDirector<Sorted<Vec<R>>, E, F, W>
+    where
+        E: DiscreteGenerator,
+        D: Fn(E::Output, E::Output) -> R,
+        R: Real,
+    {
+        LinearDirector {
+            knots: chordal_knots(&self.elements, alpha, distance),
+            elements: self.elements,
+            easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
         }
     }
@@ -416,21 +723,139 @@ impl<E, F, W> LinearBuilder<Unknown, E, F, W> {
             inner: self.inner.map(|director| director.equidistant()),
         }
     }
+
+    /// Derive the knots from the distances between the elements.
+    ///
+    /// The first knot is zero and the distance between two knots is given by
+    /// `distance(first, second).abs().powf(alpha)`. An `alpha` of 0.5 results in a centripetal,
+    /// an `alpha` of 1.0 in a chordal parameterization.
+    /// The closure should return the distance between the given elements, for example the euclidean distance.
+    /// As the absolute value of the distance is used, the closure may also return signed differences like `b - a`.
+    ///
+    /// Consecutive elements should not be equal, as otherwise the interpolation may generate NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,1.0,5.0])
+    ///                 .chordal(1.0, |a: f64, b: f64| b - a)
+    ///                 .build()?;
+    /// assert_eq!(linear.domain(), [0.0,5.0]);
+    /// assert_f64_near!(linear.gen(3.0), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn chordal<R, D>(self, alpha: R, distance: D) -> LinearBuilder<Sorted<Vec<R>>, E, F, W>
+    where
+        E: DiscreteGenerator,
+        D: Fn(E::Output, E::Output) -> R,
+        R: Real,
+    {
+        LinearBuilder {
+            inner: self.inner.map(|director| director.chordal(alpha, distance)),
+        }
+    }
+}
+
+impl<E, F> LinearDirector<Unknown, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+{
+    /// Close the interpolation such that it loops back to its first element.
+    ///
+    /// A copy of the first element is appended to the elements. As such the interpolation blends the
+    /// last element back to the first and its domain covers a whole period.
+    /// This is useful for cyclic interpolations like color wheels.
+    ///
+    /// If knots are given afterwards with [`knots()`], one knot more than the number of original elements
+    /// is needed, where the last knot marks the end of the period.
+    ///
+    /// [`knots()`]: LinearDirector::knots()
+    pub fn closed(self) -> LinearDirector<Unknown, Wrap<E>, F, WithoutWeight> {
+        LinearDirector {
+            knots: self.knots,
+            elements: Wrap::new(self.elements, 1),
+            easing: self.easing,
+            extrapolation: self.extrapolation,
+            _phantom: self._phantom,
+        }
+    }
+}
+
+impl<E, F> LinearBuilder<Unknown, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+{
+    /// Close the interpolation such that it loops back to its first element.
+    ///
+    /// A copy of the first element is appended to the elements. As such the interpolation blends the
+    /// last element back to the first and its domain covers a whole period.
+    /// This is useful for cyclic interpolations like color wheels.
+    ///
+    /// If knots are given afterwards with [`knots()`], one knot more than the number of original elements
+    /// is needed, where the last knot marks the end of the period.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator, Curve};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,3.0,6.0])
+    ///                 .closed()
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .build()?;
+    /// let results = [0.0,1.5,3.0,4.5,6.0,3.0,0.0];
+    /// for (value,result) in linear.take(7).zip(results.iter().copied()){
+    ///     assert_f64_near!(value, result);
+    /// }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`knots()`]: LinearBuilder::knots()
+    pub fn closed(self) -> LinearBuilder<Unknown, Wrap<E>, F, WithoutWeight> {
+        LinearBuilder {
+            inner: self.inner.map(|director| director.closed()),
+        }
+    }
 }
 
 impl<R, E, F, W> LinearDirector<Type<R>, E, F, W>
 where
     E: DiscreteGenerator,
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     /// Set the domain of the interpolation.
-    pub fn domain(self, start: R, end: R) -> LinearDirector<Equidistant<R>, E, F, W> {
-        LinearDirector {
-            knots: Equidistant::new(self.elements.len(), start, end),
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<LinearDirector<Equidistant<R>, E, F, W>, InvalidEquidistant> {
+        Ok(LinearDirector {
+            knots: Equidistant::try_new(self.elements.len(), start, end)?,
             elements: self.elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
-        }
+        })
     }
 
     /// Set the domain of the interpolation to be [0.0,1.0].
@@ -439,6 +864,7 @@ where
             knots: Equidistant::normalized(self.elements.len()),
             elements: self.elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
         }
     }
@@ -448,6 +874,7 @@ where
             knots: Equidistant::step(self.elements.len(), start, step),
             elements: self.elements,
             easing: self.easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
         }
     }
@@ -456,12 +883,14 @@ where
 impl<R, E, F, W> LinearBuilder<Type<R>, E, F, W>
 where
     E: DiscreteGenerator,
-    R: Real + FromPrimitive,
+    R: Scalar,
 {
     /// Set the domain of the interpolation.
     pub fn domain(self, start: R, end: R) -> LinearBuilder<Equidistant<R>, E, F, W> {
         LinearBuilder {
-            inner: self.inner.map(|director| director.domain(start, end)),
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
         }
     }
 
@@ -487,20 +916,77 @@ where
     ///
     /// This allows quasi-linear interpolations. Before merging two elements together with a factor,
     /// the factor is send to the given function before and the output is the new factor.
+    /// Any [`Easing`] can be used, including closures.
     ///
     /// # Examples
     ///
     /// See the [plateau example] for more information.
     ///
     /// [plateau example]: https://github.com/NicolasKlenert/enterpolation/blob/main/examples/plateaus.rs
+    /// [`Easing`]: crate::easing::Easing
     pub fn easing<FF>(self, easing: FF) -> LinearDirector<K, E, FF, W> {
         LinearDirector {
             knots: self.knots,
             elements: self.elements,
             easing,
+            extrapolation: self.extrapolation,
             _phantom: self._phantom,
         }
     }
+
+    /// Sets an easing function for each segment.
+    ///
+    /// Like [`easing()`], but the factor of segment `i` is send to the `i`-th easing of the given generator.
+    /// This allows different transitions between each pair of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EasingSegmentInequality`] if the number of easings is not the number of knots - 1.
+    ///
+    /// [`easing()`]: LinearDirector::easing()
+    /// [`EasingSegmentInequality`]: super::error::LinearError
+    pub fn segment_easings<G>(
+        self,
+        easings: G,
+    ) -> Result<LinearDirector<K, E, SegmentEasings<G>, W>, EasingSegmentInequality>
+    where
+        G: DiscreteGenerator,
+    {
+        let segments = self.knots.len() - 1;
+        if easings.len() != segments {
+            return Err(EasingSegmentInequality::new(easings.len(), segments));
+        }
+        Ok(self.easing(SegmentEasings::new(easings)))
+    }
+
+    /// Sets the policy on how inputs outside of the domain are handled.
+    ///
+    /// Per default, the first and last segment are extended ([`Extrapolation::Extend`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Extrapolation, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .extrapolation(Extrapolation::Clamp)
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(-1.0), 0.0);
+    /// assert_f64_near!(linear.gen(5.0), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extrapolation(self, extrapolation: Extrapolation) -> Self {
+        LinearDirector {
+            extrapolation,
+            ..self
+        }
+    }
 }
 
 impl<K, E, F, W> LinearBuilder<K, E, F, W>
@@ -511,17 +997,111 @@ where
     ///
     /// This allows quasi-linear interpolations. Before merging two elements together with a factor,
     /// the factor is send to the given function before and the output is the new factor.
+    /// Any [`Easing`] can be used, including closures.
     ///
     /// # Examples
     ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, easing::Easing, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let square = |x: f64| x * x;
+    /// let ease_in = Linear::builder()
+    ///                 .elements([0.0,4.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .easing(square)
+    ///                 .build()?;
+    /// assert_f64_near!(ease_in.gen(0.5), 1.0);
+    /// let ease_out = Linear::builder()
+    ///                 .elements([0.0,4.0])
+    ///                 .knots([0.0,1.0])
+    ///                 .easing(square.invert())
+    ///                 .build()?;
+    /// assert_f64_near!(ease_out.gen(0.5), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
     /// See the [plateau example] for more information.
     ///
     /// [plateau example]: https://github.com/NicolasKlenert/enterpolation/blob/main/examples/plateaus.rs
+    /// [`Easing`]: crate::easing::Easing
     pub fn easing<FF>(self, easing: FF) -> LinearBuilder<K, E, FF, W> {
         LinearBuilder {
             inner: self.inner.map(|director| director.easing(easing)),
         }
     }
+
+    /// Sets an easing function for each segment.
+    ///
+    /// Like [`easing()`], but the factor of segment `i` is send to the `i`-th easing of the given generator.
+    /// This allows different transitions between each pair of elements.
+    /// The number of easings has to be the number of knots - 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, easing::Plateau, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,2.0])
+    ///                 .segment_easings([Plateau::new(0.5), Plateau::new(0.0)])
+    ///                 .build()?;
+    /// // the first segment holds its elements for a while
+    /// assert_f64_near!(linear.gen(0.2), 0.0);
+    /// // the second segment does not
+    /// assert_f64_near!(linear.gen(1.2), 4.792);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`easing()`]: LinearBuilder::easing()
+    pub fn segment_easings<G>(self, easings: G) -> LinearBuilder<K, E, SegmentEasings<G>, W>
+    where
+        G: DiscreteGenerator,
+    {
+        LinearBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.segment_easings(easings).map_err(|err| err.into())),
+        }
+    }
+
+    /// Sets the policy on how inputs outside of the domain are handled.
+    ///
+    /// Per default, the first and last segment are extended ([`Extrapolation::Extend`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Extrapolation, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .extrapolation(Extrapolation::Clamp)
+    ///                 .build()?;
+    /// assert_f64_near!(linear.gen(-1.0), 0.0);
+    /// assert_f64_near!(linear.gen(5.0), 3.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extrapolation(self, extrapolation: Extrapolation) -> Self {
+        LinearBuilder {
+            inner: self
+                .inner
+                .map(|director| director.extrapolation(extrapolation)),
+        }
+    }
 }
 
 impl<K, E, F> LinearDirector<K, E, F, WithoutWeight>
@@ -529,20 +1109,49 @@ where
     E: DiscreteGenerator,
     K: SortedGenerator,
     E::Output: Merge<K::Output>,
-    K::Output: Real,
+    K::Output: Scalar,
 {
     /// Build a linear interpolation.
     pub fn build(self) -> Linear<K, E, F> {
-        Linear::new_unchecked(self.elements, self.knots, self.easing)
+        Linear {
+            extrapolation: self.extrapolation,
+            ..Linear::new_unchecked(self.elements, self.knots, self.easing)
+        }
     }
 }
 
-impl<K, E, F> LinearBuilder<K, E, F, WithoutWeight>
+impl<K, E, F> LinearDirector<K, E, F, WithoutWeight>
 where
     E: DiscreteGenerator,
     K: SortedGenerator,
     E::Output: Merge<K::Output>,
     K::Output: Real,
+{
+    /// Build a linear interpolation and check that its knots are numerically sane.
+    ///
+    /// See [`LinearBuilder::build_validated()`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// [`NonFiniteKnot`] if any knot is NaN or infinite.
+    /// [`DegenerateDomain`] if all knots are equal.
+    ///
+    /// [`NonFiniteKnot`]: super::LinearError
+    /// [`DegenerateDomain`]: super::LinearError
+    pub fn build_validated(self) -> Result<Linear<K, E, F>, LinearError> {
+        let linear = self.build();
+        let domain = [linear.knots.first().unwrap(), linear.knots.last().unwrap()];
+        validate_knots::<_, LinearError>(&linear.knots, domain)?;
+        Ok(linear)
+    }
+}
+
+impl<K, E, F> LinearBuilder<K, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator,
+    E::Output: Merge<K::Output>,
+    K::Output: Scalar,
 {
     /// Build a linear interpolation.
     pub fn build(self) -> Result<Linear<K, E, F>, LinearError> {
@@ -553,6 +1162,52 @@ where
     }
 }
 
+impl<K, E, F> LinearBuilder<K, E, F, WithoutWeight>
+where
+    E: DiscreteGenerator,
+    K: SortedGenerator,
+    E::Output: Merge<K::Output>,
+    K::Output: Real,
+{
+    /// Build a linear interpolation and check that its knots are numerically sane.
+    ///
+    /// [`build()`] only checks the structure of the given data, like the number of elements and knots.
+    /// This method additionally checks that all knots are finite and not all equal,
+    /// such that the domain does not collapse to a single point.
+    /// Equal adjacent knots are still allowed, as they create a jump in the interpolation.
+    /// This is useful if the knots come from an external source.
+    ///
+    /// # Errors
+    ///
+    /// All errors of [`build()`].
+    /// [`NonFiniteKnot`] if any knot is NaN or infinite.
+    /// [`DegenerateDomain`] if all knots are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::linear::{Linear, LinearError};
+    /// let result = Linear::builder()
+    ///                 .elements([0.0,1.0])
+    ///                 .equidistant::<f64>()
+    ///                 .domain(1.0,1.0)
+    ///                 .build_validated();
+    /// assert!(matches!(result, Err(LinearError::DegenerateDomain(_))));
+    /// let result = Linear::builder()
+    ///                 .elements([0.0,1.0])
+    ///                 .knots([0.0,f64::INFINITY])
+    ///                 .build_validated();
+    /// assert!(matches!(result, Err(LinearError::NonFiniteKnot(_))));
+    /// ```
+    ///
+    /// [`build()`]: LinearBuilder::build()
+    /// [`NonFiniteKnot`]: super::LinearError
+    /// [`DegenerateDomain`]: super::LinearError
+    pub fn build_validated(self) -> Result<Linear<K, E, F>, LinearError> {
+        self.inner.and_then(|director| director.build_validated())
+    }
+}
+
 impl<K, G, F> LinearDirector<K, Weights<G>, F, WithWeight>
 where
     K: SortedGenerator,
@@ -563,11 +1218,10 @@ where
 {
     /// Build a weighted linear interpolation.
     pub fn build(self) -> WeightedLinear<K, G, F> {
-        Weighted::new(Linear::new_unchecked(
-            self.elements,
-            self.knots,
-            self.easing,
-        ))
+        Weighted::new(Linear {
+            extrapolation: self.extrapolation,
+            ..Linear::new_unchecked(self.elements, self.knots, self.easing)
+        })
     }
 }
 
@@ -590,12 +1244,30 @@ where
 
 /// Type alias for weighted linear interpolations
 type WeightedLinear<K, G, F> = Weighted<Linear<K, Weights<G>, F>>;
+#[cfg(feature = "alloc")]
+type ElementsDirector<T, F> = LinearDirector<Unknown, Vec<T>, F, WithoutWeight>;
+#[cfg(feature = "alloc")]
+type PointsDirector<R, T, F> = LinearDirector<Sorted<Vec<R>>, Vec<T>, F, WithoutWeight>;
+
+/// Split pairs of knots and elements into a vector of knots and a vector of elements.
+#[cfg(feature = "alloc")]
+fn split_points<I, P, R, T>(points: I) -> (Vec<R>, Vec<T>)
+where
+    I: IntoIterator<Item = P>,
+    P: Borrow<(R, T)>,
+    R: Copy,
+    T: Copy,
+{
+    points.into_iter().map(|point| *point.borrow()).unzip()
+}
 
 #[cfg(test)]
 mod test {
     use super::LinearBuilder;
     // Homogeneous for creating Homogeneous, Generator for using .stack()
-    use crate::{linear::LinearDirector, weights::Homogeneous, Generator};
+    use crate::{
+        linear::LinearDirector, weights::Homogeneous, Curve, Equidistant, Generator, Sorted,
+    };
     #[test]
     fn building_weights() {
         LinearBuilder::new()
@@ -644,6 +1316,18 @@ mod test {
             .knots([1.0, 2.0, 3.0])
             .build()
             .is_err());
+        assert!(LinearBuilder::new()
+            .elements([1.0, 2.0])
+            .equidistant::<f64>()
+            .domain(0.0, f64::NAN)
+            .build()
+            .is_err());
+        assert!(LinearBuilder::new()
+            .elements([1.0, 2.0])
+            .equidistant::<f64>()
+            .domain(f64::NEG_INFINITY, 0.0)
+            .build()
+            .is_err());
     }
 
     #[test]
@@ -665,4 +1349,121 @@ mod test {
             .knots([1.0, 2.0])
             .is_ok());
     }
+
+    #[test]
+    fn from_sorted() {
+        let knots = Sorted::new_unchecked([0.0, 1.0, 3.0]);
+        let linear = LinearBuilder::new()
+            .from_sorted([1.0, 5.0, 3.0], knots)
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.5), 3.0);
+        assert_f64_near!(linear.gen(2.0), 4.0);
+        assert!(LinearBuilder::new()
+            .from_sorted([1.0, 5.0], knots)
+            .build()
+            .is_err());
+        assert!(LinearBuilder::new()
+            .from_sorted([1.0], Sorted::new_unchecked([0.0]))
+            .build()
+            .is_err());
+        assert!(LinearDirector::new()
+            .from_sorted([1.0, 2.0, 3.0], Equidistant::<f64>::normalized(3))
+            .is_ok());
+    }
+
+    #[test]
+    fn closed() {
+        let linear = LinearBuilder::new()
+            .elements([1.0, 5.0, 3.0])
+            .closed()
+            .knots([0.0, 1.0, 3.0, 4.0])
+            .build()
+            .unwrap();
+        let [start, end] = linear.domain();
+        assert_f64_near!(start, 0.0);
+        assert_f64_near!(end, 4.0);
+        assert_f64_near!(linear.gen(end), linear.gen(start));
+        assert_f64_near!(linear.gen(3.5), 2.0);
+        assert!(LinearBuilder::new()
+            .elements([1.0, 5.0, 3.0])
+            .closed()
+            .knots([0.0, 1.0, 3.0])
+            .build()
+            .is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn chordal() {
+        use crate::Point;
+        let linear = LinearBuilder::new()
+            .elements([
+                Point::new([0.0, 0.0]),
+                Point::new([3.0, 4.0]),
+                Point::new([3.0, 5.0]),
+            ])
+            .chordal(0.5, |a: Point<f64, 2>, b: Point<f64, 2>| {
+                ((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt()
+            })
+            .build()
+            .unwrap();
+        let [start, end] = linear.domain();
+        assert_f64_near!(start, 0.0);
+        assert_f64_near!(end, 5.0_f64.sqrt() + 1.0);
+        let point = linear.gen(5.0_f64.sqrt() + 0.5);
+        assert_f64_near!(point[0], 3.0);
+        assert_f64_near!(point[1], 4.5);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn points() {
+        use alloc::vec;
+        let table = vec![(0.0, 1.0), (1.0, 3.0), (3.0, 0.0)];
+        let linear = LinearBuilder::new().points(&table).build().unwrap();
+        assert_f64_near!(linear.gen(2.0), 1.5);
+        let linear = LinearBuilder::new()
+            .points(table.as_slice())
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.5), 2.0);
+        let linear = LinearBuilder::new().points(table).build().unwrap();
+        assert_eq!(linear.domain(), [0.0, 3.0]);
+        assert!(LinearBuilder::new()
+            .points([(0.0, 1.0), (1.0, 3.0), (1.0, 0.0)])
+            .build()
+            .is_err());
+        assert!(LinearBuilder::new()
+            .points([(0.0, 1.0), (2.0, 3.0), (1.0, 0.0)])
+            .build()
+            .is_err());
+        assert!(LinearBuilder::new().points([(0.0, 1.0)]).build().is_err());
+        let linear = LinearBuilder::new()
+            .points_with_duplicates([(0.0, 1.0), (1.0, 3.0), (1.0, 0.0), (2.0, 1.0)])
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.5), 2.0);
+        assert_f64_near!(linear.gen(1.5), 0.5);
+        assert!(LinearDirector::new()
+            .points_with_duplicates([(0.0, 1.0), (2.0, 3.0), (1.0, 0.0)])
+            .is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn elements_from_iter() {
+        let linear = LinearBuilder::new()
+            .elements_from_iter((0..3).map(|i| i as f64 * 2.0))
+            .knots([0.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(1.5), 3.0);
+        assert!(LinearBuilder::new()
+            .elements_from_iter(core::iter::once(1.0))
+            .equidistant::<f64>()
+            .normalized()
+            .build()
+            .is_err());
+    }
 }
@@ -1,7 +1,7 @@
 //! All error types for linear interpolation.
 
-pub use crate::builder::TooFewElements;
-pub use crate::NotSorted;
+pub use crate::builder::{DegenerateDomain, NonFiniteKnot, TooFewElements};
+pub use crate::{InvalidEquidistant, NotSorted};
 use core::{convert::From, fmt};
 
 #[cfg(feature = "std")]
@@ -17,6 +17,14 @@ pub enum LinearError {
     KnotElementInequality(KnotElementInequality),
     /// Error returned if knots are not sorted.
     NotSorted(NotSorted),
+    /// Error returned if equidistant knots could not be created.
+    InvalidEquidistant(InvalidEquidistant),
+    /// Error returned if the number of easings and segments are not equal.
+    EasingSegmentInequality(EasingSegmentInequality),
+    /// Error returned if a knot is NaN or infinite.
+    NonFiniteKnot(NonFiniteKnot),
+    /// Error returned if the domain collapsed to a single point.
+    DegenerateDomain(DegenerateDomain),
 }
 
 impl fmt::Display for LinearError {
@@ -24,7 +32,11 @@ impl fmt::Display for LinearError {
         match self {
             LinearError::TooFewElements(inner) => inner.fmt(f),
             LinearError::NotSorted(inner) => inner.fmt(f),
+            LinearError::InvalidEquidistant(inner) => inner.fmt(f),
             LinearError::KnotElementInequality(inner) => inner.fmt(f),
+            LinearError::EasingSegmentInequality(inner) => inner.fmt(f),
+            LinearError::NonFiniteKnot(inner) => inner.fmt(f),
+            LinearError::DegenerateDomain(inner) => inner.fmt(f),
         }
     }
 }
@@ -47,6 +59,30 @@ impl From<NotSorted> for LinearError {
     }
 }
 
+impl From<InvalidEquidistant> for LinearError {
+    fn from(from: InvalidEquidistant) -> Self {
+        LinearError::InvalidEquidistant(from)
+    }
+}
+
+impl From<EasingSegmentInequality> for LinearError {
+    fn from(from: EasingSegmentInequality) -> Self {
+        LinearError::EasingSegmentInequality(from)
+    }
+}
+
+impl From<NonFiniteKnot> for LinearError {
+    fn from(from: NonFiniteKnot) -> Self {
+        LinearError::NonFiniteKnot(from)
+    }
+}
+
+impl From<DegenerateDomain> for LinearError {
+    fn from(from: DegenerateDomain) -> Self {
+        LinearError::DegenerateDomain(from)
+    }
+}
+
 #[cfg(feature = "std")]
 impl Error for LinearError {}
 
@@ -79,3 +115,33 @@ impl KnotElementInequality {
         KnotElementInequality { elements, knots }
     }
 }
+
+/// Error returned if the number of easings and the number of segments are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct EasingSegmentInequality {
+    /// The number of easings found.
+    easings: usize,
+    /// The number of segments found.
+    segments: usize,
+}
+
+impl fmt::Display for EasingSegmentInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be an easing for each segment, however we found {} easings and {} segments.",
+            self.easings, self.segments
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EasingSegmentInequality {}
+
+impl EasingSegmentInequality {
+    /// Create a new error with the number of easings and segments found.
+    pub fn new(easings: usize, segments: usize) -> Self {
+        EasingSegmentInequality { easings, segments }
+    }
+}
@@ -30,6 +30,7 @@
 //! One can imagine a linear interpolation between 2D points. Then quasi-linearity means that
 //! the curve consists of lines between the given 2D points but its velocity may change non-linear.
 //! To achieve a non-linear interpolation, the [`easing()`] method on the builder may be used.
+//! If each segment should be eased differently, use [`segment_easings()`] instead.
 //!
 //! Linear equidistant constant interpolations are often wanted to define some specific curve
 //! (like a specific gradient). To create such interpolation, the builder pattern can not be used yet.
@@ -40,33 +41,48 @@
 //! [plateus.rs]: https://github.com/NicolasKlenert/enterpolation/blob/main/examples/plateaus.rs
 //! [`equidistant()`]: LinearBuilder::equidistant()
 //! [`easing()`]: LinearBuilder::easing()
+//! [`segment_easings()`]: LinearBuilder::segment_easings()
 //! [`equidistant_unchecked()`]: Linear::equidistant_unchecked()
 
 use crate::builder::Unknown;
-use crate::{ConstEquidistant, Curve, DiscreteGenerator, Generator, Identity, SortedGenerator};
+use crate::easing::Easing;
+use crate::{
+    ConstEquidistant, ConstEquidistantDomain, Curve, DiscreteGenerator, Extrapolation, Generator,
+    Identity, Scalar, SortedGenerator,
+};
 use num_traits::real::Real;
 use topology_traits::Merge;
 
+use core::cmp::Ordering;
 use core::fmt::Debug;
+use core::ops::{Add, Mul, Sub};
 
 // mod hyper;
 mod builder;
 pub use builder::{LinearBuilder, LinearDirector};
 
+mod segment;
+pub use segment::{SegmentEasing, SegmentEasings};
+
 pub mod error;
-pub use error::{KnotElementInequality, LinearError, NotSorted, TooFewElements};
+pub use error::{
+    DegenerateDomain, EasingSegmentInequality, InvalidEquidistant, KnotElementInequality,
+    LinearError, NonFiniteKnot, NotSorted, TooFewElements,
+};
 
 /// Linear Interpolation.
 ///
 /// See [linear module] for more information.
 ///
 /// [linear module]: self
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Linear<K, E, F> {
     elements: E,
     knots: K,
     easing: F,
+    #[cfg_attr(feature = "serde", serde(default))]
+    extrapolation: Extrapolation,
 }
 
 impl Linear<Unknown, Unknown, Unknown> {
@@ -74,7 +90,7 @@ impl Linear<Unknown, Unknown, Unknown> {
     ///
     /// The builder takes:
     /// - elements with [`elements()`] or [`elements_with_weights()`]
-    /// - knots with either [`knots()`] or [`equidistant()`]
+    /// - knots with either [`knots()`], [`equidistant()`] or [`chordal()`]
     ///
     /// # Examples
     ///
@@ -102,6 +118,7 @@ impl Linear<Unknown, Unknown, Unknown> {
     /// [`elements_with_weights()`]: LinearBuilder::elements_with_weights()
     /// [`knots()`]: LinearBuilder::knots()
     /// [`equidistant()`]: LinearBuilder::equidistant()
+    /// [`chordal()`]: LinearBuilder::chordal()
     pub fn builder() -> LinearBuilder<Unknown, Unknown, Identity, Unknown> {
         LinearBuilder::new()
     }
@@ -112,19 +129,120 @@ where
     K: SortedGenerator<Output = R>,
     E: DiscreteGenerator,
     E::Output: Merge<R> + Debug,
-    F: Curve<R, Output = R>,
-    R: Real + Debug,
+    F: SegmentEasing<R>,
+    R: Scalar + Debug,
 {
     type Output = E::Output;
     /// # Panics
     ///
-    /// Panics if `scalar` is NaN or similar.
+    /// Panics if `scalar` is NaN or similar or if `scalar` is outside of the domain
+    /// and the interpolation was built with [`Extrapolation::Error`].
+    /// Use [`try_gen()`] to handle such inputs without panicking.
+    ///
+    /// [`try_gen()`]: Linear::try_gen()
     fn gen(&self, scalar: K::Output) -> Self::Output {
+        let scalar = self.extrapolate(scalar);
         //we use upper_border_with_factor as this allows us a performance improvement for equidistant knots
         let (min_index, max_index, factor) = self.knots.upper_border(scalar);
         let min_point = self.elements.gen(min_index);
         let max_point = self.elements.gen(max_index);
-        min_point.merge(max_point, self.easing.gen(factor))
+        min_point.merge(max_point, self.easing.ease(min_index, factor))
+    }
+}
+
+impl<R, K, E, F> Linear<K, E, F>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Debug,
+    F: SegmentEasing<R>,
+    R: Scalar + Debug,
+{
+    /// Generate the value at the given input if possible.
+    ///
+    /// In contrast to [`gen()`], this method does not panic but returns `None`
+    /// if the input is not finite (NaN or infinite) or if the interpolation
+    /// has less than two elements or not as many knots as elements.
+    /// If the interpolation was built with [`Extrapolation::Error`], `None` is also returned
+    /// for inputs outside of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .build()?;
+    /// assert_f64_near!(linear.try_gen(2.0).unwrap(), 4.0);
+    /// assert_eq!(linear.try_gen(f64::NAN), None);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`gen()`]: Linear::gen()
+    pub fn try_gen(&self, scalar: R) -> Option<E::Output> {
+        if !scalar.is_finite() || self.elements.len() < 2 || self.knots.len() != self.elements.len()
+        {
+            return None;
+        }
+        if self.extrapolation == Extrapolation::Error && !self.contains(scalar) {
+            return None;
+        }
+        Some(self.gen(scalar))
+    }
+
+    /// Returns the policy used for inputs outside of the domain.
+    pub fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    /// Returns the first and last knot.
+    ///
+    /// In contrast to [`domain()`], this does not need the input to be [`Real`].
+    ///
+    /// [`domain()`]: Curve::domain()
+    fn bounds(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+
+    /// Returns `true` if the given input is inside of the domain.
+    fn contains(&self, scalar: R) -> bool {
+        let [start, end] = self.bounds();
+        start <= scalar && scalar <= end
+    }
+
+    /// Map the input according to the extrapolation policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the policy is [`Extrapolation::Error`] and the input is outside of the domain.
+    fn extrapolate(&self, scalar: R) -> R {
+        match self.extrapolation {
+            Extrapolation::Extend => scalar,
+            Extrapolation::Clamp => {
+                let [start, end] = self.bounds();
+                // NaN is not comparable and clamped to the start, as `Real::max` would do
+                match scalar.partial_cmp(&start) {
+                    Some(Ordering::Less) | None => start,
+                    _ if scalar > end => end,
+                    _ => scalar,
+                }
+            }
+            Extrapolation::Error => {
+                assert!(
+                    self.contains(scalar),
+                    "input {:?} is outside of the domain {:?}",
+                    scalar,
+                    self.bounds()
+                );
+                scalar
+            }
+        }
     }
 }
 
@@ -133,7 +251,7 @@ where
     K: SortedGenerator<Output = R>,
     E: DiscreteGenerator,
     E::Output: Merge<R> + Debug,
-    F: Curve<R, Output = R>,
+    F: SegmentEasing<R>,
     R: Real + Debug,
 {
     fn domain(&self) -> [R; 2] {
@@ -141,10 +259,139 @@ where
     }
 }
 
+impl<R, K, E, F> Easing<R> for Linear<K, E, F>
+where
+    Self: Generator<R, Output = R>,
+{
+    fn ease(&self, factor: R) -> R {
+        self.gen(factor)
+    }
+}
+
+impl<R, K, E, F> Linear<K, E, F>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Sub<Output = E::Output> + Mul<R, Output = E::Output> + Copy + Debug,
+    F: SegmentEasing<R>,
+    R: Real + Debug,
+{
+    /// Generate the value and its tangent, in this order.
+    ///
+    /// The tangent is the direction of the current segment divided by the distance of its knots.
+    /// At an exact knot, the tangent of the segment to the right is returned.
+    /// Inputs outside of the domain are handled according to the [`Extrapolation`] policy.
+    /// With [`Extrapolation::Clamp`], the interpolation is constant outside of its domain,
+    /// such that the tangent of such inputs is zero.
+    ///
+    /// The derivative of the easing function is given by its [`ease_derivative()`] method.
+    /// For [`Identity`], the tangent is calculated exactly. Other easings may approximate their derivative.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// let linear = Linear::builder()
+    ///                 .elements([0.0,5.0,3.0])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .build()?;
+    /// let [value, tangent] = linear.gen_with_tangent(2.0);
+    /// assert_f64_near!(value, 4.0);
+    /// assert_f64_near!(tangent, -1.0);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ease_derivative()`]: SegmentEasing::ease_derivative()
+    pub fn gen_with_tangent(&self, scalar: R) -> [E::Output; 2] {
+        let clamped = self.extrapolation == Extrapolation::Clamp && !self.contains(scalar);
+        let scalar = self.extrapolate(scalar);
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        let min_point = self.elements.gen(min_index);
+        let max_point = self.elements.gen(max_index);
+        let value = min_point.merge(max_point, self.easing.ease(min_index, factor));
+        if clamped {
+            return [value, (max_point - min_point) * R::zero()];
+        }
+        let length = self.knots.gen(max_index) - self.knots.gen(min_index);
+        let easing_derivative = self.easing.ease_derivative(min_index, factor);
+        [
+            value,
+            (max_point - min_point) * (easing_derivative / length),
+        ]
+    }
+}
+
+impl<R, K, E> Linear<K, E, Identity>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Merge<R> + Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy + Debug,
+    R: Real + Debug,
+{
+    /// Calculate the definite integral of the interpolation from `start` to `end`.
+    ///
+    /// The interval is split at all knots inbetween, such that the interpolation is linear on each piece
+    /// and its integral is given exactly by the value in the middle of the piece times its length.
+    /// Inputs outside of the domain are handled according to the [`Extrapolation`] policy.
+    /// If `start` is bigger than `end`, the integral is negative.
+    ///
+    /// In contrast to [`Curve::integrate()`], which approximates the integral of any curve,
+    /// this method is exact but only available for interpolations without easing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interpolation was built with [`Extrapolation::Error`]
+    /// and the interval is not inside of the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::{Linear, LinearError}, Generator};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// #
+    /// # fn main() -> Result<(), LinearError> {
+    /// // speed over time
+    /// let speed = Linear::builder()
+    ///                 .elements([0.0,10.0,10.0,0.0])
+    ///                 .knots([0.0,2.0,5.0,6.0])
+    ///                 .build()?;
+    /// assert_f64_near!(speed.integral(0.0, 6.0), 45.0);
+    /// assert_f64_near!(speed.integral(1.0, 3.0), 17.5);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn integral(&self, start: R, end: R) -> E::Output {
+        if start > end {
+            return self.integral(end, start) * -R::one();
+        }
+        let half = (R::one() + R::one()).recip();
+        let mut sum = self.elements.gen(0) * R::zero();
+        let mut lower = start;
+        let mut index = self.knots.strict_upper_bound(start);
+        while lower < end {
+            let upper = if index < self.knots.len() {
+                self.knots.gen(index).min(end)
+            } else {
+                end
+            };
+            sum = sum + self.gen((lower + upper) * half) * (upper - lower);
+            lower = upper;
+            index += 1;
+        }
+        sum
+    }
+}
+
 impl<K, E, F> Linear<K, E, F>
 where
     K: SortedGenerator,
-    K::Output: Real,
+    K::Output: Scalar,
     E: DiscreteGenerator,
     E::Output: Merge<K::Output>,
 {
@@ -163,6 +410,7 @@ where
             elements,
             knots,
             easing,
+            extrapolation: Extrapolation::Extend,
         })
     }
 }
@@ -172,7 +420,7 @@ where
     E: DiscreteGenerator,
     K: SortedGenerator,
     E::Output: Merge<K::Output>,
-    K::Output: Real,
+    K::Output: Scalar,
 {
     /// Create a linear interpolation with slice-like collections of elements and knots.
     ///
@@ -186,6 +434,7 @@ where
             elements,
             knots,
             easing,
+            extrapolation: Extrapolation::Extend,
         }
     }
 }
@@ -203,6 +452,40 @@ impl<R, T, const N: usize> Linear<ConstEquidistant<R, N>, [T; N], Identity> {
             elements,
             knots: ConstEquidistant::new(),
             easing: Identity::new(),
+            extrapolation: Extrapolation::Extend,
+        }
+    }
+}
+
+impl<R, T, const N: usize> Linear<ConstEquidistantDomain<R, N>, [T; N], Identity> {
+    /// Create a linear interpolation with an array of elements and equidistant knots,
+    /// beginning with `start` and increasing by `step`.
+    ///
+    /// This constructor should be used if one wants to create a constant Interpolation
+    /// with a domain other than `[0.0,1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// The array has to be at least of length *two* and `step` has to be positive.
+    /// Otherwise the library may panic at any time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{linear::Linear, ConstEquidistantDomain, Generator, Curve, Identity};
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// // temperature over a minute
+    /// const TEMPERATURE: Linear<ConstEquidistantDomain<f64, 3>, [f64; 3], Identity> =
+    ///     Linear::equidistant_domain_unchecked([20.0, 25.0, 22.0], 0.0, 30.0);
+    /// assert_eq!(TEMPERATURE.domain(), [0.0, 60.0]);
+    /// assert_f64_near!(TEMPERATURE.gen(45.0), 23.5);
+    /// ```
+    pub const fn equidistant_domain_unchecked(elements: [T; N], start: R, step: R) -> Self {
+        Linear {
+            elements,
+            knots: ConstEquidistantDomain::new(start, step),
+            easing: Identity::new(),
+            extrapolation: Extrapolation::Extend,
         }
     }
 }
@@ -220,6 +503,7 @@ mod test {
     // use alloc::collections::binary_heap::IntoIter;
 
     use super::*;
+    use crate::easing::FuncEase;
     use crate::Curve;
 
     #[test]
@@ -324,4 +608,179 @@ mod test {
             .unwrap();
         assert_eq!(linear, linear2);
     }
+    #[test]
+    fn tangent() {
+        let lin = Linear::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 1.0, 3.0, 4.0])
+            .build()
+            .unwrap();
+        let expected = [
+            (0.5, 60.0, 80.0),
+            (1.0, 100.0, -50.0),
+            (2.0, 50.0, -50.0),
+            (4.0, 200.0, 200.0),
+            (5.0, 400.0, 200.0),
+        ];
+        for (input, value, tangent) in expected {
+            let [val, tan] = lin.gen_with_tangent(input);
+            assert_f64_near!(val, value);
+            assert_f64_near!(tan, tangent);
+        }
+        let eased = Linear::builder()
+            .elements([0.0, 1.0])
+            .knots([0.0, 2.0])
+            .easing(FuncEase::new(|x: f64| x * x))
+            .build()
+            .unwrap();
+        let [val, tan] = eased.gen_with_tangent(1.0);
+        assert_f64_near!(val, 0.25);
+        assert!((tan - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn try_gen() {
+        let linear = Linear::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 1.0, 2.0, 3.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.try_gen(0.5).unwrap(), 60.0);
+        assert_f64_near!(linear.try_gen(4.0).unwrap(), 400.0);
+        assert_eq!(linear.try_gen(f64::NAN), None);
+        assert_eq!(linear.try_gen(f64::INFINITY), None);
+        assert_eq!(linear.try_gen(f64::NEG_INFINITY), None);
+        let single =
+            Linear::new_unchecked([1.0], crate::Sorted::new([0.0]).unwrap(), Identity::new());
+        assert_eq!(single.try_gen(0.5), None);
+    }
+
+    #[test]
+    fn extrapolation_modes() {
+        let builder = Linear::builder()
+            .elements([20.0, 100.0, 0.0, 200.0])
+            .knots([0.0, 1.0, 2.0, 3.0]);
+        let extend = builder.clone().build().unwrap();
+        assert_eq!(extend.extrapolation(), Extrapolation::Extend);
+        assert_f64_near!(extend.gen(-1.0), -60.0);
+        let clamp = builder
+            .clone()
+            .extrapolation(Extrapolation::Clamp)
+            .build()
+            .unwrap();
+        assert_f64_near!(clamp.gen(-1.0), 20.0);
+        assert_f64_near!(clamp.gen(4.0), 200.0);
+        assert_f64_near!(clamp.gen(1.5), 50.0);
+        // NaN is clamped to the start
+        assert_f64_near!(clamp.gen(f64::NAN), 20.0);
+        assert_eq!(clamp.gen_with_tangent(4.0), [200.0, 0.0]);
+        assert_eq!(clamp.gen_with_tangent(-1.0), [20.0, 0.0]);
+        assert_f64_near!(clamp.gen_with_tangent(3.0)[1], 200.0);
+        let error = builder.extrapolation(Extrapolation::Error).build().unwrap();
+        assert_f64_near!(error.gen(3.0), 200.0);
+        assert_f64_near!(error.try_gen(0.0).unwrap(), 20.0);
+        assert_eq!(error.try_gen(-0.5), None);
+        assert_eq!(error.try_gen(3.5), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extrapolation_error() {
+        let linear = Linear::builder()
+            .elements([20.0, 100.0])
+            .knots([0.0, 1.0])
+            .extrapolation(Extrapolation::Error)
+            .build()
+            .unwrap();
+        linear.gen(1.5);
+    }
+
+    #[test]
+    fn segment_easings() {
+        type Ease = FuncEase<fn(f64) -> f64>;
+        let easings: [Ease; 2] = [FuncEase::new(|x| x * x), FuncEase::new(|x| x.sqrt())];
+        let linear = Linear::builder()
+            .elements([0.0, 4.0, 0.0])
+            .knots([0.0, 1.0, 2.0])
+            .segment_easings(easings)
+            .build()
+            .unwrap();
+        let expected = [(0.0, 0.0), (0.5, 1.0), (1.0, 4.0), (1.25, 2.0), (2.0, 0.0)];
+        for (input, result) in expected {
+            assert_f64_near!(linear.gen(input), result);
+        }
+        let [_, tangent] = linear.gen_with_tangent(0.5);
+        assert!((tangent - 4.0).abs() < 1e-6);
+        assert!(Linear::builder()
+            .elements([0.0, 4.0, 0.0])
+            .knots([0.0, 1.0, 2.0])
+            .segment_easings([Identity::new()])
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn curve_as_easing() {
+        let easing = Linear::builder()
+            .elements([0.0, 0.25, 1.0])
+            .knots([0.0, 0.5, 1.0])
+            .build()
+            .unwrap();
+        let linear = Linear::builder()
+            .elements([0.0, 10.0])
+            .knots([0.0, 1.0])
+            .easing(easing)
+            .build()
+            .unwrap();
+        assert_f64_near!(linear.gen(0.5), 2.5);
+        let wrapped = Linear::builder()
+            .elements([0.0, 10.0])
+            .knots([0.0, 1.0])
+            .easing(crate::easing::CurveEasing::new(
+                FuncEase::new(|x: f64| x * x).clamp(),
+            ))
+            .build()
+            .unwrap();
+        assert_f64_near!(wrapped.gen(0.5), 2.5);
+        #[cfg(feature = "bezier")]
+        {
+            use crate::bezier::Bezier;
+            let easing = Bezier::builder()
+                .elements([0.0, 0.0, 1.0])
+                .normalized::<f64>()
+                .constant()
+                .build()
+                .unwrap();
+            let linear = Linear::builder()
+                .elements([0.0, 10.0])
+                .knots([0.0, 1.0])
+                .easing(easing)
+                .build()
+                .unwrap();
+            assert_f64_near!(linear.gen(0.5), 2.5);
+        }
+    }
+
+    #[test]
+    fn integral() {
+        // the jump at the duplicated knot is integrated correctly
+        let lin = Linear::builder()
+            .elements([0.0, 2.0, 4.0, 4.0])
+            .knots([0.0, 1.0, 1.0, 2.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(lin.integral(0.0, 2.0), 5.0);
+        assert_f64_near!(lin.integral(2.0, 0.0), -5.0);
+        assert_f64_near!(lin.integral(0.5, 1.5), 2.75);
+        assert_f64_near!(lin.integral(1.0, 1.0), 0.0);
+        // extrapolation extends the first segment
+        assert_f64_near!(lin.integral(-1.0, 0.0), -1.0);
+        let clamped = Linear::builder()
+            .elements([1.0, 3.0])
+            .knots([0.0, 1.0])
+            .extrapolation(Extrapolation::Clamp)
+            .build()
+            .unwrap();
+        assert_f64_near!(clamped.integral(-1.0, 2.0), 6.0);
+    }
 }
@@ -0,0 +1,84 @@
+//! Easings which may differ for each segment of a linear interpolation.
+
+use crate::easing::Easing;
+use crate::DiscreteGenerator;
+use num_traits::real::Real;
+
+/// Trait for easings used by linear interpolations.
+///
+/// Before merging the two elements of a segment, the factor between them is given to the easing
+/// together with the index of the segment. The output is used as the new factor.
+///
+/// This trait is implemented for all [`Easing`]s, which ignore the index of the segment,
+/// and for [`SegmentEasings`], which use a different easing for each segment.
+pub trait SegmentEasing<R> {
+    /// Reshape the factor of the segment with the given index.
+    fn ease(&self, segment: usize, factor: R) -> R;
+    /// Returns the derivative of the easing of the segment with the given index.
+    fn ease_derivative(&self, segment: usize, factor: R) -> R
+    where
+        R: Real;
+}
+
+impl<R, F> SegmentEasing<R> for F
+where
+    F: Easing<R>,
+{
+    fn ease(&self, _segment: usize, factor: R) -> R {
+        Easing::ease(self, factor)
+    }
+    fn ease_derivative(&self, _segment: usize, factor: R) -> R
+    where
+        R: Real,
+    {
+        Easing::ease_derivative(self, factor)
+    }
+}
+
+/// Easing which uses a different easing for each segment of a linear interpolation.
+///
+/// Segment `i` uses the `i`-th easing of the underlying generator.
+/// This struct is created by the [`segment_easings()`] method of the linear builder.
+///
+/// # Limitations
+///
+/// `SegmentEasings` only implements [`SegmentEasing`] for inputs of type `f32` and `f64`.
+/// Interpolations with other inputs, like custom [`Scalar`] types, can not use this struct.
+/// For such inputs, implement [`SegmentEasing`] for your own type instead.
+///
+/// [`segment_easings()`]: super::LinearBuilder::segment_easings()
+/// [`Scalar`]: crate::Scalar
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SegmentEasings<G>(G);
+
+impl<G> SegmentEasings<G> {
+    /// Create a new `SegmentEasings` struct.
+    pub fn new(easings: G) -> Self {
+        SegmentEasings(easings)
+    }
+}
+
+// Implementing `SegmentEasing` generically over `R` would conflict with the implementation for easings,
+// as other crates are allowed to implement `Easing<R>` for `SegmentEasings<G>` with their own `R`.
+// Add further types here if needed, but remember to update the documentation of `SegmentEasings`.
+macro_rules! impl_segment_easings {
+    ($($real:ty),*) => {
+        $(
+            impl<G> SegmentEasing<$real> for SegmentEasings<G>
+            where
+                G: DiscreteGenerator,
+                G::Output: Easing<$real>,
+            {
+                fn ease(&self, segment: usize, factor: $real) -> $real {
+                    Easing::ease(&self.0.gen(segment), factor)
+                }
+                fn ease_derivative(&self, segment: usize, factor: $real) -> $real {
+                    Easing::ease_derivative(&self.0.gen(segment), factor)
+                }
+            }
+        )*
+    };
+}
+
+impl_segment_easings!(f32, f64);
@@ -0,0 +1,325 @@
+//! Builder module for monotone cubic interpolations.
+
+use super::error::MonotoneCubicError;
+use super::{KnotElementInequality, MonotoneCubic, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, InvalidEquidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for monotone cubic interpolations.
+///
+/// This struct helps create monotone cubic interpolations. The difference between this struct and [`MonotoneCubicBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// [`MonotoneCubicBuilder`]: MonotoneCubicBuilder
+/// [`build()`]: MonotoneCubicDirector::build()
+/// [`elements()`]: MonotoneCubicDirector::elements()
+/// [`knots()`]: MonotoneCubicDirector::knots()
+/// [`equidistant()`]: MonotoneCubicDirector::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonotoneCubicDirector<K, E> {
+    knots: K,
+    elements: E,
+}
+
+/// Builder for monotone cubic interpolations.
+///
+/// This struct helps create monotone cubic interpolations. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// ```rust
+/// # use enterpolation::{monotone_cubic::{MonotoneCubic, MonotoneCubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), MonotoneCubicError> {
+/// let monotone = MonotoneCubic::builder()
+///                 .elements([0.0,0.1,0.9,1.0])
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .build()?;
+/// assert_f64_near!(monotone.gen(0.5), 0.5);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: MonotoneCubicBuilder::build()
+/// [`builder()`]: super::MonotoneCubic::builder()
+/// [`elements()`]: MonotoneCubicBuilder::elements()
+/// [`knots()`]: MonotoneCubicBuilder::knots()
+/// [`equidistant()`]: MonotoneCubicBuilder::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonotoneCubicBuilder<K, E> {
+    inner: Result<MonotoneCubicDirector<K, E>, MonotoneCubicError>,
+}
+
+impl Default for MonotoneCubicDirector<Unknown, Unknown> {
+    fn default() -> Self {
+        MonotoneCubicDirector::new()
+    }
+}
+
+impl Default for MonotoneCubicBuilder<Unknown, Unknown> {
+    fn default() -> Self {
+        MonotoneCubicBuilder::new()
+    }
+}
+
+impl MonotoneCubicDirector<Unknown, Unknown> {
+    /// Create a new monotone cubic interpolation builder.
+    pub const fn new() -> Self {
+        MonotoneCubicDirector {
+            knots: Unknown,
+            elements: Unknown,
+        }
+    }
+
+    /// Set the elements of the interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::MonotoneCubicError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<MonotoneCubicDirector<Unknown, E>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(MonotoneCubicDirector {
+            knots: self.knots,
+            elements,
+        })
+    }
+}
+
+impl MonotoneCubicBuilder<Unknown, Unknown> {
+    /// Create a new monotone cubic interpolation builder.
+    pub const fn new() -> Self {
+        MonotoneCubicBuilder {
+            inner: Ok(MonotoneCubicDirector::new()),
+        }
+    }
+
+    /// Set the elements of the interpolation.
+    pub fn elements<E>(self, elements: E) -> MonotoneCubicBuilder<Unknown, E>
+    where
+        E: DiscreteGenerator,
+    {
+        MonotoneCubicBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> MonotoneCubicDirector<Unknown, E>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::MonotoneCubicError
+    /// [`NotSorted`]: super::error::MonotoneCubicError
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> Result<MonotoneCubicDirector<Sorted<K>, E>, MonotoneCubicError>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(MonotoneCubicDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+        })
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: MonotoneCubicDirector::domain()
+    /// [`normalized()`]: MonotoneCubicDirector::normalized()
+    /// [`distance()`]: MonotoneCubicDirector::distance()
+    pub fn equidistant<R>(self) -> MonotoneCubicDirector<Type<R>, E> {
+        MonotoneCubicDirector {
+            knots: Type::new(),
+            elements: self.elements,
+        }
+    }
+}
+
+impl<E> MonotoneCubicBuilder<Unknown, E>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    pub fn knots<K>(self, knots: K) -> MonotoneCubicBuilder<Sorted<K>, E>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        MonotoneCubicBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: MonotoneCubicBuilder::domain()
+    /// [`normalized()`]: MonotoneCubicBuilder::normalized()
+    /// [`distance()`]: MonotoneCubicBuilder::distance()
+    pub fn equidistant<R>(self) -> MonotoneCubicBuilder<Type<R>, E> {
+        MonotoneCubicBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E> MonotoneCubicDirector<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<MonotoneCubicDirector<Equidistant<R>, E>, InvalidEquidistant> {
+        Ok(MonotoneCubicDirector {
+            knots: Equidistant::try_new(self.elements.len(), start, end)?,
+            elements: self.elements,
+        })
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> MonotoneCubicDirector<Equidistant<R>, E> {
+        MonotoneCubicDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> MonotoneCubicDirector<Equidistant<R>, E> {
+        MonotoneCubicDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+        }
+    }
+}
+
+impl<R, E> MonotoneCubicBuilder<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> MonotoneCubicBuilder<Equidistant<R>, E> {
+        MonotoneCubicBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> MonotoneCubicBuilder<Equidistant<R>, E> {
+        MonotoneCubicBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> MonotoneCubicBuilder<Equidistant<R>, E> {
+        MonotoneCubicBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E, R> MonotoneCubicDirector<K, E>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Build a monotone cubic interpolation.
+    pub fn build(self) -> MonotoneCubic<K, E, R> {
+        MonotoneCubic::new_unchecked(self.elements, self.knots)
+    }
+}
+
+impl<K, E, R> MonotoneCubicBuilder<K, E>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Build a monotone cubic interpolation.
+    pub fn build(self) -> Result<MonotoneCubic<K, E, R>, MonotoneCubicError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
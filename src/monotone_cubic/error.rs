@@ -0,0 +1,90 @@
+//! All error types for monotone cubic interpolations.
+
+pub use crate::builder::TooFewElements;
+pub use crate::{InvalidEquidistant, NotSorted};
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when using or creating a monotone cubic interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum MonotoneCubicError {
+    /// Error returned if the elements are to few for a monotone cubic interpolation.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+    /// Error returned if equidistant knots could not be created.
+    InvalidEquidistant(InvalidEquidistant),
+}
+
+impl fmt::Display for MonotoneCubicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MonotoneCubicError::TooFewElements(inner) => inner.fmt(f),
+            MonotoneCubicError::KnotElementInequality(inner) => inner.fmt(f),
+            MonotoneCubicError::NotSorted(inner) => inner.fmt(f),
+            MonotoneCubicError::InvalidEquidistant(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for MonotoneCubicError {
+    fn from(from: TooFewElements) -> Self {
+        MonotoneCubicError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for MonotoneCubicError {
+    fn from(from: KnotElementInequality) -> Self {
+        MonotoneCubicError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for MonotoneCubicError {
+    fn from(from: NotSorted) -> Self {
+        MonotoneCubicError::NotSorted(from)
+    }
+}
+
+impl From<InvalidEquidistant> for MonotoneCubicError {
+    fn from(from: InvalidEquidistant) -> Self {
+        MonotoneCubicError::InvalidEquidistant(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MonotoneCubicError {}
+
+/// Error returned if the number of elements and the number of knots are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotElementInequality {}
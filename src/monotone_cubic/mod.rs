@@ -0,0 +1,254 @@
+//! Monotone cubic interpolations.
+//!
+//! The easist way to create a monotone cubic interpolation is by using the builder pattern of [`MonotoneCubicBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{monotone_cubic::{MonotoneCubic, MonotoneCubicError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), MonotoneCubicError> {
+//! let monotone = MonotoneCubic::builder()
+//!                 .elements([0.0,0.0,1.0,1.0])
+//!                 .knots([0.0,1.0,2.0,3.0])
+//!                 .build()?;
+//! // flat parts of the data stay flat
+//! assert_f64_near!(monotone.gen(0.5), 0.0);
+//! assert_f64_near!(monotone.gen(1.5), 0.5);
+//! assert_f64_near!(monotone.gen(2.5), 1.0);
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Cubic splines interpolating monotone data may overshoot between the data points, such that
+//! the interpolation is not monotone itself. A monotone cubic interpolation is a [`Hermite`]
+//! spline whose tangents are limited with the Fritsch–Carlson method, such it never overshoots.
+//! If the elements are strictly increasing (decreasing), the curve is strictly increasing (decreasing)
+//! over its whole domain.
+//!
+//! [`MonotoneCubicBuilder`]: MonotoneCubicBuilder
+//! [`Hermite`]: crate::hermite::Hermite
+
+use crate::builder::Unknown;
+use crate::hermite::Hermite;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::fmt::Debug;
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::{MonotoneCubicBuilder, MonotoneCubicDirector};
+
+pub mod error;
+pub use error::{
+    InvalidEquidistant, KnotElementInequality, MonotoneCubicError, NotSorted, TooFewElements,
+};
+
+/// Monotone cubic interpolation.
+///
+/// See the [monotone cubic module] for more information.
+///
+/// [monotone cubic module]: self
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MonotoneCubic<K, E, R> {
+    inner: Hermite<K, E, Vec<R>>,
+}
+
+impl MonotoneCubic<Unknown, Unknown, Unknown> {
+    /// Get the builder for a monotone cubic interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    ///
+    /// [`elements()`]: MonotoneCubicBuilder::elements()
+    /// [`knots()`]: MonotoneCubicBuilder::knots()
+    /// [`equidistant()`]: MonotoneCubicBuilder::equidistant()
+    pub fn builder() -> MonotoneCubicBuilder<Unknown, Unknown> {
+        MonotoneCubicBuilder::new()
+    }
+}
+
+impl<K, E, R> MonotoneCubic<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    /// Create a monotone cubic interpolation of the given elements at the given knots.
+    ///
+    /// Knots should be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than two elements are given.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`TooFewElements`]: MonotoneCubicError
+    /// [`KnotElementInequality`]: MonotoneCubicError
+    pub fn new(elements: E, knots: K) -> Result<Self, MonotoneCubicError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Self::new_unchecked(elements, knots))
+    }
+
+    /// Create a monotone cubic interpolation of the given elements at the given knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be strictly increasing, there should be as many knots as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K) -> Self {
+        let tangents = tangents(&elements, &knots);
+        MonotoneCubic {
+            inner: Hermite::new_unchecked(elements, tangents, knots),
+        }
+    }
+}
+
+/// Calculate tangents with the Fritsch–Carlson method.
+fn tangents<K, E, R>(elements: &E, knots: &K) -> Vec<R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real,
+{
+    let len = elements.len();
+    let slopes: Vec<R> = (0..len - 1)
+        .map(|i| (elements.gen(i + 1) - elements.gen(i)) / (knots.gen(i + 1) - knots.gen(i)))
+        .collect();
+    let two = R::one() + R::one();
+    let three = two + R::one();
+    // initial tangents are the mean of the adjacent slopes or zero at local extrema
+    let mut tangents = Vec::with_capacity(len);
+    tangents.push(slopes[0]);
+    for i in 1..len - 1 {
+        if slopes[i - 1] * slopes[i] <= R::zero() {
+            tangents.push(R::zero());
+        } else {
+            tangents.push((slopes[i - 1] + slopes[i]) / two);
+        }
+    }
+    tangents.push(slopes[len - 2]);
+    // limit the tangents such that no segment overshoots
+    for i in 0..len - 1 {
+        if slopes[i] == R::zero() {
+            tangents[i] = R::zero();
+            tangents[i + 1] = R::zero();
+            continue;
+        }
+        let alpha = tangents[i] / slopes[i];
+        let beta = tangents[i + 1] / slopes[i];
+        let radius = alpha.hypot(beta);
+        if radius > three {
+            let tau = three / radius;
+            tangents[i] = tau * alpha * slopes[i];
+            tangents[i + 1] = tau * beta * slopes[i];
+        }
+    }
+    tangents
+}
+
+impl<K, E, R> Generator<R> for MonotoneCubic<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + Debug,
+{
+    type Output = R;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> R {
+        self.inner.gen(scalar)
+    }
+}
+
+impl<K, E, R> Curve<R> for MonotoneCubic<K, E, R>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator<Output = R>,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        self.inner.domain()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn no_overshoot() {
+        let elements = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let knots = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let monotone = MonotoneCubic::builder()
+            .elements(elements)
+            .knots(knots)
+            .build()
+            .unwrap();
+        #[cfg(feature = "natural_cubic")]
+        {
+            // a plain cubic spline overshoots
+            let cubic = crate::natural_cubic::NaturalCubic::builder()
+                .elements(elements)
+                .knots(knots)
+                .build()
+                .unwrap();
+            assert!(cubic.take(51).any(|value| !(0.0..=1.0).contains(&value)));
+        }
+        for i in 0..50 {
+            let input = i as f64 * 0.1;
+            let segment = input.floor() as usize;
+            let value = monotone.gen(input);
+            assert!(value >= elements[segment] && value <= elements[segment + 1]);
+        }
+    }
+
+    #[test]
+    fn strictly_increasing() {
+        let monotone = MonotoneCubic::builder()
+            .elements([0.0, 1.0, 1.1, 5.0, 5.2, 10.0])
+            .knots([0.0, 0.5, 2.0, 2.5, 4.0, 4.1])
+            .build()
+            .unwrap();
+        let samples: Vec<f64> = monotone.clone().take(200).collect();
+        for window in samples.windows(2) {
+            assert!(window[0] < window[1]);
+        }
+        // all elements are interpolated
+        assert_f64_near!(monotone.gen(2.0), 1.1);
+        assert_f64_near!(monotone.gen(4.1), 10.0);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0])
+                .knots([0.0])
+                .build(),
+            Err(MonotoneCubicError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0, 2.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(MonotoneCubicError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            MonotoneCubic::builder()
+                .elements([1.0, 2.0])
+                .knots([1.0, 0.0])
+                .build(),
+            Err(MonotoneCubicError::NotSorted(_))
+        ));
+    }
+}
@@ -0,0 +1,265 @@
+//! Builder module for natural cubic splines.
+
+use super::error::NaturalCubicError;
+use super::{Boundary, KnotElementInequality, NaturalCubic, TooFewElements};
+use crate::builder::Unknown;
+use crate::{DiscreteGenerator, Sorted, SortedGenerator};
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::{FromPrimitive, Zero};
+
+/// Builder for natural cubic splines.
+///
+/// This struct helps create natural cubic splines. The difference between this struct and [`NaturalCubicBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should interpolate with [`elements()`].
+/// - The knots at which the elements are interpolated with [`knots()`].
+///
+/// Optionally the tangents at the first and last knot can be given with [`clamped_tangents()`].
+///
+/// [`NaturalCubicBuilder`]: NaturalCubicBuilder
+/// [`build()`]: NaturalCubicDirector::build()
+/// [`elements()`]: NaturalCubicDirector::elements()
+/// [`knots()`]: NaturalCubicDirector::knots()
+/// [`clamped_tangents()`]: NaturalCubicDirector::clamped_tangents()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NaturalCubicDirector<K, E, B> {
+    knots: K,
+    elements: E,
+    boundary: B,
+}
+
+/// Builder for natural cubic splines.
+///
+/// This struct helps create natural cubic splines. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the spline itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the spline should interpolate with [`elements()`].
+/// - The knots at which the elements are interpolated with [`knots()`].
+///
+/// Optionally the tangents at the first and last knot can be given with [`clamped_tangents()`].
+///
+/// ```rust
+/// # use enterpolation::{natural_cubic::{NaturalCubic, NaturalCubicError}, Generator, Curve};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), NaturalCubicError> {
+/// let spline = NaturalCubic::builder()
+///                 .elements([0.0,1.0,8.0])
+///                 .knots([0.0,1.0,2.0])
+///                 .clamped_tangents(0.0,12.0)
+///                 .build()?;
+/// assert_f64_near!(spline.gen(1.5), 3.375);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: NaturalCubicBuilder::build()
+/// [`builder()`]: super::NaturalCubic::builder()
+/// [`elements()`]: NaturalCubicBuilder::elements()
+/// [`knots()`]: NaturalCubicBuilder::knots()
+/// [`clamped_tangents()`]: NaturalCubicBuilder::clamped_tangents()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NaturalCubicBuilder<K, E, B> {
+    inner: Result<NaturalCubicDirector<K, E, B>, NaturalCubicError>,
+}
+
+impl Default for NaturalCubicDirector<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        NaturalCubicDirector::new()
+    }
+}
+
+impl Default for NaturalCubicBuilder<Unknown, Unknown, Unknown> {
+    fn default() -> Self {
+        NaturalCubicBuilder::new()
+    }
+}
+
+impl NaturalCubicDirector<Unknown, Unknown, Unknown> {
+    /// Create a new natural cubic spline builder.
+    pub const fn new() -> Self {
+        NaturalCubicDirector {
+            knots: Unknown,
+            elements: Unknown,
+            boundary: Unknown,
+        }
+    }
+
+    /// Set the elements of the spline.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::NaturalCubicError
+    pub fn elements<E>(
+        self,
+        elements: E,
+    ) -> Result<NaturalCubicDirector<Unknown, E, Boundary<E::Output>>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(NaturalCubicDirector {
+            knots: self.knots,
+            elements,
+            boundary: Boundary::Natural,
+        })
+    }
+}
+
+impl NaturalCubicBuilder<Unknown, Unknown, Unknown> {
+    /// Create a new natural cubic spline builder.
+    pub const fn new() -> Self {
+        NaturalCubicBuilder {
+            inner: Ok(NaturalCubicDirector::new()),
+        }
+    }
+
+    /// Set the elements of the spline.
+    pub fn elements<E>(self, elements: E) -> NaturalCubicBuilder<Unknown, E, Boundary<E::Output>>
+    where
+        E: DiscreteGenerator,
+    {
+        NaturalCubicBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> NaturalCubicDirector<Unknown, E, Boundary<E::Output>>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::NaturalCubicError
+    /// [`NotSorted`]: super::error::NaturalCubicError
+    #[allow(clippy::type_complexity)]
+    pub fn knots<K>(
+        self,
+        knots: K,
+    ) -> Result<NaturalCubicDirector<Sorted<K>, E, Boundary<E::Output>>, NaturalCubicError>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(NaturalCubicDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            boundary: self.boundary,
+        })
+    }
+}
+
+impl<E> NaturalCubicBuilder<Unknown, E, Boundary<E::Output>>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the spline.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    /// Knots should also be strictly increasing.
+    pub fn knots<K>(self, knots: K) -> NaturalCubicBuilder<Sorted<K>, E, Boundary<E::Output>>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        NaturalCubicBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+}
+
+impl<K, E> NaturalCubicDirector<K, E, Boundary<E::Output>>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Use the given tangents at the first and last knot instead of natural boundary conditions.
+    ///
+    /// The tangents are the first derivatives of the spline at its first and last knot.
+    pub fn clamped_tangents(self, start: E::Output, end: E::Output) -> Self {
+        NaturalCubicDirector {
+            knots: self.knots,
+            elements: self.elements,
+            boundary: Boundary::Clamped(start, end),
+        }
+    }
+}
+
+impl<K, E> NaturalCubicBuilder<K, E, Boundary<E::Output>>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Use the given tangents at the first and last knot instead of natural boundary conditions.
+    ///
+    /// The tangents are the first derivatives of the spline at its first and last knot.
+    pub fn clamped_tangents(self, start: E::Output, end: E::Output) -> Self {
+        NaturalCubicBuilder {
+            inner: self
+                .inner
+                .map(|director| director.clamped_tangents(start, end)),
+        }
+    }
+}
+
+impl<K, E, R> NaturalCubicDirector<K, E, Boundary<E::Output>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Zero
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Build a natural cubic spline.
+    pub fn build(self) -> NaturalCubic<K, E, E::Output> {
+        NaturalCubic::new_unchecked(self.elements, self.knots, self.boundary)
+    }
+}
+
+impl<K, E, R> NaturalCubicBuilder<K, E, Boundary<E::Output>>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Zero
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Build a natural cubic spline.
+    pub fn build(self) -> Result<NaturalCubic<K, E, E::Output>, NaturalCubicError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! Interpolating cubic splines with natural or clamped boundary conditions.
+//!
+//! The easist way to create a natural cubic spline is by using the builder pattern of [`NaturalCubicBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{natural_cubic::{NaturalCubic, NaturalCubicError}, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), NaturalCubicError> {
+//! let spline = NaturalCubic::builder()
+//!                 .elements([0.0,1.0,0.0])
+//!                 .knots([0.0,1.0,2.0])
+//!                 .build()?;
+//! let results = [0.0,0.6875,1.0,0.6875,0.0];
+//! for (value,result) in spline.take(5).zip(results.iter().copied()){
+//!     assert_f64_near!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! In contrast to bsplines, these splines interpolate all their elements. The spline is twice
+//! continuously differentiable, which uniquely defines it up to two boundary conditions.
+//! By default the second derivatives at both ends are zero, which is called a *natural* spline.
+//! Alternatively the first derivatives at both ends can be given with [`clamped_tangents()`].
+//!
+//! The second derivatives at all knots are calculated when building the spline by solving
+//! a tridiagonal system with the Thomas algorithm. The results are equal to the `CubicSpline`
+//! of SciPy with the corresponding boundary conditions.
+//!
+//! [`NaturalCubicBuilder`]: NaturalCubicBuilder
+//! [`clamped_tangents()`]: NaturalCubicBuilder::clamped_tangents()
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::{Add, Mul, Sub};
+use num_traits::real::Real;
+use num_traits::{FromPrimitive, Zero};
+
+mod builder;
+pub use builder::{NaturalCubicBuilder, NaturalCubicDirector};
+
+pub mod error;
+pub use error::{KnotElementInequality, NaturalCubicError, NotSorted, TooFewElements};
+
+/// The boundary condition of a cubic spline.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Boundary<T> {
+    /// The second derivatives at the first and last knot are zero.
+    Natural,
+    /// The first derivatives at the first and last knot are the given tangents.
+    Clamped(T, T),
+}
+
+/// Interpolating cubic spline.
+///
+/// See the [natural cubic module] for more information.
+///
+/// [natural cubic module]: self
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NaturalCubic<K, E, T> {
+    elements: E,
+    knots: K,
+    /// The second derivatives at each knot.
+    moments: Vec<T>,
+}
+
+impl NaturalCubic<Unknown, Unknown, Unknown> {
+    /// Get the builder for a natural cubic spline.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with [`knots()`]
+    /// - optionally tangents at both ends with [`clamped_tangents()`]
+    ///
+    /// [`elements()`]: NaturalCubicBuilder::elements()
+    /// [`knots()`]: NaturalCubicBuilder::knots()
+    /// [`clamped_tangents()`]: NaturalCubicBuilder::clamped_tangents()
+    pub fn builder() -> NaturalCubicBuilder<Unknown, Unknown, Unknown> {
+        NaturalCubicBuilder::new()
+    }
+}
+
+impl<K, E, R> NaturalCubic<K, E, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Zero
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    /// Create a cubic spline interpolating the given elements at the given knots.
+    ///
+    /// Knots should be strictly increasing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than two elements are given.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`TooFewElements`]: NaturalCubicError
+    /// [`KnotElementInequality`]: NaturalCubicError
+    pub fn new(
+        elements: E,
+        knots: K,
+        boundary: Boundary<E::Output>,
+    ) -> Result<Self, NaturalCubicError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Self::new_unchecked(elements, knots, boundary))
+    }
+
+    /// Create a cubic spline interpolating the given elements at the given knots.
+    ///
+    /// # Panics
+    ///
+    /// Knots should be strictly increasing, there should be as many knots as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K, boundary: Boundary<E::Output>) -> Self {
+        let moments = moments(&elements, &knots, boundary);
+        NaturalCubic {
+            elements,
+            knots,
+            moments,
+        }
+    }
+}
+
+/// Calculate the second derivatives at all knots with the Thomas algorithm.
+fn moments<K, E, R>(elements: &E, knots: &K, boundary: Boundary<E::Output>) -> Vec<E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output>
+        + Sub<Output = E::Output>
+        + Mul<R, Output = E::Output>
+        + Zero
+        + Copy,
+    R: Real + FromPrimitive,
+{
+    let len = elements.len();
+    let last = len - 1;
+    let two = R::from_usize(2).unwrap();
+    let six = R::from_usize(6).unwrap();
+    let step = |i: usize| knots.gen(i + 1) - knots.gen(i);
+    let slope = |i: usize| (elements.gen(i + 1) - elements.gen(i)) * step(i).recip();
+    // tridiagonal system with lower diagonal a, diagonal b, upper diagonal c and right side d
+    let row = |i: usize| -> (R, R, R, E::Output) {
+        if i == 0 {
+            return match boundary {
+                Boundary::Natural => (R::zero(), R::one(), R::zero(), E::Output::zero()),
+                Boundary::Clamped(start, _) => {
+                    (R::zero(), two * step(0), step(0), (slope(0) - start) * six)
+                }
+            };
+        }
+        if i == last {
+            return match boundary {
+                Boundary::Natural => (R::zero(), R::one(), R::zero(), E::Output::zero()),
+                Boundary::Clamped(_, end) => (
+                    step(last - 1),
+                    two * step(last - 1),
+                    R::zero(),
+                    (end - slope(last - 1)) * six,
+                ),
+            };
+        }
+        (
+            step(i - 1),
+            two * (step(i - 1) + step(i)),
+            step(i),
+            (slope(i) - slope(i - 1)) * six,
+        )
+    };
+    // forward sweep
+    let mut upper = Vec::with_capacity(len);
+    let mut moments = Vec::with_capacity(len);
+    let (_, b, c, d) = row(0);
+    upper.push(c / b);
+    moments.push(d * b.recip());
+    for i in 1..len {
+        let (a, b, c, d) = row(i);
+        let denominator = b - a * upper[i - 1];
+        upper.push(c / denominator);
+        moments.push((d - moments[i - 1] * a) * denominator.recip());
+    }
+    // backward substitution
+    for i in (0..last).rev() {
+        moments[i] = moments[i] - moments[i + 1] * upper[i];
+    }
+    moments
+}
+
+impl<K, E, R> Generator<R> for NaturalCubic<K, E, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    type Output = E::Output;
+    /// # Panics
+    ///
+    /// Panics if `scalar` is NaN or similar.
+    fn gen(&self, scalar: R) -> E::Output {
+        let (min_index, max_index, factor) = self.knots.upper_border(scalar);
+        let length = self.knots.gen(max_index) - self.knots.gen(min_index);
+        let inverse = R::one() - factor;
+        let scale = length * length / R::from_usize(6).unwrap();
+        self.elements.gen(min_index) * inverse
+            + self.elements.gen(max_index) * factor
+            + self.moments[min_index] * ((inverse * inverse * inverse - inverse) * scale)
+            + self.moments[max_index] * ((factor * factor * factor - factor) * scale)
+    }
+}
+
+impl<K, E, R> Curve<R> for NaturalCubic<K, E, E::Output>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    E::Output: Add<Output = E::Output> + Mul<R, Output = E::Output> + Copy,
+    R: Real + FromPrimitive + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn natural() {
+        // same values as scipy.interpolate.CubicSpline(knots, elements, bc_type='natural')
+        let spline = NaturalCubic::builder()
+            .elements([0.0, 1.0, 0.0, 2.0])
+            .knots([0.0, 1.0, 2.0, 4.0])
+            .build()
+            .unwrap();
+        let expected = [
+            (0.0, 0.0),
+            (0.5, 67.0 / 92.0),
+            (1.0, 1.0),
+            (1.5, 13.0 / 23.0),
+            (3.0, 8.0 / 23.0),
+            (4.0, 2.0),
+        ];
+        for (input, result) in expected {
+            assert_f64_near!(spline.gen(input), result, 16);
+        }
+        assert_eq!(spline.domain(), [0.0, 4.0]);
+    }
+
+    #[test]
+    fn clamped() {
+        // clamped splines reproduce cubic polynomials
+        let spline = NaturalCubic::builder()
+            .elements([-1.0, 0.0, 1.0, 27.0])
+            .knots([-1.0, 0.0, 1.0, 3.0])
+            .clamped_tangents(3.0, 27.0)
+            .build()
+            .unwrap();
+        for i in 0..=16 {
+            let input = -1.0 + i as f64 * 0.25;
+            assert_f64_near!(spline.gen(input), input * input * input, 64);
+        }
+    }
+
+    #[test]
+    fn linear() {
+        use alloc::vec;
+        // two elements with natural boundaries result in a line
+        let spline = NaturalCubic::builder()
+            .elements(vec![1.0, 3.0])
+            .knots(vec![0.0, 1.0])
+            .build()
+            .unwrap();
+        assert_f64_near!(spline.gen(0.25), 1.5);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            NaturalCubic::builder().elements([1.0]).knots([0.0]).build(),
+            Err(NaturalCubicError::TooFewElements(_))
+        ));
+        assert!(matches!(
+            NaturalCubic::builder()
+                .elements([1.0, 2.0])
+                .knots([0.0, 1.0, 2.0])
+                .build(),
+            Err(NaturalCubicError::KnotElementInequality(_))
+        ));
+        assert!(matches!(
+            NaturalCubic::builder()
+                .elements([1.0, 2.0])
+                .knots([1.0, 0.0])
+                .build(),
+            Err(NaturalCubicError::NotSorted(_))
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+//! Builder module for piecewise curves.
+
+use super::InvalidRange;
+use super::{Piece, Piecewise, PiecewiseError};
+use crate::Curve;
+use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use num_traits::real::Real;
+
+/// Builder for piecewise curves.
+///
+/// Pieces are added with [`push()`] and may be given in any order, as they get sorted by
+/// the start of their range when calling [`build()`].
+///
+/// [`push()`]: PiecewiseBuilder::push()
+/// [`build()`]: PiecewiseBuilder::build()
+pub struct PiecewiseBuilder<R, T> {
+    #[allow(clippy::type_complexity)]
+    pieces: Vec<([R; 2], Box<dyn Piece<R, T>>)>,
+}
+
+impl<R, T> Default for PiecewiseBuilder<R, T> {
+    fn default() -> Self {
+        PiecewiseBuilder::new()
+    }
+}
+
+impl<R, T> PiecewiseBuilder<R, T> {
+    /// Create a new piecewise curve builder.
+    pub fn new() -> Self {
+        PiecewiseBuilder { pieces: Vec::new() }
+    }
+
+    /// Add a curve which covers the given range of the domain.
+    ///
+    /// The range gets mapped linearly onto the domain of the curve.
+    pub fn push<C>(mut self, range: [R; 2], curve: C) -> Self
+    where
+        C: Curve<R, Output = T> + 'static,
+        R: Real,
+    {
+        self.pieces.push((range, Box::new(curve)));
+        self
+    }
+}
+
+impl<R, T> PiecewiseBuilder<R, T>
+where
+    R: Real,
+{
+    /// Build a piecewise curve.
+    ///
+    /// # Errors
+    ///
+    /// [`NoPieces`] if no pieces were given.
+    /// [`InvalidRange`] if the start of a range is not smaller than its end.
+    /// [`Discontiguous`] if the ranges do not cover the domain without gaps or overlaps.
+    ///
+    /// [`NoPieces`]: super::PiecewiseError
+    /// [`InvalidRange`]: super::PiecewiseError
+    /// [`Discontiguous`]: super::PiecewiseError
+    pub fn build(mut self) -> Result<Piecewise<R, T>, PiecewiseError> {
+        // validate the ranges before sorting, as NaN is not comparable
+        for (index, ([start, end], _)) in self.pieces.iter().enumerate() {
+            if start.partial_cmp(end) != Some(Ordering::Less) {
+                return Err(InvalidRange::new(index).into());
+            }
+        }
+        self.pieces
+            .sort_by(|(first, _), (second, _)| first[0].partial_cmp(&second[0]).unwrap());
+        let (ranges, curves) = self.pieces.into_iter().unzip();
+        Piecewise::new(ranges, curves)
+    }
+}
+
+impl<R, T> fmt::Debug for PiecewiseBuilder<R, T>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PiecewiseBuilder")
+            .field(
+                "ranges",
+                &self
+                    .pieces
+                    .iter()
+                    .map(|(range, _)| range)
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
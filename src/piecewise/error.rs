@@ -0,0 +1,130 @@
+//! All error types for piecewise curves.
+
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when creating a piecewise curve.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PiecewiseError {
+    /// Error returned if no pieces were given.
+    NoPieces(NoPieces),
+    /// Error returned if the range of a piece is empty or reversed.
+    InvalidRange(InvalidRange),
+    /// Error returned if the ranges of two adjacent pieces have a gap or overlap.
+    Discontiguous(Discontiguous),
+}
+
+impl fmt::Display for PiecewiseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PiecewiseError::NoPieces(inner) => inner.fmt(f),
+            PiecewiseError::InvalidRange(inner) => inner.fmt(f),
+            PiecewiseError::Discontiguous(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<NoPieces> for PiecewiseError {
+    fn from(from: NoPieces) -> Self {
+        PiecewiseError::NoPieces(from)
+    }
+}
+
+impl From<InvalidRange> for PiecewiseError {
+    fn from(from: InvalidRange) -> Self {
+        PiecewiseError::InvalidRange(from)
+    }
+}
+
+impl From<Discontiguous> for PiecewiseError {
+    fn from(from: Discontiguous) -> Self {
+        PiecewiseError::Discontiguous(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for PiecewiseError {}
+
+/// Error returned if no pieces were given.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NoPieces {}
+
+impl NoPieces {
+    /// Create a new error.
+    pub const fn new() -> Self {
+        NoPieces {}
+    }
+}
+
+impl fmt::Display for NoPieces {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "No pieces given, a piecewise curve needs at least one piece."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NoPieces {}
+
+/// Error returned if the range of a piece is empty or reversed.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InvalidRange {
+    /// The index of the piece, ordered by the start of their ranges.
+    index: usize,
+}
+
+impl InvalidRange {
+    /// Create a new error with the index of the piece with the invalid range.
+    pub fn new(index: usize) -> Self {
+        InvalidRange { index }
+    }
+}
+
+impl fmt::Display for InvalidRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The range of piece {} is invalid. The start of a range has to be smaller than its end.",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for InvalidRange {}
+
+/// Error returned if the ranges of two adjacent pieces have a gap or overlap.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Discontiguous {
+    /// The index of the piece, ordered by the start of their ranges.
+    index: usize,
+}
+
+impl Discontiguous {
+    /// Create a new error with the index of the piece whose range does not end where the next one starts.
+    pub fn new(index: usize) -> Self {
+        Discontiguous { index }
+    }
+}
+
+impl fmt::Display for Discontiguous {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The range of piece {} does not end where the range of piece {} starts.",
+            self.index,
+            self.index + 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Discontiguous {}
@@ -0,0 +1,215 @@
+//! Piecewise curves, which combine several curves into one.
+//!
+//! Each curve, called a piece, covers a range of the domain of the piecewise curve.
+//! The ranges of all pieces have to be contiguous, such that together they cover the whole domain.
+//! The easiest way to create a piecewise curve is by using the builder pattern of [`PiecewiseBuilder`].
+//!
+#![cfg_attr(all(feature = "linear", feature = "std"), doc = "```rust")]
+#![cfg_attr(not(all(feature = "linear", feature = "std")), doc = "```ignore")]
+//! # use enterpolation::{linear::Linear, piecewise::Piecewise, Generator, Curve};
+//! # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+//! #
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let rise = Linear::builder()
+//!                 .elements([0.0,1.0])
+//!                 .equidistant::<f64>()
+//!                 .normalized()
+//!                 .build()?;
+//! let fall = Linear::builder()
+//!                 .elements([1.0,0.0])
+//!                 .equidistant::<f64>()
+//!                 .normalized()
+//!                 .build()?;
+//! let piecewise = Piecewise::builder()
+//!                 .push([0.0,2.0], rise)
+//!                 .push([2.0,3.0], fall)
+//!                 .build()?;
+//! assert_eq!(piecewise.domain(), [0.0,3.0]);
+//! let results = [0.0,0.5,1.0,0.0];
+//! for (value,result) in piecewise.take(4).zip(results.iter().copied()){
+//!     assert_f64_near!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! [`PiecewiseBuilder`]: PiecewiseBuilder
+
+use crate::{Curve, Generator};
+use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::PiecewiseBuilder;
+
+pub mod error;
+pub use error::{Discontiguous, InvalidRange, NoPieces, PiecewiseError};
+
+/// Object safe version of [`Curve`], such that curves of different types can be stored together.
+trait Piece<R, T> {
+    fn gen_piece(&self, input: R) -> T;
+    fn piece_domain(&self) -> [R; 2];
+}
+
+impl<C, R, T> Piece<R, T> for C
+where
+    C: Curve<R, Output = T>,
+    R: Real,
+{
+    fn gen_piece(&self, input: R) -> T {
+        self.gen(input)
+    }
+    fn piece_domain(&self) -> [R; 2] {
+        self.domain()
+    }
+}
+
+/// Curve consisting of several curves, each covering a range of the domain.
+///
+/// The range of each piece gets mapped linearly onto the domain of its curve.
+/// Inputs outside of the domain are passed to the first or last piece respectively.
+pub struct Piecewise<R, T> {
+    ranges: Vec<[R; 2]>,
+    curves: Vec<Box<dyn Piece<R, T>>>,
+}
+
+impl<R, T> Piecewise<R, T> {
+    /// Get a builder for piecewise curves.
+    pub fn builder() -> PiecewiseBuilder<R, T> {
+        PiecewiseBuilder::new()
+    }
+}
+
+impl<R, T> Piecewise<R, T>
+where
+    R: Real,
+{
+    /// Create a piecewise curve of ranges and their corresponding curves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of ranges and curves are not equal.
+    fn new(ranges: Vec<[R; 2]>, curves: Vec<Box<dyn Piece<R, T>>>) -> Result<Self, PiecewiseError> {
+        assert_eq!(ranges.len(), curves.len());
+        if ranges.is_empty() {
+            return Err(NoPieces::new().into());
+        }
+        for (index, [start, end]) in ranges.iter().copied().enumerate() {
+            if start.partial_cmp(&end) != Some(Ordering::Less) {
+                return Err(InvalidRange::new(index).into());
+            }
+        }
+        for (index, window) in ranges.windows(2).enumerate() {
+            if window[0][1] != window[1][0] {
+                return Err(Discontiguous::new(index).into());
+            }
+        }
+        Ok(Piecewise { ranges, curves })
+    }
+
+    /// Returns the number of pieces.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if there are no pieces, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<R, T> Generator<R> for Piecewise<R, T>
+where
+    R: Real,
+{
+    type Output = T;
+    fn gen(&self, input: R) -> T {
+        let index = self
+            .ranges
+            .partition_point(|range| range[0] <= input)
+            .saturating_sub(1);
+        let [start, end] = self.ranges[index];
+        let curve = &self.curves[index];
+        let [curve_start, curve_end] = curve.piece_domain();
+        curve.gen_piece(curve_start + (input - start) / (end - start) * (curve_end - curve_start))
+    }
+}
+
+impl<R, T> Curve<R> for Piecewise<R, T>
+where
+    R: Real,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.ranges[0][0], self.ranges[self.ranges.len() - 1][1]]
+    }
+}
+
+impl<R, T> fmt::Debug for Piecewise<R, T>
+where
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Piecewise")
+            .field("ranges", &self.ranges)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::easing::Identity;
+    use crate::TransformInput;
+
+    #[test]
+    fn piecewise() {
+        let piecewise = Piecewise::builder()
+            .push([1.0, 2.0], TransformInput::new(Identity {}, 0.0, 0.5))
+            .push([0.0, 1.0], Identity {})
+            .build()
+            .unwrap();
+        assert_eq!(piecewise.len(), 2);
+        assert_eq!(piecewise.domain(), [0.0, 2.0]);
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 1.5, 2.0, 3.0];
+        let results = [-1.0, 0.0, 0.5, 0.0, 0.5, 1.0, 2.0];
+        for (value, result) in piecewise.sample(inputs).zip(results) {
+            assert_f64_near!(value, result);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            Piecewise::<f64, f64>::builder().build(),
+            Err(PiecewiseError::NoPieces(_))
+        ));
+        assert!(matches!(
+            Piecewise::builder().push([1.0, 1.0], Identity {}).build(),
+            Err(PiecewiseError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            Piecewise::builder()
+                .push([0.0, 1.0], Identity {})
+                .push([f64::NAN, 2.0], Identity {})
+                .build(),
+            Err(PiecewiseError::InvalidRange(_))
+        ));
+        assert!(matches!(
+            Piecewise::builder()
+                .push([0.0, 1.0], Identity {})
+                .push([0.5, 2.0], Identity {})
+                .build(),
+            Err(PiecewiseError::Discontiguous(_))
+        ));
+        assert!(matches!(
+            Piecewise::builder()
+                .push([0.0, 1.0], Identity {})
+                .push([1.5, 2.0], Identity {})
+                .build(),
+            Err(PiecewiseError::Discontiguous(_))
+        ));
+    }
+}
@@ -0,0 +1,184 @@
+use core::ops::{Add, Div, Index, IndexMut, Mul, Sub};
+
+/// Wrapper of an array, such that it can be used as element of interpolations.
+///
+/// The trait [`Merge`] can not be implemented for arrays directly, as neither the trait nor arrays are part of this crate.
+/// Instead, this wrapper implements element-wise addition, subtraction as well as multiplication and division with a scalar,
+/// such that [`Merge`] is implemented for it and it can be used for all interpolations,
+/// including the calculation of tangents.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "linear", doc = "```rust")]
+#[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+/// # use enterpolation::{linear::{Linear, LinearError}, Generator, Point};
+/// #
+/// # fn main() -> Result<(), LinearError> {
+/// let linear = Linear::builder()
+///                 .elements([Point::new([0.0,0.0,0.0]), Point::new([1.0,2.0,3.0])])
+///                 .knots([0.0,1.0])
+///                 .build()?;
+/// assert_eq!(linear.gen(0.5).into_array(), [0.5,1.0,1.5]);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Merge`]: crate::Merge
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Point<T, const N: usize>(
+    #[cfg_attr(feature = "serde", serde(with = "crate::base::serde_array"))] [T; N],
+);
+
+impl<T, const N: usize> Point<T, N> {
+    /// Create a new point from the given array.
+    pub const fn new(array: [T; N]) -> Self {
+        Point(array)
+    }
+
+    /// Returns the wrapped array.
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for Point<T, N>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Point(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(array: [T; N]) -> Self {
+        Point(array)
+    }
+}
+
+impl<T, const N: usize> From<Point<T, N>> for [T; N] {
+    fn from(point: Point<T, N>) -> Self {
+        point.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T; N]> for Point<T, N> {
+    fn as_ref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T; N]> for Point<T, N> {
+    fn as_mut(&mut self) -> &mut [T; N] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T, const N: usize> Add for Point<T, N>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Point(core::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<T, const N: usize> Sub for Point<T, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Point(core::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<T, R, const N: usize> Mul<R> for Point<T, N>
+where
+    T: Mul<R, Output = T> + Copy,
+    R: Copy,
+{
+    type Output = Self;
+    fn mul(self, scalar: R) -> Self {
+        Point(core::array::from_fn(|i| self.0[i] * scalar))
+    }
+}
+
+impl<T, R, const N: usize> Div<R> for Point<T, N>
+where
+    T: Div<R, Output = T> + Copy,
+    R: Copy,
+{
+    type Output = Self;
+    fn div(self, scalar: R) -> Self {
+        Point(core::array::from_fn(|i| self.0[i] / scalar))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Merge;
+
+    #[test]
+    fn merge() {
+        let first = Point::new([0.0, 2.0, -4.0]);
+        let second = Point::from([1.0, 4.0, 4.0]);
+        assert_eq!(first.merge(second, 0.25).into_array(), [0.25, 2.5, -2.0]);
+        assert_eq!((second - first)[2], 8.0);
+        assert_eq!(Point::<f64, 2>::default(), Point::new([0.0, 0.0]));
+    }
+
+    #[cfg(feature = "bezier")]
+    #[test]
+    fn bezier_tangent() {
+        use crate::bezier::Bezier;
+        let bez = Bezier::builder()
+            .elements([
+                Point::new([0.0, 0.0]),
+                Point::new([1.0, 2.0]),
+                Point::new([2.0, 0.0]),
+            ])
+            .normalized::<f64>()
+            .constant()
+            .build()
+            .unwrap();
+        let [value, tangent] = bez.gen_with_tangent(0.5);
+        assert_eq!(value.into_array(), [1.0, 1.0]);
+        assert_eq!(tangent.into_array(), [2.0, 0.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let point = Point::new([1.0, 2.5, -4.0]);
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(json, "[1.0,2.5,-4.0]");
+        let restored: Point<f64, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, point);
+        assert!(serde_json::from_str::<Point<f64, 3>>("[1.0,2.5]").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+//! Numeric trait for the inputs of interpolations.
+
+use core::ops::{Div, Sub};
+use num_traits::real::Real;
+use num_traits::{NumCast, One, Zero};
+
+/// The minimal numeric operations some interpolations need from their input.
+///
+/// Besides the arithmetic operators, [`Zero`] and [`One`] of `num_traits` have to be implemented.
+///
+/// This trait is implemented for all types implementing [`Real`], such as `f32` and `f64`.
+/// Other types, like fixed-point or half-precision numbers, may implement it to be used as
+/// input of [`Linear`] and as elements of [`Equidistant`].
+/// As the orphan rule prohibits implementing this trait for foreign types, such types
+/// have to be wrapped in a newtype first.
+///
+/// Only the methods of [`Generator`] are available for these types. The methods of [`Curve`]
+/// still require [`Real`].
+///
+/// # Examples
+///
+#[cfg_attr(feature = "linear", doc = "```rust")]
+#[cfg_attr(not(feature = "linear"), doc = "```ignore")]
+/// # use enterpolation::{linear::{Linear, LinearError}, Generator, Scalar};
+/// # use core::ops::{Add, Div, Mul, Sub};
+/// # use num_traits::{One, Zero};
+/// // Q16.16 fixed-point number
+/// #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+/// struct Fixed(i32);
+///
+/// impl Add for Fixed {
+///     type Output = Self;
+///     fn add(self, rhs: Self) -> Self { Fixed(self.0 + rhs.0) }
+/// }
+/// impl Sub for Fixed {
+///     type Output = Self;
+///     fn sub(self, rhs: Self) -> Self { Fixed(self.0 - rhs.0) }
+/// }
+/// impl Mul for Fixed {
+///     type Output = Self;
+///     fn mul(self, rhs: Self) -> Self { Fixed(((self.0 as i64 * rhs.0 as i64) >> 16) as i32) }
+/// }
+/// impl Div for Fixed {
+///     type Output = Self;
+///     fn div(self, rhs: Self) -> Self { Fixed((((self.0 as i64) << 16) / rhs.0 as i64) as i32) }
+/// }
+///
+/// impl Zero for Fixed {
+///     fn zero() -> Self { Fixed(0) }
+///     fn is_zero(&self) -> bool { self.0 == 0 }
+/// }
+/// impl One for Fixed {
+///     fn one() -> Self { Fixed(1 << 16) }
+/// }
+///
+/// impl Scalar for Fixed {
+///     fn from_index(n: usize) -> Option<Self> {
+///         i32::try_from(n).ok()?.checked_mul(1 << 16).map(Fixed)
+///     }
+///     fn to_index(self) -> Option<usize> { usize::try_from(self.0 >> 16).ok() }
+///     fn floor(self) -> Self { Fixed(self.0 & !0xFFFF) }
+///     fn ceil(self) -> Self { Fixed(self.0 + 0xFFFF).floor() }
+///     fn is_finite(self) -> bool { true }
+/// }
+///
+/// // Fixed-point numbers as elements only have to be mergeable.
+/// impl enterpolation::Merge<Fixed> for Fixed {
+///     fn merge(self, to: Self, factor: Fixed) -> Self { self + (to - self) * factor }
+/// }
+///
+/// # fn main() -> Result<(), LinearError> {
+/// let one = Fixed::one();
+/// let two = one + one;
+/// let linear = Linear::builder()
+///                 .elements([Fixed::zero(), two])
+///                 .knots([Fixed::zero(), one])
+///                 .build()?;
+/// assert_eq!(linear.gen(Fixed(1 << 15)), one);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Real`]: num_traits::real::Real
+/// [`Zero`]: num_traits::Zero
+/// [`One`]: num_traits::One
+/// [`Linear`]: crate::linear::Linear
+/// [`Equidistant`]: crate::Equidistant
+/// [`Generator`]: crate::Generator
+/// [`Curve`]: crate::Curve
+pub trait Scalar: Copy + PartialOrd + Zero + One + Sub<Output = Self> + Div<Output = Self> {
+    /// Converts an index or count to this type if it is representable.
+    ///
+    /// This method is not called `from_usize`, such that it does not collide with
+    /// the method of [`FromPrimitive`] for types implementing both traits.
+    ///
+    /// [`FromPrimitive`]: num_traits::FromPrimitive
+    fn from_index(n: usize) -> Option<Self>;
+    /// Converts this number to an index if it is representable.
+    ///
+    /// This method is only called with non-negative whole numbers.
+    fn to_index(self) -> Option<usize>;
+    /// Returns the largest whole number less than or equal to `self`.
+    fn floor(self) -> Self;
+    /// Returns the smallest whole number greater than or equal to `self`.
+    fn ceil(self) -> Self;
+    /// Returns the fractional part of `self`.
+    fn fract(self) -> Self {
+        self - self.floor()
+    }
+    /// Returns `true` if this number is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+}
+
+impl<R> Scalar for R
+where
+    R: Real,
+{
+    fn from_index(n: usize) -> Option<Self> {
+        <R as NumCast>::from(n)
+    }
+    fn to_index(self) -> Option<usize> {
+        num_traits::ToPrimitive::to_usize(&self)
+    }
+    fn floor(self) -> Self {
+        Real::floor(self)
+    }
+    fn ceil(self) -> Self {
+        Real::ceil(self)
+    }
+    fn fract(self) -> Self {
+        Real::fract(self)
+    }
+    fn is_finite(self) -> bool {
+        // only finite numbers are at most as big as the maximal value, NaN is not comparable at all
+        self.abs() <= R::max_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn real() {
+        assert_eq!(<f64 as Scalar>::from_index(3), Some(3.0));
+        assert_eq!(Scalar::to_index(3.0f32), Some(3));
+        assert_eq!(Scalar::fract(2.25f64), 0.25);
+        assert_eq!(Scalar::ceil(2.25f64), 3.0);
+        assert!(Scalar::is_finite(1.0f64));
+        assert!(!Scalar::is_finite(f64::NAN));
+        assert!(!Scalar::is_finite(f32::NEG_INFINITY));
+    }
+
+    #[test]
+    fn no_collision_with_num_traits() {
+        use num_traits::{FromPrimitive, ToPrimitive};
+        fn convert<R: Scalar + FromPrimitive + ToPrimitive>(n: usize) -> (R, Option<usize>) {
+            let converted = R::from_usize(n).unwrap();
+            (converted, converted.to_usize())
+        }
+        assert_eq!(convert::<f64>(3), (3.0, Some(3)));
+    }
+}
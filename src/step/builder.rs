@@ -0,0 +1,367 @@
+//! Builder module for step interpolations.
+
+use super::error::StepError;
+use super::{KnotElementInequality, Step, StepMode, TooFewElements};
+use crate::builder::{Type, Unknown};
+use crate::{DiscreteGenerator, Equidistant, InvalidEquidistant, Sorted, SortedGenerator};
+use num_traits::real::Real;
+use num_traits::FromPrimitive;
+
+/// Builder for step interpolations.
+///
+/// This struct helps create step interpolations. The difference between this struct and [`StepBuilder`]
+/// is that this struct may have other fallible methods and not only the [`build()`] method.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// [`StepBuilder`]: StepBuilder
+/// [`build()`]: StepDirector::build()
+/// [`elements()`]: StepDirector::elements()
+/// [`knots()`]: StepDirector::knots()
+/// [`equidistant()`]: StepDirector::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepDirector<K, E> {
+    knots: K,
+    elements: E,
+    mode: StepMode,
+}
+
+/// Builder for step interpolations.
+///
+/// This struct helps create step interpolations. Its only fallible method is [`build()`].
+/// Usually one creates an instance by using the [`builder()`] method on the interpolation itself.
+///
+/// Before building, one has to give information for:
+/// - The elements the interpolation should use with [`elements()`].
+/// - The knots the interpolation uses. Either by giving them directly with [`knots()`]
+///   or by using equidistant knots with [`equidistant()`].
+///
+/// ```rust
+/// # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+/// #
+/// # fn main() -> Result<(), StepError> {
+/// let step = Step::builder()
+///                 .elements([1,5,100])
+///                 .equidistant::<f64>()
+///                 .normalized()
+///                 .build()?;
+/// let results = [1,1,5,5,100];
+/// for (value,result) in step.take(5).zip(results.iter().copied()){
+///     assert_eq!(value, result);
+/// }
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`build()`]: StepBuilder::build()
+/// [`builder()`]: super::Step::builder()
+/// [`elements()`]: StepBuilder::elements()
+/// [`knots()`]: StepBuilder::knots()
+/// [`equidistant()`]: StepBuilder::equidistant()
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StepBuilder<K, E> {
+    inner: Result<StepDirector<K, E>, StepError>,
+}
+
+impl Default for StepDirector<Unknown, Unknown> {
+    fn default() -> Self {
+        StepDirector::new()
+    }
+}
+
+impl Default for StepBuilder<Unknown, Unknown> {
+    fn default() -> Self {
+        StepBuilder::new()
+    }
+}
+
+impl StepDirector<Unknown, Unknown> {
+    /// Create a new step interpolation builder.
+    pub const fn new() -> Self {
+        StepDirector {
+            knots: Unknown,
+            elements: Unknown,
+            mode: StepMode::Previous,
+        }
+    }
+
+    /// Set the elements of the step interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if not at least 2 elements are given.
+    ///
+    /// [`TooFewElements`]: super::error::StepError
+    pub fn elements<E>(self, elements: E) -> Result<StepDirector<Unknown, E>, TooFewElements>
+    where
+        E: DiscreteGenerator,
+    {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()));
+        }
+        Ok(StepDirector {
+            knots: self.knots,
+            elements,
+            mode: self.mode,
+        })
+    }
+}
+
+impl StepBuilder<Unknown, Unknown> {
+    /// Create a new step interpolation builder.
+    pub const fn new() -> Self {
+        StepBuilder {
+            inner: Ok(StepDirector::new()),
+        }
+    }
+
+    /// Set the elements of the step interpolation.
+    pub fn elements<E>(self, elements: E) -> StepBuilder<Unknown, E>
+    where
+        E: DiscreteGenerator,
+    {
+        StepBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.elements(elements).map_err(|err| err.into())),
+        }
+    }
+}
+
+impl<E> StepDirector<Unknown, E>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    /// Returns [`NotSorted`] if the knots are not sorted such that they are increasing.
+    ///
+    /// [`KnotElementInequality`]: super::error::StepError
+    /// [`NotSorted`]: super::error::StepError
+    pub fn knots<K>(self, knots: K) -> Result<StepDirector<Sorted<K>, E>, StepError>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        if self.elements.len() != knots.len() {
+            return Err(KnotElementInequality::new(self.elements.len(), knots.len()).into());
+        }
+        Ok(StepDirector {
+            knots: Sorted::new(knots)?,
+            elements: self.elements,
+            mode: self.mode,
+        })
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: StepDirector::domain()
+    /// [`normalized()`]: StepDirector::normalized()
+    /// [`distance()`]: StepDirector::distance()
+    pub fn equidistant<R>(self) -> StepDirector<Type<R>, E> {
+        StepDirector {
+            knots: Type::new(),
+            elements: self.elements,
+            mode: self.mode,
+        }
+    }
+}
+
+impl<E> StepBuilder<Unknown, E>
+where
+    E: DiscreteGenerator,
+{
+    /// Set the knots of the interpolation.
+    ///
+    /// The amount of knots must be equal to the amount of elements.
+    pub fn knots<K>(self, knots: K) -> StepBuilder<Sorted<K>, E>
+    where
+        K: DiscreteGenerator,
+        K::Output: PartialOrd,
+    {
+        StepBuilder {
+            inner: self.inner.and_then(|director| director.knots(knots)),
+        }
+    }
+
+    /// Build an interpolation with equidistant knots.
+    ///
+    /// This method takes `R` as a generic parameter. `R` has to be the type you want the knots to be.
+    /// Often this is just `f32` or `f64`.
+    ///
+    /// After this call, you also have to call either of
+    /// - [`domain()`],
+    /// - [`normalized()`] or
+    /// - [`distance()`],
+    ///
+    /// which all define the domain of the interpolation and the spacing of the knots.
+    ///
+    /// [`domain()`]: StepBuilder::domain()
+    /// [`normalized()`]: StepBuilder::normalized()
+    /// [`distance()`]: StepBuilder::distance()
+    pub fn equidistant<R>(self) -> StepBuilder<Type<R>, E> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.equidistant()),
+        }
+    }
+}
+
+impl<R, E> StepDirector<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidEquidistant`] if the distance between the knots is not finite.
+    ///
+    /// [`InvalidEquidistant`]: crate::InvalidEquidistant
+    pub fn domain(
+        self,
+        start: R,
+        end: R,
+    ) -> Result<StepDirector<Equidistant<R>, E>, InvalidEquidistant> {
+        Ok(StepDirector {
+            knots: Equidistant::try_new(self.elements.len(), start, end)?,
+            elements: self.elements,
+            mode: self.mode,
+        })
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> StepDirector<Equidistant<R>, E> {
+        StepDirector {
+            knots: Equidistant::normalized(self.elements.len()),
+            elements: self.elements,
+            mode: self.mode,
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> StepDirector<Equidistant<R>, E> {
+        StepDirector {
+            knots: Equidistant::step(self.elements.len(), start, step),
+            elements: self.elements,
+            mode: self.mode,
+        }
+    }
+}
+
+impl<R, E> StepBuilder<Type<R>, E>
+where
+    E: DiscreteGenerator,
+    R: Real + FromPrimitive,
+{
+    /// Set the domain of the interpolation.
+    pub fn domain(self, start: R, end: R) -> StepBuilder<Equidistant<R>, E> {
+        StepBuilder {
+            inner: self
+                .inner
+                .and_then(|director| director.domain(start, end).map_err(|err| err.into())),
+        }
+    }
+
+    /// Set the domain of the interpolation to be [0.0,1.0].
+    pub fn normalized(self) -> StepBuilder<Equidistant<R>, E> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.normalized()),
+        }
+    }
+
+    /// Set the domain of the interpolation by defining the distance between the knots.
+    pub fn distance(self, start: R, step: R) -> StepBuilder<Equidistant<R>, E> {
+        StepBuilder {
+            inner: self.inner.map(|director| director.distance(start, step)),
+        }
+    }
+}
+
+impl<K, E> StepDirector<K, E> {
+    /// Sets the policy on which element is generated inbetween two knots.
+    ///
+    /// Per default, the element of the previous knot is held ([`StepMode::Previous`]).
+    pub fn mode(self, mode: StepMode) -> Self {
+        StepDirector { mode, ..self }
+    }
+}
+
+impl<K, E> StepBuilder<K, E> {
+    /// Sets the policy on which element is generated inbetween two knots.
+    ///
+    /// Per default, the element of the previous knot is held ([`StepMode::Previous`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{step::{Step, StepError, StepMode}, Generator};
+    /// #
+    /// # fn main() -> Result<(), StepError> {
+    /// let step = Step::builder()
+    ///                 .elements([0,5,3])
+    ///                 .knots([0.0,1.0,3.0])
+    ///                 .mode(StepMode::Next)
+    ///                 .build()?;
+    /// assert_eq!(step.gen(0.5), 5);
+    /// assert_eq!(step.gen(1.0), 5);
+    /// assert_eq!(step.gen(1.5), 3);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn mode(self, mode: StepMode) -> Self {
+        StepBuilder {
+            inner: self.inner.map(|director| director.mode(mode)),
+        }
+    }
+}
+
+impl<K, E> StepDirector<K, E>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Build a step interpolation.
+    pub fn build(self) -> Step<K, E> {
+        Step {
+            mode: self.mode,
+            ..Step::new_unchecked(self.elements, self.knots)
+        }
+    }
+}
+
+impl<K, E> StepBuilder<K, E>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Build a step interpolation.
+    pub fn build(self) -> Result<Step<K, E>, StepError> {
+        match self.inner {
+            Err(err) => Err(err),
+            Ok(director) => Ok(director.build()),
+        }
+    }
+}
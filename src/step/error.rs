@@ -0,0 +1,90 @@
+//! All error types for step interpolations.
+
+pub use crate::builder::TooFewElements;
+pub use crate::{InvalidEquidistant, NotSorted};
+use core::{convert::From, fmt};
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Errors which could occur when using or creating a step interpolation.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StepError {
+    /// Error returned if the elements are to few for a step interpolation.
+    TooFewElements(TooFewElements),
+    /// Error returned if the number of knots and elements are not equal.
+    KnotElementInequality(KnotElementInequality),
+    /// Error returned if knots are not sorted.
+    NotSorted(NotSorted),
+    /// Error returned if equidistant knots could not be created.
+    InvalidEquidistant(InvalidEquidistant),
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::TooFewElements(inner) => inner.fmt(f),
+            StepError::KnotElementInequality(inner) => inner.fmt(f),
+            StepError::NotSorted(inner) => inner.fmt(f),
+            StepError::InvalidEquidistant(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl From<TooFewElements> for StepError {
+    fn from(from: TooFewElements) -> Self {
+        StepError::TooFewElements(from)
+    }
+}
+
+impl From<KnotElementInequality> for StepError {
+    fn from(from: KnotElementInequality) -> Self {
+        StepError::KnotElementInequality(from)
+    }
+}
+
+impl From<NotSorted> for StepError {
+    fn from(from: NotSorted) -> Self {
+        StepError::NotSorted(from)
+    }
+}
+
+impl From<InvalidEquidistant> for StepError {
+    fn from(from: InvalidEquidistant) -> Self {
+        StepError::InvalidEquidistant(from)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for StepError {}
+
+/// Error returned if the number of elements and the number of knots are not matching.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KnotElementInequality {
+    /// The number of elements found.
+    elements: usize,
+    /// The number of knots found.
+    knots: usize,
+}
+
+impl KnotElementInequality {
+    /// Create a new error with the number of elements and knots found.
+    pub fn new(elements: usize, knots: usize) -> Self {
+        KnotElementInequality { elements, knots }
+    }
+}
+
+impl fmt::Display for KnotElementInequality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "There has to be as many knots as elements, however we found {} elements and {} knots.",
+            self.elements, self.knots
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KnotElementInequality {}
@@ -0,0 +1,268 @@
+//! Step interpolations, which hold their elements without blending them.
+//!
+//! The easist way to create a step interpolation is by using the builder pattern of [`StepBuilder`].
+//!
+//! ```rust
+//! # use enterpolation::{step::{Step, StepError}, Generator, Curve};
+//! #
+//! # fn main() -> Result<(), StepError> {
+//! let step = Step::builder()
+//!                 .elements(['a','b','c'])
+//!                 .knots([0.0,1.0,3.0])
+//!                 .build()?;
+//! let results = ['a','a','b','b','c'];
+//! for (value,result) in step.take(5).zip(results.iter().copied()){
+//!     assert_eq!(value, result);
+//! }
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! A step interpolation generates piecewise constant curves. Which element is generated
+//! for an input inbetween two knots is defined by its [`StepMode`].
+//! As elements are never merged, they do not have to implement [`Merge`] and can be of any type,
+//! like enums or characters. This is useful for discrete schedules, control changes or
+//! nearest-neighbor resampling.
+//!
+//! Inputs outside of the domain generate the first or last element.
+//!
+//! [`StepBuilder`]: StepBuilder
+//! [`Merge`]: crate::Merge
+
+use crate::builder::Unknown;
+use crate::{Curve, DiscreteGenerator, Generator, SortedGenerator};
+use core::fmt::Debug;
+use num_traits::real::Real;
+
+mod builder;
+pub use builder::{StepBuilder, StepDirector};
+
+pub mod error;
+pub use error::{InvalidEquidistant, KnotElementInequality, NotSorted, StepError, TooFewElements};
+
+/// Policy of a [`Step`] interpolation on which element to generate inbetween two knots.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum StepMode {
+    /// Hold the element of the previous knot, that is the biggest knot smaller or equal to the input.
+    #[default]
+    Previous,
+    /// Take the element of the next knot, that is the smallest knot bigger or equal to the input.
+    Next,
+    /// Take the element of the nearest knot. If both knots are equally near, the previous one is used.
+    Nearest,
+}
+
+/// Step interpolation.
+///
+/// See [step module] for more information.
+///
+/// [step module]: self
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Step<K, E> {
+    elements: E,
+    knots: K,
+    mode: StepMode,
+}
+
+impl Step<Unknown, Unknown> {
+    /// Get the builder for a step interpolation.
+    ///
+    /// The builder takes:
+    /// - elements with [`elements()`]
+    /// - knots with either [`knots()`] or [`equidistant()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{step::{Step, StepError, StepMode}, Generator};
+    /// #
+    /// # fn main() -> Result<(), StepError> {
+    /// let step = Step::builder()
+    ///                 .elements([1,2,3])
+    ///                 .equidistant::<f64>()
+    ///                 .normalized()
+    ///                 .mode(StepMode::Nearest)
+    ///                 .build()?;
+    /// assert_eq!(step.gen(0.2), 1);
+    /// assert_eq!(step.gen(0.3), 2);
+    /// assert_eq!(step.gen(0.8), 3);
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`elements()`]: StepBuilder::elements()
+    /// [`knots()`]: StepBuilder::knots()
+    /// [`equidistant()`]: StepBuilder::equidistant()
+    pub fn builder() -> StepBuilder<Unknown, Unknown> {
+        StepBuilder::new()
+    }
+}
+
+impl<R, K, E> Step<K, E>
+where
+    K: SortedGenerator<Output = R>,
+    R: Real + Debug,
+{
+    /// Returns the index of the element to generate for the given input.
+    fn index(&self, input: R) -> usize {
+        match self.mode {
+            StepMode::Previous => self.previous(input),
+            StepMode::Next => self.next(input),
+            StepMode::Nearest => {
+                let previous = self.previous(input);
+                let next = self.next(input);
+                if self.knots.gen(next) - input < input - self.knots.gen(previous) {
+                    next
+                } else {
+                    previous
+                }
+            }
+        }
+    }
+
+    /// Returns the index of the biggest knot smaller or equal to the input or the first index.
+    fn previous(&self, input: R) -> usize {
+        self.knots.strict_upper_bound(input).saturating_sub(1)
+    }
+
+    /// Returns the index of the smallest knot bigger or equal to the input or the last index.
+    fn next(&self, input: R) -> usize {
+        self.knots
+            .strict_lower_bound(input)
+            .min(self.knots.len() - 1)
+    }
+}
+
+impl<R, K, E> Generator<R> for Step<K, E>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real + Debug,
+{
+    type Output = E::Output;
+    fn gen(&self, input: R) -> Self::Output {
+        self.elements.gen(self.index(input))
+    }
+}
+
+impl<R, K, E> Curve<R> for Step<K, E>
+where
+    K: SortedGenerator<Output = R>,
+    E: DiscreteGenerator,
+    R: Real + Debug,
+{
+    fn domain(&self) -> [R; 2] {
+        [self.knots.first().unwrap(), self.knots.last().unwrap()]
+    }
+}
+
+impl<K, E> Step<K, E>
+where
+    K: SortedGenerator,
+    E: DiscreteGenerator,
+{
+    /// Create a step interpolation with the given elements and knots.
+    ///
+    /// The interpolation holds the element of the previous knot, see [`StepMode::Previous`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooFewElements`] if less than two elements are given.
+    /// Returns [`KnotElementInequality`] if the number of knots is not equal to the number of elements.
+    ///
+    /// [`TooFewElements`]: StepError
+    /// [`KnotElementInequality`]: StepError
+    pub fn new(elements: E, knots: K) -> Result<Self, StepError> {
+        if elements.len() < 2 {
+            return Err(TooFewElements::new(elements.len()).into());
+        }
+        if knots.len() != elements.len() {
+            return Err(KnotElementInequality::new(elements.len(), knots.len()).into());
+        }
+        Ok(Step {
+            elements,
+            knots,
+            mode: StepMode::Previous,
+        })
+    }
+}
+
+impl<K, E> Step<K, E> {
+    /// Create a step interpolation with the given elements and knots.
+    ///
+    /// The interpolation holds the element of the previous knot, see [`StepMode::Previous`].
+    ///
+    /// # Panics
+    ///
+    /// Knots should be in increasing order, there should be as many knots as elements
+    /// and there has to be at least *two* elements.
+    /// If any of these requirements are not uphold, the library may panic at any time.
+    pub fn new_unchecked(elements: E, knots: K) -> Self {
+        Step {
+            elements,
+            knots,
+            mode: StepMode::Previous,
+        }
+    }
+
+    /// Returns the policy used to choose the element inbetween two knots.
+    pub fn mode(&self) -> StepMode {
+        self.mode
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn modes() {
+        let inputs = [-1.0, 0.0, 0.4, 0.6, 1.0, 2.0, 3.0, 4.0];
+        let expected = [
+            (StepMode::Previous, [0, 0, 0, 0, 1, 1, 2, 2]),
+            (StepMode::Next, [0, 0, 1, 1, 1, 2, 2, 2]),
+            (StepMode::Nearest, [0, 0, 0, 1, 1, 1, 2, 2]),
+        ];
+        for (mode, results) in expected {
+            let step = Step::builder()
+                .elements([0, 1, 2])
+                .knots([0.0, 1.0, 3.0])
+                .mode(mode)
+                .build()
+                .unwrap();
+            assert_eq!(step.mode(), mode);
+            for (input, result) in inputs.iter().zip(results) {
+                assert_eq!(step.gen(input), result, "{:?} at {}", mode, input);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicated_knots() {
+        // the later knot wins, as for linear interpolations
+        let step = Step::new([0, 1, 2], crate::Sorted::new_unchecked([0.0, 1.0, 1.0])).unwrap();
+        assert_eq!(step.gen(1.0), 2);
+        assert_eq!(step.gen(0.9), 0);
+        assert!(Step::new([0], crate::Sorted::new_unchecked([0.0])).is_err());
+        assert!(Step::new([0, 1], crate::Sorted::new_unchecked([0.0])).is_err());
+    }
+
+    #[test]
+    fn equidistant() {
+        let step = Step::builder()
+            .elements(['a', 'b', 'c', 'd'])
+            .equidistant::<f64>()
+            .distance(1.0, 2.0)
+            .mode(StepMode::Next)
+            .build()
+            .unwrap();
+        assert_eq!(step.domain(), [1.0, 7.0]);
+        assert_eq!(step.gen(1.0), 'a');
+        assert_eq!(step.gen(1.5), 'b');
+        assert_eq!(step.gen(6.9), 'd');
+    }
+}
@@ -1,6 +1,8 @@
 //! Module for different utilities which are used across other modules or to help the user of the library.
+use crate::{Concat, Curve, StackArray};
 use core::ops::{Add, Mul};
 use num_traits::real::Real;
+use topology_traits::Merge;
 
 /// Linear interpolation of the two values given.
 pub fn lerp<T, R>(first: T, second: T, factor: R) -> T
@@ -10,3 +12,103 @@ where
 {
     first * (R::one() - factor) + second * factor
 }
+
+/// Linear interpolation of the two values given, using their implementation of [`Merge`].
+///
+/// In contrast to [`lerp()`], this function also works for elements which define their own blending.
+///
+/// [`Merge`]: crate::Merge
+pub fn merge<T, R>(first: T, second: T, factor: R) -> T
+where
+    T: Merge<R>,
+{
+    first.merge(second, factor)
+}
+
+/// Componentwise linear interpolation of the two arrays given.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::utils;
+/// assert_eq!(utils::lerp_array([0.0,2.0], [1.0,4.0], 0.5), [0.5,3.0]);
+/// ```
+pub fn lerp_array<T, R, const N: usize>(first: [T; N], second: [T; N], factor: R) -> [T; N]
+where
+    T: Merge<R> + Copy,
+    R: Copy,
+{
+    core::array::from_fn(|i| first[i].merge(second[i], factor))
+}
+
+/// Componentwise linear interpolation of the two tuples given.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::utils;
+/// assert_eq!(utils::lerp_tuple((0.0,10.0), (1.0,20.0), 0.5), (0.5,15.0));
+/// ```
+pub fn lerp_tuple<A, B, R>(first: (A, B), second: (A, B), factor: R) -> (A, B)
+where
+    A: Merge<R>,
+    B: Merge<R>,
+    R: Copy,
+{
+    (
+        first.0.merge(second.0, factor),
+        first.1.merge(second.1, factor),
+    )
+}
+
+/// Concatenate the given curves back-to-back into one curve.
+///
+/// The domain of each curve is moved such that it starts where the domain of the previous curve ends.
+/// As such, the domain of the resulting curve starts at the start of the domain of the first curve
+/// and is as long as the domains of all curves together. At a seam, the latter curve is used.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{utils, Curve, Generator, TransformInput, Identity};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// let first = TransformInput::normalized_to_domain(Identity::new(), 0.0, 2.0);
+/// let second = TransformInput::normalized_to_domain(Identity::new(), 5.0, 6.0);
+/// let curve = utils::concat([first, second]);
+/// assert_eq!(curve.domain(), [0.0, 3.0]);
+/// assert_f64_near!(curve.gen(1.0), 0.5);
+/// assert_f64_near!(curve.gen(2.0), 0.0);
+/// assert_f64_near!(curve.gen(2.5), 0.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if no curve is given.
+pub fn concat<C, R, const N: usize>(curves: [C; N]) -> Concat<C, R, N>
+where
+    C: Curve<R>,
+    R: Real,
+{
+    Concat::new(curves)
+}
+
+/// Stack the given generators into one generator whose output is the array of all their outputs.
+///
+/// This is useful to assemble points out of separate channels, one for each coordinate.
+/// The resulting generator is as long as the shortest of the given generators
+/// and its domain is the intersection of the domains of the given curves.
+///
+/// # Examples
+///
+/// ```
+/// # use enterpolation::{utils, DiscreteGenerator, Generator};
+/// let xs = [0.0, 1.0, 2.0];
+/// let ys = [3.0, 4.0, 5.0];
+/// let zs = [6.0, 7.0, 8.0, 9.0];
+/// let points = utils::stack_array([&xs[..], &ys[..], &zs[..]]);
+/// assert_eq!(points.len(), 3);
+/// assert_eq!(points.gen(1), [1.0, 4.0, 7.0]);
+/// ```
+pub fn stack_array<G, const N: usize>(generators: [G; N]) -> StackArray<G, N> {
+    StackArray::new(generators)
+}
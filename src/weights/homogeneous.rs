@@ -9,6 +9,38 @@ use num_traits::identities::{One, Zero};
 /// Wrapper for elements to achieve weighted and rational curves.
 ///
 /// This wrapper allows for Homogeneous Coordinates.
+///
+/// Homogeneous coordinates can be added and multiplied by a scalar, such that they implement [`Merge`]
+/// and can be used as elements of any interpolation directly. As the blend happens in homogeneous space,
+/// projecting the result gives the perspective-correct blend of the elements.
+///
+/// # Examples
+///
+#[cfg_attr(feature = "bspline", doc = "```rust")]
+#[cfg_attr(not(feature = "bspline"), doc = "```ignore")]
+/// # use enterpolation::{bspline::{BSpline, BSplineError}, weights::Homogeneous, Generator, Merge};
+/// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+/// #
+/// # fn main() -> Result<(), BSplineError> {
+/// let first = Homogeneous::new(0.0);
+/// let second = Homogeneous::weighted_unchecked(4.0, 3.0);
+/// assert_f64_near!(second.weight(), 3.0);
+/// assert_f64_near!(second.element(), 12.0);
+/// // the heavier element pulls the blend towards itself
+/// assert_f64_near!(first.merge(second, 0.5).project(), 3.0);
+/// // homogeneous elements can be given to any interpolation directly
+/// let nurbs = BSpline::builder()
+///                 .elements([first, second, Homogeneous::new(1.0)])
+///                 .knots([0.0,0.0,1.0,1.0])
+///                 .constant::<3>()
+///                 .build()?;
+/// assert_f64_near!(nurbs.gen(0.5).project(), 3.125);
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// [`Merge`]: crate::Merge
 #[derive(Debug, Copy, Clone, PartialEq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Homogeneous<E, R> {
@@ -60,6 +92,29 @@ where
     pub fn direction(&self) -> E {
         self.element
     }
+
+    /// Return the element of the coordinate, which is already multiplied by its weight.
+    ///
+    /// This is the same as [`direction()`], named for coordinates not lying at infinity.
+    /// To get the element itself, use [`project()`] instead.
+    ///
+    /// [`direction()`]: Homogeneous::direction()
+    /// [`project()`]: Homogeneous::project()
+    pub fn element(&self) -> E {
+        self.direction()
+    }
+}
+
+impl<E, R> Homogeneous<E, R>
+where
+    R: Copy,
+{
+    /// Return the weight of the coordinate.
+    ///
+    /// Coordinates at infinity have a weight of zero.
+    pub fn weight(&self) -> R {
+        self.rational
+    }
 }
 
 impl<E, R> Homogeneous<E, R>
@@ -136,6 +191,36 @@ where
     }
 }
 
+impl<E, R> Homogeneous<E, R>
+where
+    E: Sub<Output = E> + Mul<R, Output = E> + Div<R, Output = E> + Copy,
+    R: Copy,
+{
+    /// Project the homogeneous coordinate and its derivative back to the element space.
+    ///
+    /// Returns the projected element and its derivative, in this order.
+    /// The derivative is calculated with the quotient rule, that is `(w*P' - w'*P)/w^2`
+    /// for the element `P` with weight `w`, which is not the same as projecting the derivative itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::weights::Homogeneous;
+    /// # use assert_float_eq::{afe_is_f64_near, afe_near_error_msg, assert_f64_near};
+    /// let value = Homogeneous::weighted_unchecked(3.0, 2.0);
+    /// // the element does not change, only its weight
+    /// let derivative = Homogeneous::weighted_unchecked(3.0, 1.0);
+    /// let [element, tangent] = value.project_with_derivative(derivative);
+    /// assert_f64_near!(element, 3.0);
+    /// assert_f64_near!(tangent, 0.0);
+    /// ```
+    pub fn project_with_derivative(self, derivative: Self) -> [E; 2] {
+        let element = self.element / self.rational;
+        let tangent = (derivative.element - element * derivative.rational) / self.rational;
+        [element, tangent]
+    }
+}
+
 impl<E, R> Add for Homogeneous<E, R>
 where
     E: Add<Output = E>,
@@ -219,3 +304,24 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Merge;
+
+    #[test]
+    fn arithmetic() {
+        let first = Homogeneous::weighted_unchecked(1.0, 2.0);
+        let second = Homogeneous::weighted_unchecked(4.0, 1.0);
+        let sum = first + second * 2.0;
+        assert_eq!(sum.element(), 10.0);
+        assert_eq!(sum.weight(), 4.0);
+        assert_eq!(sum.project(), 2.5);
+        // blending in homogeneous space weights the elements
+        assert_eq!(first.merge(second, 0.5).project(), 2.0);
+        let infinite = Homogeneous::<f64, f64>::infinity(1.0);
+        assert_eq!(infinite.weight(), 0.0);
+        assert!(infinite.is_infinite());
+    }
+}
@@ -12,10 +12,13 @@ use core::ops::Mul;
 use num_traits::identities::Zero;
 use num_traits::real::Real;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Generator adaptor to transform `(T,R)` to `Homogeneous<T,R>`.
 ///
 /// Weights given by the generator who equal `R::zero()` are considered to be at infinity.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Weights<G> {
     gen: G,
@@ -28,6 +31,79 @@ impl<G> Weights<G> {
     }
 }
 
+/// Returns the maximal absolute value of all weights which are neither zero nor infinite.
+fn max_weight<R, I>(weights: I) -> Option<R>
+where
+    I: Iterator<Item = R>,
+    R: Real,
+{
+    weights
+        .map(|weight| weight.abs())
+        .filter(|weight| !weight.is_zero() && *weight <= R::max_value())
+        .fold(None, |max, weight| match max {
+            Some(max) if max >= weight => Some(max),
+            _ => Some(weight),
+        })
+}
+
+#[cfg(feature = "alloc")]
+impl<T, R> Weights<Vec<(T, R)>>
+where
+    T: Copy,
+    R: Real,
+{
+    /// Rescale all weights such that the biggest weight is one.
+    ///
+    /// Every weight gets divided by the absolute value of the biggest weight, which scales the homogeneous
+    /// elements accordingly. The projected curve stays the same, however the numerical conditioning
+    /// of the calculations may improve for huge or tiny weights.
+    ///
+    /// Weights which are zero or not finite are skipped when searching for the biggest weight.
+    /// If no such weight exists, the weights are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use enterpolation::{weights::Weights, Generator};
+    /// let weights = Weights::new(vec![(1.0, 2.0), (3.0, 8.0), (5.0, 0.0)]).normalize_weights();
+    /// assert_eq!(weights.gen(0).project(), 1.0);
+    /// assert_eq!(weights.gen(1).project(), 3.0);
+    /// assert!(weights.gen(2).is_infinite());
+    /// ```
+    pub fn normalize_weights(&self) -> Self {
+        match max_weight(self.gen.iter().map(|(_, weight)| weight)) {
+            Some(max) => Weights::new(
+                self.gen
+                    .iter()
+                    .map(|(element, weight)| (element, weight / max))
+                    .collect(),
+            ),
+            None => Weights::new(self.gen.clone()),
+        }
+    }
+}
+
+impl<T, R, const N: usize> Weights<[(T, R); N]>
+where
+    T: Copy,
+    R: Real,
+{
+    /// Rescale all weights such that the biggest weight is one.
+    ///
+    /// Every weight gets divided by the absolute value of the biggest weight, which scales the homogeneous
+    /// elements accordingly. The projected curve stays the same, however the numerical conditioning
+    /// of the calculations may improve for huge or tiny weights.
+    ///
+    /// Weights which are zero or not finite are skipped when searching for the biggest weight.
+    /// If no such weight exists, the weights are returned unchanged.
+    pub fn normalize_weights(&self) -> Self {
+        match max_weight(self.gen.iter().map(|(_, weight)| weight)) {
+            Some(max) => Weights::new(self.gen.map(|(element, weight)| (element, weight / max))),
+            None => Weights::new(self.gen),
+        }
+    }
+}
+
 impl<G, Input> Generator<Input> for Weights<G>
 where
     G: Generator<Input>,
@@ -103,3 +179,24 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_weights() {
+        let weights = Weights::new([(1.0, 0.5), (2.0, -4.0), (3.0, 0.0), (4.0, f64::INFINITY)]);
+        let normalized = weights.normalize_weights();
+        assert_eq!(
+            normalized.gen.map(|(_, weight)| weight)[..3],
+            [0.125, -1.0, 0.0]
+        );
+        assert!(normalized.gen(2).is_infinite());
+        for i in 0..2 {
+            assert_f64_near!(normalized.gen(i).project(), weights.gen(i).project());
+        }
+        let zeros = Weights::new([(1.0, 0.0), (2.0, 0.0)]);
+        assert_eq!(zeros.normalize_weights(), zeros);
+    }
+}
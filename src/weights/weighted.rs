@@ -8,7 +8,7 @@ use num_traits::real::Real;
 /// Interpolation Adaptor used for weighted elements to automatically unwrap them from their weights.
 ///
 /// This Adaptor is often appended to an interpolation with weighted elements to automatically unwrap them.
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Weighted<G> {
     inner: G,
@@ -23,6 +23,10 @@ impl<G> Weighted<G> {
     pub fn inner(self) -> G {
         self.inner
     }
+    /// Return a reference to the inner interpolation.
+    pub fn get_ref(&self) -> &G {
+        &self.inner
+    }
 }
 
 impl<G, I> Generator<I> for Weighted<G>
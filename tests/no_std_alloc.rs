@@ -0,0 +1,55 @@
+//! Checks that dynamic workspaces and `Vec` elements are usable without std.
+//!
+//! Run with `cargo test --no-default-features --features alloc,libm --test no_std_alloc`.
+//! Enable the `bezier` and `bspline` features to also test curves using them.
+#![no_std]
+#![cfg(feature = "alloc")]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+use enterpolation::{DiscreteGenerator, DynSpace, Generator, Space};
+
+#[test]
+fn vec_generator() {
+    let elements: Vec<f64> = vec![1.0, 2.0, 3.0];
+    assert_eq!(elements.len(), 3);
+    assert_eq!(elements.gen(1), 2.0);
+    assert_eq!(elements.clone().to_vec(), elements);
+}
+
+#[test]
+fn dyn_space() {
+    let space: DynSpace<f64> = DynSpace::new(4);
+    assert_eq!(space.len(), 4);
+    let workspace: Vec<f64> = space.workspace();
+    assert_eq!(workspace, vec![0.0; 4]);
+}
+
+#[cfg(feature = "bezier")]
+#[test]
+fn bezier() {
+    use enterpolation::bezier::Bezier;
+    let bezier = Bezier::builder()
+        .elements(vec![0.0, 4.0, 0.0])
+        .normalized::<f64>()
+        .dynamic()
+        .build()
+        .unwrap();
+    assert_eq!(bezier.gen(0.5), 2.0);
+}
+
+#[cfg(feature = "bspline")]
+#[test]
+fn bspline() {
+    use enterpolation::bspline::BSpline;
+    let bspline = BSpline::builder()
+        .clamped()
+        .elements(vec![0.0, 4.0, 0.0])
+        .knots(vec![0.0, 1.0])
+        .dynamic()
+        .build()
+        .unwrap();
+    assert_eq!(bspline.gen(0.5), 2.0);
+}